@@ -0,0 +1,127 @@
+//! Simulation benchmark
+//!
+//! Run with:
+//!   cargo run --release --no-default-features --bin bench_sim [days] [seed]
+//!
+//! Plays many in-game days with the headless `GameSim` and prints total
+//! and per-operation timings. Needs neither a window nor an API key.
+//!
+//! Baseline: 10_000 days (the default) takes roughly 0.6s in a release
+//! build, about 60µs per day, most of it in `sleep` where the midnight
+//! rollover re-rolls industry trends. A large jump in any line usually
+//! means a regression in the simulation core.
+//!
+//! Ends with the best-case XP per day of each study strategy, so balance
+//! changes show up next to the timings.
+
+use std::time::{Duration, Instant};
+
+use ai_career_rpg::game::{study_strategies, BalanceParams, GameSim, SIM_INTERVIEW_THRESHOLD};
+use ai_career_rpg::player::CoffeeMenu;
+
+const DEFAULT_DAYS: u32 = 10_000;
+const DEFAULT_SEED: u64 = 42;
+
+/// Accumulated time and call count for one kind of operation
+#[derive(Default)]
+struct Timing {
+    total: Duration,
+    calls: u32,
+}
+
+impl Timing {
+    fn time<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.total += start.elapsed();
+        self.calls += 1;
+        result
+    }
+
+    fn report(&self, name: &str) {
+        let avg = if self.calls > 0 {
+            self.total / self.calls
+        } else {
+            Duration::ZERO
+        };
+        println!(
+            "{:<12} {:>9} calls  {:>10.2?} total  {:>9.2?} avg",
+            name, self.calls, self.total, avg
+        );
+    }
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let days: u32 = args.next().and_then(|a| a.parse().ok()).unwrap_or(DEFAULT_DAYS);
+    let seed: u64 = args.next().and_then(|a| a.parse().ok()).unwrap_or(DEFAULT_SEED);
+
+    println!("=== Simulation Benchmark: {} days, seed {} ===\n", days, seed);
+
+    let mut matching = Timing::default();
+    let mut interviews = Timing::default();
+    let mut studying = Timing::default();
+    let mut sleeping = Timing::default();
+    let mut interviews_passed = 0;
+
+    let start = Instant::now();
+    let mut sim = GameSim::new(seed);
+
+    for _ in 0..days {
+        let target = matching.time(|| sim.best_job());
+
+        if let Some((job, _)) = target.filter(|(_, score)| *score >= SIM_INTERVIEW_THRESHOLD) {
+            if !sim.state.player.employed && interviews.time(|| sim.interview(&job)) {
+                interviews_passed += 1;
+            }
+        }
+
+        // Study the first unmastered skill until energy runs out
+        loop {
+            let skill = sim
+                .state
+                .player
+                .skills
+                .iter()
+                .filter(|(_, s)| !s.is_maxed())
+                .map(|(name, _)| name.clone())
+                .min();
+            let Some(skill) = skill else { break };
+            if studying.time(|| sim.study(&skill)).is_err() {
+                break;
+            }
+        }
+
+        sleeping.time(|| sim.sleep());
+    }
+    let elapsed = start.elapsed();
+
+    matching.report("matching");
+    interviews.report("interview");
+    studying.report("study");
+    sleeping.report("sleep");
+
+    let player = &sim.state.player;
+    println!(
+        "\nReached day {} | career level {} | {} interview(s) passed | ${}",
+        sim.state.day,
+        player.career_level(),
+        interviews_passed,
+        player.money
+    );
+    println!("Total: {:.2?} ({:.2?} per day)", elapsed, elapsed / days.max(1));
+
+    println!("\n=== Study strategies (best case per day) ===\n");
+    let reports = study_strategies(&BalanceParams::current(), &CoffeeMenu::load());
+    for report in &reports {
+        let dominated_by = reports.iter().find(|other| report.is_dominated_by(other));
+        println!(
+            "{:<24} {:>2} sessions  {:>4} XP  ${:>4}{}",
+            report.name,
+            report.sessions_per_day,
+            report.xp_per_day,
+            report.cost_per_day,
+            dominated_by.map_or(String::new(), |other| format!("  (dominated by {})", other.name))
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! Simulation control server
+//!
+//! Run with:
+//!   cargo run --no-default-features --features server --bin sim_server [addr] [seed]
+//!
+//! Serves a headless `GameSim` over line-delimited JSON; see the `server`
+//! module for the protocol. Try it with
+//! `echo '{"cmd":"state"}' | nc 127.0.0.1 7878`.
+
+use ai_career_rpg::server::{serve, DEFAULT_SERVER_ADDR};
+
+const DEFAULT_SEED: u64 = 42;
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let addr = args.next().unwrap_or_else(|| DEFAULT_SERVER_ADDR.to_string());
+    let seed: u64 = args.next().and_then(|a| a.parse().ok()).unwrap_or(DEFAULT_SEED);
+
+    println!("Serving the simulation on {} (seed {})", addr, seed);
+    serve(&addr, seed)
+}
@@ -5,11 +5,15 @@
 //!
 //! Tests:
 //! - Config loading
-//! - NpcEngine with mock provider
-//! - NpcEngine with real API (optional, requires ANTHROPIC_API_KEY)
+//! - A mock `Scenario` of NPC and interview interactions (no API key needed)
+//! - Real API call (optional, requires ANTHROPIC_API_KEY)
+//!
+//! Exits with status 1 if any scenario step fails.
 
-use ai_career_rpg::engine::{GameConfig, GameContext, NpcEngine, NpcInput};
-use ai_career_rpg::llm::{LlmConfig, LlmProvider, Provider, create_provider, MockProvider};
+use ai_career_rpg::companies::get_all_companies;
+use ai_career_rpg::engine::{GameConfig, GameContext, Scenario, SkillInfo};
+use ai_career_rpg::interview::quiz::create_question_for_skill;
+use ai_career_rpg::llm::{LlmConfig, LlmProvider, create_provider};
 
 #[tokio::main]
 async fn main() {
@@ -31,76 +35,20 @@ async fn main() {
         }
     };
 
-    // Test 2: Create mock provider
-    println!("\n2. Testing mock provider...");
-    let mock = MockProvider::new("Hello, brave adventurer!");
-    let result = mock.complete("You are an NPC", vec![ai_career_rpg::llm::LlmMessage::user("Hi")])
-        .await
-        .expect("Mock should not fail");
-    println!("   ✓ Mock response: {}", result);
-
-    // Test 3: Create NpcEngine with mock
-    println!("\n3. Testing NpcEngine with mock provider...");
-    let test_config = GameConfig::load().expect("Config should load");
-    let mut engine = NpcEngine::with_mock(test_config, "I am a test NPC response!");
-    
-    let input = NpcInput {
-        npc_id: 1,
-        npc_class: "barista".to_string(),
-        npc_name: "Test Barista".to_string(),
-        player_message: None,
-    };
-    
-    let context = GameContext::empty();
-    
-    match engine.get_dialog(&input, &context).await {
-        Ok(output) => {
-            println!("   ✓ NPC response: {}", output.text);
-            println!("   - From LLM: {}", output.from_llm);
-        }
-        Err(e) => {
-            eprintln!("   ✗ Failed: {}", e);
-        }
+    // Test 2: Scripted NPC and interview interactions against the mock provider
+    println!("\n2. Running mock scenario...");
+    let report = smoke_scenario(config).run().await;
+    for line in report.to_string().lines() {
+        println!("   {}", line);
     }
 
-    // Test 4: Test LLM-powered NPC (recruiter has engine="llm" in config)
-    println!("\n4. Testing LLM-powered NPC (recruiter) with mock...");
-    let input = NpcInput {
-        npc_id: 2,
-        npc_class: "recruiter".to_string(),
-        npc_name: "Alex".to_string(),
-        player_message: Some("What jobs do you have?".to_string()),
-    };
-    
-    let context = GameContext {
-        player_name: "Test Player".to_string(),
-        top_skills: vec![
-            ai_career_rpg::engine::context::SkillInfo {
-                name: "Python".to_string(),
-                proficiency: "Intermediate".to_string(),
-            },
-        ],
-        employed: false,
-        current_job: None,
-        day: 5,
-    };
-    
-    match engine.get_dialog(&input, &context).await {
-        Ok(output) => {
-            println!("   ✓ Recruiter response: {}", output.text);
-            println!("   - From LLM: {}", output.from_llm);
-        }
-        Err(e) => {
-            eprintln!("   ✗ Failed: {}", e);
-        }
-    }
-
-    // Test 5: Real API test (optional)
-    println!("\n5. Testing real API (if credentials available)...");
+    // Test 3: Real API test (optional)
+    println!("\n3. Testing real API (if credentials available)...");
     
     let provider_config = LlmConfig {
         provider: "anthropic".to_string(),
         model: "glm-4.7".to_string(),
+        fallbacks: Vec::new(),
     };
     
     match create_provider(&provider_config) {
@@ -127,4 +75,41 @@ async fn main() {
     }
 
     println!("\n=== Test Complete ===");
+    if !report.passed() {
+        std::process::exit(1);
+    }
+}
+
+/// Interactions every prompt change should keep working
+///
+/// The recruiter goes through the LLM in the bundled config, and the
+/// barista through rules, so both paths get exercised. Interviews are
+/// switched to the LLM so the mentor and tutor prompts run too.
+fn smoke_scenario(mut config: GameConfig) -> Scenario {
+    config.game.use_llm = true;
+    config.interview.engine = "llm".to_string();
+    let job = get_all_companies()[0].open_positions[0].clone();
+    let context = GameContext {
+        player_name: "Test Player".to_string(),
+        top_skills: vec![SkillInfo {
+            name: "Python".to_string(),
+            proficiency: "Intermediate".to_string(),
+        }],
+        employed: false,
+        current_job: None,
+        day: 5,
+    };
+
+    Scenario::new("smoke test", config)
+        .context(context)
+        .npc("barista", "Test Barista", None)
+        .npc("recruiter", "Alex", Some("What jobs do you have?"))
+        .responds("We're hiring ML engineers - your Python will help!")
+        .expect("ML engineers")
+        .advice(&job, &["Explain the attention mechanism in transformers"])
+        .responds("Focus on Transformers next, then practice system design.")
+        .expect("Transformers")
+        .explain(&create_question_for_skill("SQL"))
+        .responds("WHERE filters rows before they are grouped or returned.")
+        .expect("WHERE")
 }
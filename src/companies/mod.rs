@@ -4,6 +4,7 @@
 //! Provides access to all companies and their open positions.
 
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use crate::jobs::{Company, CompanyTier, Job, SkillRequirement};
@@ -36,7 +37,10 @@ struct JobConfig {
 struct CompanyConfig {
     name: String,
     description: String,
-    tier: String,
+    tier: CompanyTier,
+    /// Skills the company's interviews lean toward, with extra weight
+    #[serde(default)]
+    emphasis: HashMap<String, f32>,
     jobs: Vec<JobConfig>,
 }
 
@@ -50,16 +54,6 @@ fn parse_proficiency(s: &str) -> Proficiency {
     Proficiency::from_str(s).unwrap_or(Proficiency::None)
 }
 
-fn parse_tier(s: &str) -> CompanyTier {
-    match s {
-        "Startup" => CompanyTier::Startup,
-        "MidSize" => CompanyTier::MidSize,
-        "BigTech" => CompanyTier::BigTech,
-        "Faang" => CompanyTier::Faang,
-        _ => CompanyTier::Startup,
-    }
-}
-
 fn convert_job_config(job: JobConfig, company_name: &str) -> Job {
     Job {
         id: job.id,
@@ -94,12 +88,60 @@ pub fn get_all_companies() -> Vec<Company> {
         .map(|c| Company {
             name: c.name.clone(),
             description: c.description,
-            tier: parse_tier(&c.tier),
+            tier: c.tier,
             open_positions: c
                 .jobs
                 .into_iter()
                 .map(|j| convert_job_config(j, &c.name))
                 .collect(),
+            emphasis: c.emphasis,
         })
         .collect()
 }
+
+/// The company with the given name
+pub fn find(company_name: &str) -> Option<Company> {
+    get_all_companies().into_iter().find(|c| c.name == company_name)
+}
+
+/// Tier of the company with the given name
+pub fn tier_of(company_name: &str) -> Option<CompanyTier> {
+    find(company_name).map(|c| c.tier)
+}
+
+/// Companies revealed to a player at the given career level
+pub fn get_visible_companies(career_level: u32) -> Vec<Company> {
+    get_all_companies()
+        .into_iter()
+        .filter(|c| c.tier.min_career_level() <= career_level)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_companies_grow_with_career_level() {
+        let fresh = get_visible_companies(0);
+        assert!(!fresh.is_empty());
+        assert!(fresh.iter().all(|c| c.tier == CompanyTier::Startup));
+        assert_eq!(get_visible_companies(u32::MAX).len(), get_all_companies().len());
+    }
+
+    #[test]
+    fn test_search_giant_loaded_from_config() {
+        let companies = get_all_companies();
+        let giant = companies.iter().find(|c| c.name == "SearchGiant").unwrap();
+        assert_eq!(giant.tier, CompanyTier::Faang);
+        assert!(giant.open_positions.iter().any(|j| j.title == "Staff LLM Engineer"));
+        assert!(giant.emphasis["RAG"] > 0.0);
+    }
+
+    #[test]
+    fn test_tier_of() {
+        let company = &get_all_companies()[0];
+        assert_eq!(tier_of(&company.name), Some(company.tier));
+        assert_eq!(tier_of("No Such Company"), None);
+    }
+}
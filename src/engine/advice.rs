@@ -0,0 +1,218 @@
+//! Mentor Advice Engine
+//!
+//! Turns a failed interview into study advice.
+//!
+//! # Flow
+//! 1. Resolve engine type (same as interviews)
+//! 2. If engine == Rule: build advice from the job's skill gaps
+//! 3. If engine == Llm:
+//!    a. Check cache, keyed by job and skill-profile bucket
+//!    b. Send failed questions + skill profile to the LLM
+//!    c. Cache response
+//! 4. If engine == Hybrid: Try LLM, fallback to rule
+
+use std::sync::Arc;
+use anyhow::Result;
+
+use crate::jobs::Job;
+use crate::llm::{LlmMessage, LlmProvider, MockProvider, Provider};
+use crate::player::Player;
+use super::cache::ResponseCache;
+use super::config::GameConfig;
+use super::rate_limit::{self, RateLimiter, SharedLimiter, Throttled};
+use super::traits::EngineType;
+
+/// System prompt for mentor advice
+const MENTOR_PERSONA: &str = "You are an experienced AI engineering mentor. \
+A mentee just failed a job interview. Suggest which skills to focus on next, \
+in one short encouraging paragraph of at most 3 sentences.";
+
+/// Output from advice generation
+pub struct AdviceOutput {
+    /// Advice paragraph to show the player
+    pub text: String,
+    /// Whether this was generated by LLM or rule engine
+    pub from_llm: bool,
+}
+
+/// Generates study advice after failed interviews
+pub struct AdviceEngine {
+    /// LLM provider (shared with other engines)
+    provider: Arc<Provider>,
+    /// Advice cache, keyed by job and skill-profile bucket
+    cache: ResponseCache,
+    /// Caps on LLM calls (shared with other engines when built by `GameLlm`)
+    limiter: SharedLimiter,
+    /// Game configuration
+    config: GameConfig,
+}
+
+impl AdviceEngine {
+    /// Create engine using a shared provider
+    pub fn with_provider(config: GameConfig, provider: Arc<Provider>) -> Self {
+        Self {
+            provider,
+            cache: ResponseCache::new(),
+            limiter: RateLimiter::from_config(&config.llm).shared(),
+            config,
+        }
+    }
+
+    /// Count LLM calls against a limiter shared with other engines
+    pub fn with_limiter(mut self, limiter: SharedLimiter) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    /// Create engine with mock provider (for testing)
+    pub fn with_mock(config: GameConfig, response: &str) -> Self {
+        Self::with_provider(config, Arc::new(Provider::Mock(MockProvider::new(response))))
+    }
+
+    /// Get advice for a failed interview
+    ///
+    /// # Arguments
+    /// * `job` - The job that was interviewed for
+    /// * `player` - The player's current state
+    /// * `failed_questions` - Questions answered incorrectly
+    pub async fn get_advice(
+        &mut self,
+        job: &Job,
+        player: &Player,
+        failed_questions: &[String],
+    ) -> Result<AdviceOutput> {
+        let (text, from_llm) = match self.config.get_interview_engine() {
+            EngineType::Rule => (Self::rule_advice(job, player), false),
+            EngineType::Llm => match self.llm_advice(job, player, failed_questions).await {
+                Ok(text) => (text, true),
+                Err(e) if e.is::<Throttled>() => (Self::rule_advice(job, player), false),
+                Err(e) => return Err(e),
+            },
+            EngineType::Hybrid => match self.llm_advice(job, player, failed_questions).await {
+                Ok(text) => (text, true),
+                Err(_) => (Self::rule_advice(job, player), false),
+            },
+        };
+
+        Ok(AdviceOutput { text, from_llm })
+    }
+
+    /// Build advice from the job's skill gaps
+    pub fn rule_advice(job: &Job, player: &Player) -> String {
+        let gaps = job.skill_gaps(&player.skills);
+        let Some(first) = gaps.first() else {
+            return format!(
+                "You have the skills for {}. Review the questions you missed and try again.",
+                job.title
+            );
+        };
+
+        let mut advice = format!(
+            "Focus on {} next: you're at {}, and {} expects {}.",
+            first.skill_name,
+            first.current.as_str(),
+            job.company,
+            first.required.as_str()
+        );
+        let others: Vec<&str> = gaps.iter().skip(1).take(2).map(|g| g.skill_name.as_str()).collect();
+        if !others.is_empty() {
+            advice.push_str(&format!(" After that, brush up on {}.", others.join(" and ")));
+        }
+        advice
+    }
+
+    /// Coarse fingerprint of the player's proficiency in the job's skills
+    ///
+    /// Players with the same levels in the relevant skills get the same
+    /// advice, so it can be served from cache.
+    pub fn profile_bucket(job: &Job, player: &Player) -> String {
+        job.requirements
+            .iter()
+            .map(|req| (player.get_skill_proficiency(&req.skill_name) as u8).to_string())
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    /// Get LLM-powered advice
+    async fn llm_advice(
+        &mut self,
+        job: &Job,
+        player: &Player,
+        failed_questions: &[String],
+    ) -> Result<String> {
+        let cache_key = format!("advice|{}|{}", job.id, Self::profile_bucket(job, player));
+        if let Some(cached) = self.cache.get(&cache_key) {
+            return Ok(cached);
+        }
+
+        let profile = job
+            .requirements
+            .iter()
+            .map(|req| {
+                format!(
+                    "- {}: {} (job wants {})",
+                    req.skill_name,
+                    player.get_skill_proficiency(&req.skill_name).as_str(),
+                    req.min_proficiency.as_str()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let missed = if failed_questions.is_empty() {
+            "None recorded".to_string()
+        } else {
+            failed_questions.iter().map(|q| format!("- {}", q)).collect::<Vec<_>>().join("\n")
+        };
+
+        let prompt = format!(
+            "I failed the interview for {} at {}.\n\nQuestions I got wrong:\n{}\n\nMy skills:\n{}",
+            job.title, job.company, missed, profile
+        );
+        rate_limit::acquire(&self.limiter)?;
+        let response = self
+            .provider
+            .complete(MENTOR_PERSONA, vec![LlmMessage::user(prompt)])
+            .await?;
+
+        self.cache.set(cache_key, response.clone());
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::tests::test_job_requiring;
+    use crate::skills::Proficiency;
+
+    fn job() -> Job {
+        test_job_requiring(&[("PyTorch", Proficiency::Intermediate)])
+    }
+
+    #[test]
+    fn test_rule_advice_names_missing_skill() {
+        let player = Player::new("Test");
+        let advice = AdviceEngine::rule_advice(&job(), &player);
+        assert!(advice.contains("PyTorch"));
+    }
+
+    #[tokio::test]
+    async fn test_llm_advice_is_cached_per_profile() {
+        let mut config = GameConfig::load().unwrap();
+        config.interview.engine = "llm".to_string();
+        config.game.use_llm = true;
+        let mock = MockProvider::new("Study PyTorch.");
+        let mut engine = AdviceEngine::with_provider(config, Arc::new(Provider::Mock(mock.clone())));
+        let mut player = Player::new("Test");
+
+        let missed = vec!["What is backpropagation?".to_string()];
+        let first = engine.get_advice(&job(), &player, &missed).await.unwrap();
+        engine.get_advice(&job(), &player, &missed).await.unwrap();
+        assert!(first.from_llm);
+        assert_eq!(mock.get_requests().len(), 1);
+
+        player.skills.get_mut("PyTorch").unwrap().proficiency = Proficiency::Basic;
+        engine.get_advice(&job(), &player, &missed).await.unwrap();
+        assert_eq!(mock.get_requests().len(), 2);
+    }
+}
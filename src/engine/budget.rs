@@ -0,0 +1,84 @@
+//! Token Budget
+//!
+//! Keeps a prompt (system prompt plus conversation history) under a
+//! configured token budget before it is sent to the provider, so long
+//! chats don't run past the model's context window.
+//!
+//! Token counts are a rough estimate (about four characters per token),
+//! which is close enough for trimming and needs no tokenizer.
+
+use crate::llm::LlmMessage;
+
+/// Budget used when `[llm] max_prompt_tokens` is not set
+pub const DEFAULT_MAX_PROMPT_TOKENS: usize = 4000;
+/// Fixed per-message cost for role markers and formatting
+const MESSAGE_OVERHEAD_TOKENS: usize = 4;
+
+/// Rough token count for a piece of text
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Estimated tokens for a full request
+pub fn prompt_tokens(system: &str, messages: &[LlmMessage]) -> usize {
+    estimate_tokens(system)
+        + messages
+            .iter()
+            .map(|m| estimate_tokens(&m.content) + MESSAGE_OVERHEAD_TOKENS)
+            .sum::<usize>()
+}
+
+/// Drop the oldest messages until the prompt fits in `budget`
+///
+/// The system prompt and the latest message are always kept, even if
+/// they alone exceed the budget. The remaining history also starts on a
+/// user turn, as providers expect. Returns how many messages were removed.
+pub fn trim_to_budget(system: &str, messages: &mut Vec<LlmMessage>, budget: usize) -> usize {
+    let before = messages.len();
+    while messages.len() > 1 && prompt_tokens(system, messages) > budget {
+        messages.remove(0);
+    }
+    while messages.len() > 1 && messages[0].role != "user" {
+        messages.remove(0);
+    }
+    before - messages.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trims_oldest_and_keeps_latest() {
+        let system = "You are a recruiter.";
+        let mut messages = Vec::new();
+        for i in 0..20 {
+            messages.push(LlmMessage::user(format!("Question {} {}", i, "x".repeat(100))));
+            messages.push(LlmMessage::assistant(format!("Answer {} {}", i, "y".repeat(100))));
+        }
+        messages.push(LlmMessage::user("What now?"));
+        let budget = 300;
+        assert!(prompt_tokens(system, &messages) > budget);
+
+        let removed = trim_to_budget(system, &mut messages, budget);
+
+        assert!(removed > 0);
+        assert!(prompt_tokens(system, &messages) <= budget);
+        assert_eq!(messages.last().unwrap().content, "What now?");
+        assert_eq!(messages[0].role, "user");
+    }
+
+    #[test]
+    fn test_latest_message_kept_even_over_budget() {
+        let mut messages = vec![LlmMessage::user("z".repeat(400))];
+        assert_eq!(trim_to_budget("system", &mut messages, 10), 0);
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn test_under_budget_untouched() {
+        let mut messages = vec![LlmMessage::user("Hi"), LlmMessage::assistant("Hello")];
+        assert_eq!(trim_to_budget("system", &mut messages, 1000), 0);
+        assert_eq!(messages.len(), 2);
+    }
+}
@@ -4,21 +4,40 @@
 //! Uses context hashing to ensure relevant cache hits.
 //!
 //! # Cache Key
-//! Tuple of (activity_type, input_hash, context_hash)
+//! Tuple of (activity_type, input_hash, turn, history_hash, context_hash)
 //! - `activity_type`: "npc_dialog", "interview", etc.
 //! - `input_hash`: Hash of NPC class / prompt
+//! - `turn`: Position in the conversation
+//! - `history_hash`: Hash of the most recent conversation messages
 //! - `context_hash`: Hash of player state (skills, day, etc.)
 //!
 //! # Cache Policy
 //! - TTL: 5 minutes (configurable)
-//! - Storage: In-memory, cleared on game exit
+//! - Storage: In-memory; owners that persist it write `to_json` on shutdown
 //! - Max entries: 100 per activity (LRU eviction)
+//! - Invalidation: everything (`clear`), one activity and those under it
+//!   (`invalidate_activity`), or every entry made for a different player
+//!   state (`invalidate_for_context`)
 
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
+use serde::{Deserialize, Serialize};
+
+use crate::llm::LlmMessage;
 use super::context::GameContext;
 
+/// Where a persisted cache is written, relative to the working directory
+pub const DEFAULT_CACHE_FILE: &str = "cache/llm_responses.json";
+
+/// A cache entry as written to disk; its age stands in for the `Instant`
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedEntry {
+    key: String,
+    response: String,
+    age_secs: u64,
+}
+
 /// LLM response cache entry
 struct CacheEntry {
     /// Cached response text
@@ -78,14 +97,40 @@ impl ResponseCache {
         }
     }
 
-    /// Generate a cache key from activity, input, and context
+    /// Number of trailing history messages folded into the cache key
+    const HISTORY_WINDOW: usize = 4;
+
+    /// Generate a cache key from activity, input, conversation state, and context
     ///
     /// The key is a combination of:
     /// - Activity type (e.g., "npc_recruiter")
-    /// - Input identifier (e.g., conversation turn or question)
+    /// - Input identifier (e.g., player message or question)
+    /// - Conversation turn index
+    /// - Hash of the last few history messages
     /// - Context hash (relevant player state)
-    pub fn make_key(activity: &str, input_id: &str, context: &GameContext) -> String {
-        // Create a context hash from relevant fields
+    pub fn make_key(
+        activity: &str,
+        input_id: &str,
+        turn: usize,
+        history: &[LlmMessage],
+        context: &GameContext,
+    ) -> String {
+        let recent = &history[history.len().saturating_sub(Self::HISTORY_WINDOW)..];
+        let history_str = recent
+            .iter()
+            .map(|m| format!("{}:{}", m.role, m.content))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let history_hash = Self::simple_hash(&history_str);
+
+        format!(
+            "{}|{}|{}|{:08x}|{:08x}",
+            activity, input_id, turn, history_hash, Self::context_hash(context)
+        )
+    }
+
+    /// Hash of the player state a response depends on
+    fn context_hash(context: &GameContext) -> u32 {
         // We only include fields that affect the response
         let context_str = format!(
             "{}|{:?}|{}|{}",
@@ -100,9 +145,7 @@ impl ResponseCache {
         );
 
         // Simple hash (good enough for caching)
-        let context_hash = Self::simple_hash(&context_str);
-
-        format!("{}|{}|{:08x}", activity, input_id, context_hash)
+        Self::simple_hash(&context_str)
     }
 
     /// Simple string hash for cache keys
@@ -167,6 +210,33 @@ impl ResponseCache {
         self.access_order.clear();
     }
 
+    /// Drop entries for an activity and the activities under it
+    ///
+    /// "npc" drops every NPC's entries, "npc_recruiter" only the
+    /// recruiter's.
+    pub fn invalidate_activity(&mut self, activity: &str) {
+        self.retain_keys(|key| {
+            let key_activity = key.split('|').next().unwrap_or_default();
+            let under = key_activity
+                .strip_prefix(activity)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('_'));
+            !under
+        });
+    }
+
+    /// Drop entries made for a different player state than `context`,
+    /// e.g. after a big skill change
+    pub fn invalidate_for_context(&mut self, context: &GameContext) {
+        let current = format!("{:08x}", Self::context_hash(context));
+        self.retain_keys(|key| key.rsplit('|').next() == Some(current.as_str()));
+    }
+
+    /// Keep only the entries whose key passes `keep`
+    fn retain_keys(&mut self, keep: impl Fn(&str) -> bool) {
+        self.entries.retain(|key, _| keep(key));
+        self.access_order.retain(|key| keep(key));
+    }
+
     /// Get number of cached entries (for debugging)
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -176,6 +246,36 @@ impl ResponseCache {
     pub fn is_empty(&self) -> bool {
         self.entries.is_empty()
     }
+
+    /// Unexpired entries as JSON, least recently used first
+    pub fn to_json(&self) -> serde_json::Result<Vec<u8>> {
+        let entries: Vec<PersistedEntry> = self
+            .access_order
+            .iter()
+            .filter_map(|key| {
+                let entry = self.entries.get(key).filter(|e| !e.is_expired(self.ttl))?;
+                Some(PersistedEntry {
+                    key: key.clone(),
+                    response: entry.response.clone(),
+                    age_secs: entry.created_at.elapsed().as_secs(),
+                })
+            })
+            .collect();
+        serde_json::to_vec(&entries)
+    }
+
+    /// Add entries written by `to_json`, keeping how old they were
+    pub fn restore_json(&mut self, json: &[u8]) -> serde_json::Result<()> {
+        let entries: Vec<PersistedEntry> = serde_json::from_slice(json)?;
+        for persisted in entries {
+            let age = Duration::from_secs(persisted.age_secs);
+            self.set(persisted.key.clone(), persisted.response);
+            if let (Some(entry), Some(created_at)) = (self.entries.get_mut(&persisted.key), Instant::now().checked_sub(age)) {
+                entry.created_at = created_at;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Default for ResponseCache {
@@ -206,18 +306,47 @@ mod tests {
             day: 5,
         };
 
-        let key1 = ResponseCache::make_key("npc", "recruiter", &ctx1);
-        let key2 = ResponseCache::make_key("npc", "recruiter", &ctx2);
+        let key1 = ResponseCache::make_key("npc", "recruiter", 0, &[], &ctx1);
+        let key2 = ResponseCache::make_key("npc", "recruiter", 0, &[], &ctx2);
 
         // Different players should have different keys
         assert_ne!(key1, key2);
     }
 
+    #[test]
+    fn test_cache_key_varies_by_turn() {
+        let ctx = GameContext::empty();
+        let history = vec![
+            LlmMessage::user("Any openings?"),
+            LlmMessage::assistant("Plenty!"),
+        ];
+
+        let turn1 = ResponseCache::make_key("npc", "Tell me more", 0, &[], &ctx);
+        let turn3 = ResponseCache::make_key("npc", "Tell me more", 2, &history, &ctx);
+        assert_ne!(turn1, turn3);
+
+        // Identical conversational states still share a key
+        let again = ResponseCache::make_key("npc", "Tell me more", 2, &history, &ctx);
+        assert_eq!(turn3, again);
+    }
+
+    #[test]
+    fn test_cache_key_varies_by_history() {
+        let ctx = GameContext::empty();
+        let a = vec![LlmMessage::user("Hi"), LlmMessage::assistant("Hello!")];
+        let b = vec![LlmMessage::user("Hi"), LlmMessage::assistant("Go away.")];
+
+        assert_ne!(
+            ResponseCache::make_key("npc", "And?", 2, &a, &ctx),
+            ResponseCache::make_key("npc", "And?", 2, &b, &ctx)
+        );
+    }
+
     #[test]
     fn test_cache_set_and_get() {
         let mut cache = ResponseCache::new();
         let ctx = GameContext::empty();
-        let key = ResponseCache::make_key("test", "input", &ctx);
+        let key = ResponseCache::make_key("test", "input", 0, &[], &ctx);
 
         cache.set(key.clone(), "response".to_string());
 
@@ -233,7 +362,6 @@ mod tests {
     #[test]
     fn test_cache_lru_eviction() {
         let mut cache = ResponseCache::with_settings(Duration::from_secs(3600), 3);
-        let ctx = GameContext::empty();
 
         // Add 3 entries
         cache.set("key1".to_string(), "v1".to_string());
@@ -251,4 +379,60 @@ mod tests {
         assert_eq!(cache.get("key3"), Some("v3".to_string())); // Still there
         assert_eq!(cache.get("key4"), Some("v4".to_string())); // New entry
     }
+
+    #[test]
+    fn test_invalidating_npc_keeps_interview_entries() {
+        let mut cache = ResponseCache::new();
+        let ctx = GameContext::empty();
+        let recruiter = ResponseCache::make_key("npc_recruiter_0", "Hi", 0, &[], &ctx);
+        let barista = ResponseCache::make_key("npc_barista_2", "Hi", 0, &[], &ctx);
+        let interview = ResponseCache::make_key("interview", "q1", 0, &[], &ctx);
+        let lookalike = ResponseCache::make_key("npcs", "Hi", 0, &[], &ctx);
+        for key in [&recruiter, &barista, &interview, &lookalike] {
+            cache.set(key.clone(), "response".to_string());
+        }
+
+        cache.invalidate_activity("npc_recruiter");
+        assert_eq!(cache.get(&recruiter), None);
+        assert!(cache.get(&barista).is_some());
+
+        cache.invalidate_activity("npc");
+        assert_eq!(cache.get(&barista), None);
+        assert_eq!(cache.get(&interview), Some("response".to_string()));
+        assert!(cache.get(&lookalike).is_some());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.access_order, vec![interview, lookalike]);
+    }
+
+    #[test]
+    fn test_invalidating_for_context_drops_stale_player_state() {
+        let mut cache = ResponseCache::new();
+        let before = GameContext::empty();
+        let after = GameContext { employed: true, ..GameContext::empty() };
+        let stale = ResponseCache::make_key("npc_recruiter_0", "Hi", 0, &[], &before);
+        let fresh = ResponseCache::make_key("interview", "q1", 0, &[], &after);
+        cache.set(stale.clone(), "old".to_string());
+        cache.set(fresh.clone(), "new".to_string());
+
+        cache.invalidate_for_context(&after);
+
+        assert_eq!(cache.access_order, vec![fresh.clone()]);
+        assert_eq!(cache.get(&stale), None);
+        assert_eq!(cache.get(&fresh), Some("new".to_string()));
+    }
+
+    #[test]
+    fn test_cache_survives_a_json_round_trip() {
+        let mut cache = ResponseCache::with_settings(Duration::from_secs(3600), 3);
+        cache.set("key1".to_string(), "v1".to_string());
+        cache.set("key2".to_string(), "v2".to_string());
+        cache.get("key1");
+
+        let mut restored = ResponseCache::with_settings(Duration::from_secs(3600), 3);
+        restored.restore_json(&cache.to_json().unwrap()).unwrap();
+
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.access_order, vec!["key2", "key1"]);
+        assert_eq!(restored.get("key1"), Some("v1".to_string()));
+    }
 }
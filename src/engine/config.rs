@@ -5,9 +5,16 @@
 //!
 //! # Config File Structure (game_config.toml)
 //! ```toml
+//! [game]
+//! use_llm = true
+//!
 //! [llm]
 //! provider = "anthropic"
 //! model = "glm-4.7"
+//! fallback_providers = ["mock"]
+//! max_prompt_tokens = 4000
+//! max_calls_per_minute = 20
+//! max_calls_per_session = 300
 //!
 //! [npc]
 //! default_engine = "rule"
@@ -18,6 +25,20 @@
 //!
 //! [interview]
 //! engine = "llm"
+//! passing_policy = "average"   # or "all_rounds"
+//! average_threshold = 0.6
+//! negative_marking = true
+//! wrong_answer_penalty = 0.25
+//!
+//! [assist]
+//! auto_rest = true
+//! home_range = 6.0
+//!
+//! [hud]
+//! energy_low = 30
+//! energy_critical = 10
+//! money_low = 100
+//! pulse_critical = true
 //! ```
 
 use anyhow::{Context, Result};
@@ -25,6 +46,37 @@ use serde::Deserialize;
 use std::collections::HashMap;
 
 use super::traits::EngineType;
+use crate::interview::quiz::DEFAULT_WRONG_ANSWER_PENALTY;
+use crate::interview::session::{InterviewSession, PassingPolicy, DEFAULT_AVERAGE_THRESHOLD};
+use crate::interview::InterviewRound;
+use crate::llm::provider::SUPPORTED_PROVIDERS;
+
+/// Environment variable that overrides `[game] use_llm`
+pub const USE_LLM_ENV: &str = "AI_CAREER_USE_LLM";
+
+/// Global game settings
+#[derive(Debug, Clone, Deserialize)]
+pub struct GameSettings {
+    /// When false, every engine resolves to rule and no provider is contacted
+    #[serde(default = "default_use_llm")]
+    pub use_llm: bool,
+    /// Seed for a randomized city layout; 0 keeps the hand-authored map
+    #[serde(default)]
+    pub map_seed: u64,
+}
+
+fn default_use_llm() -> bool {
+    true
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            use_llm: default_use_llm(),
+            map_seed: 0,
+        }
+    }
+}
 
 /// LLM configuration
 #[derive(Debug, Clone, Deserialize)]
@@ -33,6 +85,30 @@ pub struct LlmConfig {
     pub provider: String,
     /// Model identifier
     pub model: String,
+    /// Providers tried in order when `provider` fails, e.g. `["mock"]`
+    #[serde(default)]
+    pub fallback_providers: Vec<String>,
+    /// Estimated token limit for system prompt plus history; older turns are trimmed to fit
+    #[serde(default = "default_max_prompt_tokens")]
+    pub max_prompt_tokens: usize,
+    /// Completions allowed in any rolling minute
+    #[serde(default = "default_max_calls_per_minute")]
+    pub max_calls_per_minute: u32,
+    /// Completions allowed for the whole game session
+    #[serde(default = "default_max_calls_per_session")]
+    pub max_calls_per_session: u32,
+}
+
+fn default_max_prompt_tokens() -> usize {
+    super::budget::DEFAULT_MAX_PROMPT_TOKENS
+}
+
+fn default_max_calls_per_minute() -> u32 {
+    super::rate_limit::DEFAULT_MAX_CALLS_PER_MINUTE
+}
+
+fn default_max_calls_per_session() -> u32 {
+    super::rate_limit::DEFAULT_MAX_CALLS_PER_SESSION
 }
 
 /// NPC class configuration
@@ -46,6 +122,10 @@ pub struct NpcClassConfig {
     /// Fallback dialog lines for rule engine
     #[serde(default)]
     pub fallback_dialog: Vec<String>,
+    /// Alternate multi-line exchanges for the in-world NPC; one is picked
+    /// at random per conversation
+    #[serde(default)]
+    pub dialog_variants: Vec<Vec<String>>,
 }
 
 /// NPC configuration
@@ -65,16 +145,102 @@ pub struct InterviewConfig {
     /// Engine type for interviews
     #[serde(default)]
     pub engine: String,
+    /// Whether every round must pass or only the average score counts
+    #[serde(default)]
+    pub passing_policy: PassingPolicy,
+    /// Average round score needed under the `average` policy
+    #[serde(default = "default_average_threshold")]
+    pub average_threshold: f32,
+    /// Whether wrong quiz answers cost points on top of their confidence score
+    #[serde(default)]
+    pub negative_marking: bool,
+    /// Points each wrong answer loses under negative marking
+    #[serde(default = "default_wrong_answer_penalty")]
+    pub wrong_answer_penalty: f32,
+}
+
+fn default_average_threshold() -> f32 {
+    DEFAULT_AVERAGE_THRESHOLD
+}
+
+fn default_wrong_answer_penalty() -> f32 {
+    DEFAULT_WRONG_ANSWER_PENALTY
+}
+
+impl InterviewConfig {
+    /// Session for the given rounds, judged by the configured policy
+    pub fn new_session(&self, rounds: Vec<InterviewRound>) -> InterviewSession {
+        InterviewSession::with_policy(rounds, self.passing_policy, self.average_threshold)
+    }
+
+    /// Points a wrong quiz answer loses; zero unless negative marking is on
+    pub fn marking_penalty(&self) -> f32 {
+        if self.negative_marking {
+            self.wrong_answer_penalty
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Optional play assists, all off by default
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct AssistConfig {
+    /// Offer to rest and retry when an action near home runs short of energy
+    pub auto_rest: bool,
+    /// How close to the apartment, in tiles, still counts as home
+    pub home_range: f32,
+}
+
+impl Default for AssistConfig {
+    fn default() -> Self {
+        Self {
+            auto_rest: false,
+            home_range: 6.0,
+        }
+    }
+}
+
+/// Thresholds for HUD warnings
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct HudConfig {
+    /// Energy below this is shown as a warning
+    pub energy_low: u32,
+    /// Energy below this is shown as critical
+    pub energy_critical: u32,
+    /// Money below this is shown as a warning; zero is always critical
+    pub money_low: u32,
+    /// Whether critical warnings pulse
+    pub pulse_critical: bool,
+}
+
+impl Default for HudConfig {
+    fn default() -> Self {
+        Self {
+            energy_low: 30,
+            energy_critical: 10,
+            money_low: 100,
+            pulse_critical: true,
+        }
+    }
 }
 
 /// Root game configuration
 #[derive(Debug, Clone, Deserialize)]
 pub struct GameConfig {
+    #[serde(default)]
+    pub game: GameSettings,
     pub llm: LlmConfig,
     #[serde(default)]
     pub npc: NpcConfig,
     #[serde(default)]
     pub interview: InterviewConfig,
+    #[serde(default)]
+    pub assist: AssistConfig,
+    #[serde(default)]
+    pub hud: HudConfig,
 }
 
 impl Default for NpcConfig {
@@ -90,6 +256,10 @@ impl Default for InterviewConfig {
     fn default() -> Self {
         Self {
             engine: "rule".to_string(),
+            passing_policy: PassingPolicy::default(),
+            average_threshold: default_average_threshold(),
+            negative_marking: false,
+            wrong_answer_penalty: default_wrong_answer_penalty(),
         }
     }
 }
@@ -100,13 +270,35 @@ impl GameConfig {
     /// The config file is embedded in the binary at compile time.
     pub fn load() -> Result<Self> {
         const CONFIG: &str = include_str!("../config/game_config.toml");
-        toml::from_str(CONFIG).context("Failed to parse game_config.toml")
+        let mut config: Self = toml::from_str(CONFIG).context("Failed to parse game_config.toml")?;
+        config.apply_env_overrides();
+        Ok(config)
+    }
+
+    /// Apply overrides from environment variables
+    pub fn apply_env_overrides(&mut self) {
+        if let Ok(value) = std::env::var(USE_LLM_ENV) {
+            match value.to_lowercase().as_str() {
+                "0" | "false" | "no" | "off" => self.game.use_llm = false,
+                "1" | "true" | "yes" | "on" => self.game.use_llm = true,
+                _ => {}
+            }
+        }
+    }
+
+    /// Whether any engine may use the LLM
+    pub fn llm_enabled(&self) -> bool {
+        self.game.use_llm
     }
 
     /// Get the engine type for an NPC class
     ///
-    /// Falls back to default_engine if class not configured
+    /// Falls back to default_engine if class not configured.
+    /// Always Rule when the LLM is disabled globally.
     pub fn get_npc_engine(&self, class_name: &str) -> EngineType {
+        if !self.llm_enabled() {
+            return EngineType::Rule;
+        }
         if let Some(class) = self.npc.classes.get(class_name) {
             if let Some(engine) = &class.engine {
                 return engine.parse().unwrap_or(EngineType::Rule);
@@ -115,6 +307,16 @@ impl GameConfig {
         self.npc.default_engine.parse().unwrap_or(EngineType::Rule)
     }
 
+    /// Get the engine type for interviews
+    ///
+    /// Always Rule when the LLM is disabled globally.
+    pub fn get_interview_engine(&self) -> EngineType {
+        if !self.llm_enabled() {
+            return EngineType::Rule;
+        }
+        self.interview.engine.parse().unwrap_or(EngineType::Rule)
+    }
+
     /// Get persona for an NPC class
     pub fn get_npc_persona(&self, class_name: &str) -> Option<&str> {
         self.npc
@@ -127,6 +329,88 @@ impl GameConfig {
     pub fn get_npc_fallback_dialog(&self, class_name: &str) -> Option<&Vec<String>> {
         self.npc.classes.get(class_name).map(|c| &c.fallback_dialog)
     }
+
+    /// Extra exchanges configured for an NPC class, empty if none
+    pub fn get_npc_dialog_variants(&self, class_name: &str) -> &[Vec<String>] {
+        self.npc.classes.get(class_name).map_or(&[], |c| &c.dialog_variants)
+    }
+
+    /// Check the whole config, collecting every problem instead of stopping at the first
+    ///
+    /// Catches what parsing lets through: unknown engine or provider names,
+    /// LLM classes without a persona and out-of-range numbers. The getters
+    /// above quietly fall back to rule for bad engine names, so without this
+    /// a typo only shows up as an NPC that never talks through the LLM.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        if !SUPPORTED_PROVIDERS.contains(&self.llm.provider.as_str()) {
+            problems.push(format!("[llm] unknown provider \"{}\"", self.llm.provider));
+        }
+        for name in &self.llm.fallback_providers {
+            if !SUPPORTED_PROVIDERS.contains(&name.as_str()) {
+                problems.push(format!("[llm] unknown fallback provider \"{}\"", name));
+            }
+        }
+        if self.llm.max_prompt_tokens == 0 {
+            problems.push("[llm] max_prompt_tokens must be greater than 0".to_string());
+        }
+
+        let default_engine = check_engine(&self.npc.default_engine, "[npc] default_engine", &mut problems);
+        let mut classes: Vec<_> = self.npc.classes.iter().collect();
+        classes.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, class) in classes {
+            let section = format!("[npc.classes.{}]", name);
+            let engine = match &class.engine {
+                Some(engine) => check_engine(engine, &format!("{} engine", section), &mut problems),
+                None => default_engine,
+            };
+            let wants_llm = matches!(engine, Some(EngineType::Llm | EngineType::Hybrid));
+            if wants_llm && class.persona.as_deref().is_none_or(|p| p.trim().is_empty()) {
+                problems.push(format!("{} uses the LLM but has no persona", section));
+            }
+            if class.dialog_variants.iter().any(Vec::is_empty) {
+                problems.push(format!("{} has an empty dialog variant", section));
+            }
+        }
+
+        check_engine(&self.interview.engine, "[interview] engine", &mut problems);
+        if !(0.0..=1.0).contains(&self.interview.average_threshold) {
+            problems.push(format!(
+                "[interview] average_threshold {} must be between 0 and 1",
+                self.interview.average_threshold
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.interview.wrong_answer_penalty) {
+            problems.push(format!(
+                "[interview] wrong_answer_penalty {} must be between 0 and 1",
+                self.interview.wrong_answer_penalty
+            ));
+        }
+        if self.hud.energy_critical > self.hud.energy_low {
+            problems.push(format!(
+                "[hud] energy_critical {} must not be above energy_low {}",
+                self.hud.energy_critical, self.hud.energy_low
+            ));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
+
+/// Parse an engine name, recording a problem for `field` if it is unknown
+fn check_engine(value: &str, field: &str, problems: &mut Vec<String>) -> Option<EngineType> {
+    match value.parse() {
+        Ok(engine) => Some(engine),
+        Err(_) => {
+            problems.push(format!("{} \"{}\" is not one of rule, llm, hybrid", field, value));
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -145,4 +429,87 @@ mod tests {
         let engine = config.get_npc_engine("unknown_class");
         assert_eq!(engine, EngineType::Rule);
     }
+
+    #[test]
+    fn test_use_llm_false_forces_rule() {
+        let mut config = GameConfig::load().unwrap();
+        config.game.use_llm = true;
+        assert_eq!(config.get_npc_engine("recruiter"), EngineType::Llm);
+
+        config.game.use_llm = false;
+        assert_eq!(config.get_npc_engine("recruiter"), EngineType::Rule);
+        assert_eq!(config.get_interview_engine(), EngineType::Rule);
+    }
+
+    #[test]
+    fn test_shipped_config_is_valid() {
+        assert_eq!(GameConfig::load().unwrap().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let config: GameConfig = toml::from_str(
+            r#"
+            [llm]
+            provider = "openia"
+            model = "x"
+            fallback_providers = ["mock", "carrier-pigeon"]
+
+            [npc]
+            default_engine = "rule"
+
+            [npc.classes.recruiter]
+            engine = "lmm"
+
+            [npc.classes.professor]
+            engine = "llm"
+
+            [interview]
+            engine = "rule"
+            average_threshold = 1.5
+            wrong_answer_penalty = -0.5
+
+            [hud]
+            energy_low = 20
+            energy_critical = 25
+            "#,
+        )
+        .unwrap();
+
+        let problems = config.validate().unwrap_err();
+        assert_eq!(
+            problems,
+            vec![
+                "[llm] unknown provider \"openia\"",
+                "[llm] unknown fallback provider \"carrier-pigeon\"",
+                "[npc.classes.professor] uses the LLM but has no persona",
+                "[npc.classes.recruiter] engine \"lmm\" is not one of rule, llm, hybrid",
+                "[interview] average_threshold 1.5 must be between 0 and 1",
+                "[interview] wrong_answer_penalty -0.5 must be between 0 and 1",
+                "[hud] energy_critical 25 must not be above energy_low 20",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interview_passing_policy_from_toml() {
+        assert_eq!(GameConfig::load().unwrap().interview.passing_policy, PassingPolicy::AllRounds);
+
+        let interview: InterviewConfig =
+            toml::from_str("engine = \"rule\"\npassing_policy = \"average\"\naverage_threshold = 0.7").unwrap();
+        assert_eq!(interview.passing_policy, PassingPolicy::Average);
+        assert_eq!(interview.new_session(Vec::new()).policy(), PassingPolicy::Average);
+        assert_eq!(interview.average_threshold, 0.7);
+        assert_eq!(interview.marking_penalty(), 0.0);
+    }
+
+    #[test]
+    fn test_negative_marking_from_toml() {
+        assert_eq!(GameConfig::load().unwrap().interview.marking_penalty(), 0.0);
+
+        let interview: InterviewConfig = toml::from_str("negative_marking = true").unwrap();
+        assert_eq!(interview.marking_penalty(), DEFAULT_WRONG_ANSWER_PENALTY);
+        let interview: InterviewConfig = toml::from_str("negative_marking = true\nwrong_answer_penalty = 0.5").unwrap();
+        assert_eq!(interview.marking_penalty(), 0.5);
+    }
 }
@@ -73,7 +73,7 @@ impl GameContext {
             .collect();
 
         // Sort by level descending, take top 5
-        skill_list.sort_by(|a, b| b.2.cmp(&a.2));
+        skill_list.sort_by_key(|s| std::cmp::Reverse(s.2));
         skill_list.truncate(5);
 
         let top_skills = skill_list
@@ -0,0 +1,172 @@
+//! Answer Explanation Engine
+//!
+//! Explains why a quiz question's correct option is right, offered after
+//! the player answers it wrong.
+//!
+//! # Flow
+//! 1. Resolve engine type (same as interviews)
+//! 2. If engine == Rule: use the explanation stored with the question
+//! 3. If engine == Llm:
+//!    a. Check cache, keyed by question text
+//!    b. Ask the LLM for a one or two sentence explanation
+//!    c. Cache response
+//! 4. If engine == Hybrid: Try LLM, fallback to rule
+
+use std::sync::Arc;
+use anyhow::Result;
+
+use crate::interview::quiz::QuizQuestion;
+use crate::llm::{LlmMessage, LlmProvider, MockProvider, Provider};
+use super::cache::ResponseCache;
+use super::config::GameConfig;
+use super::rate_limit::{self, RateLimiter, SharedLimiter, Throttled};
+use super::traits::EngineType;
+
+/// System prompt for answer explanations
+const TUTOR_PERSONA: &str = "You are a patient AI engineering tutor. \
+Explain why the given answer to an interview question is correct \
+in one or two short sentences.";
+
+/// Output from explanation generation
+pub struct ExplainOutput {
+    /// Explanation to show the player
+    pub text: String,
+    /// Whether this was generated by LLM or rule engine
+    pub from_llm: bool,
+}
+
+/// Explains correct answers to missed quiz questions
+pub struct ExplainEngine {
+    /// LLM provider (shared with other engines)
+    provider: Arc<Provider>,
+    /// Explanation cache, keyed by question
+    cache: ResponseCache,
+    /// Caps on LLM calls (shared with other engines when built by `GameLlm`)
+    limiter: SharedLimiter,
+    /// Game configuration
+    config: GameConfig,
+}
+
+impl ExplainEngine {
+    /// Create engine using a shared provider
+    pub fn with_provider(config: GameConfig, provider: Arc<Provider>) -> Self {
+        Self {
+            provider,
+            cache: ResponseCache::new(),
+            limiter: RateLimiter::from_config(&config.llm).shared(),
+            config,
+        }
+    }
+
+    /// Count LLM calls against a limiter shared with other engines
+    pub fn with_limiter(mut self, limiter: SharedLimiter) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    /// Create engine with mock provider (for testing)
+    pub fn with_mock(config: GameConfig, response: &str) -> Self {
+        Self::with_provider(config, Arc::new(Provider::Mock(MockProvider::new(response))))
+    }
+
+    /// Explain why the question's correct option is right
+    pub async fn explain(&mut self, question: &QuizQuestion) -> Result<ExplainOutput> {
+        let (text, from_llm) = match self.config.get_interview_engine() {
+            EngineType::Rule => (Self::rule_explanation(question), false),
+            EngineType::Llm => match self.llm_explanation(question).await {
+                Ok(text) => (text, true),
+                Err(e) if e.is::<Throttled>() => (Self::rule_explanation(question), false),
+                Err(e) => return Err(e),
+            },
+            EngineType::Hybrid => match self.llm_explanation(question).await {
+                Ok(text) => (text, true),
+                Err(_) => (Self::rule_explanation(question), false),
+            },
+        };
+
+        Ok(ExplainOutput { text, from_llm })
+    }
+
+    /// The configured explanation, or just the correct answer if there is none
+    pub fn rule_explanation(question: &QuizQuestion) -> String {
+        if let Some(explanation) = &question.explanation {
+            return explanation.clone();
+        }
+        match question.options.get(question.correct_idx) {
+            Some(answer) => format!("The correct answer was: {}", answer),
+            None => "No explanation available for this question.".to_string(),
+        }
+    }
+
+    /// Get LLM-powered explanation
+    async fn llm_explanation(&mut self, question: &QuizQuestion) -> Result<String> {
+        let cache_key = format!("explain|{}", question.question);
+        if let Some(cached) = self.cache.get(&cache_key) {
+            return Ok(cached);
+        }
+
+        let correct = question
+            .options
+            .get(question.correct_idx)
+            .map(String::as_str)
+            .unwrap_or_default();
+        let prompt = format!(
+            "Question: {}\nOptions:\n{}\nCorrect answer: {}\n\nWhy is this the right answer?",
+            question.question,
+            question.options.iter().map(|o| format!("- {}", o)).collect::<Vec<_>>().join("\n"),
+            correct
+        );
+        rate_limit::acquire(&self.limiter)?;
+        let response = self
+            .provider
+            .complete(TUTOR_PERSONA, vec![LlmMessage::user(prompt)])
+            .await?;
+
+        self.cache.set(cache_key, response.clone());
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interview::questions::InterviewQuestionDb;
+
+    fn config(engine: &str) -> GameConfig {
+        let mut config = GameConfig::load().unwrap();
+        config.interview.engine = engine.to_string();
+        config.game.use_llm = true;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_rule_mode_uses_configured_explanation() {
+        let db = InterviewQuestionDb::load();
+        let question = db
+            .get_questions("SQL")
+            .iter()
+            .find(|q| q.explanation.is_some())
+            .expect("an SQL question with an explanation");
+        let quiz_question = QuizQuestion::from(question);
+        let mut engine = ExplainEngine::with_mock(config("rule"), "unused");
+
+        let output = engine.explain(&quiz_question).await.unwrap();
+
+        assert!(!output.from_llm);
+        assert_eq!(Some(output.text), question.explanation.clone());
+    }
+
+    #[tokio::test]
+    async fn test_llm_explanation_is_cached_per_question() {
+        let mock = MockProvider::new("Because WHERE filters rows.");
+        let mut engine = ExplainEngine::with_provider(config("llm"), Arc::new(Provider::Mock(mock.clone())));
+        let question = crate::interview::quiz::create_question_for_skill("SQL");
+
+        let first = engine.explain(&question).await.unwrap();
+        engine.explain(&question).await.unwrap();
+
+        assert!(first.from_llm);
+        assert_eq!(first.text, "Because WHERE filters rows.");
+        assert_eq!(mock.get_requests().len(), 1);
+    }
+}
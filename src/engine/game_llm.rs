@@ -0,0 +1,121 @@
+//! Shared LLM Container
+//!
+//! Builds the LLM provider once and hands out clones of it to every
+//! engine, so NPC dialog, interviews, etc. share one HTTP client and
+//! connection pool. Engines also share one rate limiter, so the call
+//! caps apply to the whole session.
+//!
+//! # Example
+//! ```rust,ignore
+//! let llm = GameLlm::new(GameConfig::load()?)?;
+//! let npc_engine = llm.npc_engine();
+//! ```
+
+use anyhow::Result;
+use std::sync::Arc;
+
+use crate::llm::{create_provider, LlmConfig, MockProvider, Provider};
+use super::config::GameConfig;
+use super::advice::AdviceEngine;
+use super::explain::ExplainEngine;
+use super::npc::NpcEngine;
+use super::rate_limit::{RateLimiter, SharedLimiter};
+
+/// Owns the shared provider and creates engines that use it
+pub struct GameLlm {
+    /// Provider shared by all engines
+    provider: Arc<Provider>,
+    /// LLM call caps shared by all engines for the whole session
+    limiter: SharedLimiter,
+    /// Game configuration passed to each engine
+    config: GameConfig,
+}
+
+impl GameLlm {
+    /// Create the provider described by the config
+    ///
+    /// # Errors
+    /// Returns error if LLM provider creation fails
+    pub fn new(config: GameConfig) -> Result<Self> {
+        let provider = build_provider(&config)?;
+        Ok(Self::with_provider(config, provider))
+    }
+
+    /// Wrap an already-constructed provider
+    pub fn with_provider(config: GameConfig, provider: Provider) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            limiter: RateLimiter::from_config(&config.llm).shared(),
+            config,
+        }
+    }
+
+    /// Get a handle to the shared provider
+    pub fn provider(&self) -> Arc<Provider> {
+        Arc::clone(&self.provider)
+    }
+
+    /// Create an NPC engine backed by the shared provider
+    pub fn npc_engine(&self) -> NpcEngine {
+        NpcEngine::with_provider(self.config.clone(), self.provider()).with_limiter(Arc::clone(&self.limiter))
+    }
+
+    /// Create a mentor advice engine backed by the shared provider
+    pub fn advice_engine(&self) -> AdviceEngine {
+        AdviceEngine::with_provider(self.config.clone(), self.provider()).with_limiter(Arc::clone(&self.limiter))
+    }
+
+    /// Create an answer explanation engine backed by the shared provider
+    pub fn explain_engine(&self) -> ExplainEngine {
+        ExplainEngine::with_provider(self.config.clone(), self.provider()).with_limiter(Arc::clone(&self.limiter))
+    }
+}
+
+/// Create the configured provider, or an inert one when the LLM is disabled
+///
+/// With `use_llm = false` no engine routes to the provider, so we avoid
+/// reading credentials or building an HTTP client at all.
+pub(crate) fn build_provider(config: &GameConfig) -> Result<Provider> {
+    if !config.llm_enabled() {
+        return Ok(Provider::Mock(MockProvider::new("")));
+    }
+    create_provider(&LlmConfig {
+        provider: config.llm.provider.clone(),
+        model: config.llm.model.clone(),
+        fallbacks: config.llm.fallback_providers.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{GameContext, NpcInput};
+
+    fn recruiter_input(npc_id: usize) -> NpcInput {
+        NpcInput {
+            npc_id,
+            npc_class: "recruiter".to_string(),
+            npc_name: "Alex".to_string(),
+            player_message: Some("Any openings?".to_string()),
+            relationship: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_engines_share_provider() {
+        let mock = MockProvider::new("Shared response");
+        let llm = GameLlm::with_provider(GameConfig::load().unwrap(), Provider::Mock(mock.clone()));
+
+        let mut first = llm.npc_engine();
+        let mut second = llm.npc_engine();
+        assert_eq!(Arc::strong_count(&llm.provider), 3);
+
+        let ctx = GameContext::empty();
+        let a = first.get_dialog(&recruiter_input(1), &ctx).await.unwrap();
+        let b = second.get_dialog(&recruiter_input(2), &ctx).await.unwrap();
+
+        assert_eq!(a.text, "Shared response");
+        assert_eq!(b.text, "Shared response");
+        assert_eq!(mock.get_requests().len(), 2);
+    }
+}
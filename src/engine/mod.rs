@@ -18,7 +18,7 @@
 //!                 │
 //!                 ▼
 //!        ┌────────────────┐
-//!        │   LlmProvider  │ (shared)
+//!        │   LlmProvider  │ (shared via GameLlm)
 //!        └────────────────┘
 //! ```
 //!
@@ -32,9 +32,20 @@ pub mod config;
 pub mod context;
 pub mod cache;
 pub mod npc;
+pub mod game_llm;
+pub mod advice;
+pub mod budget;
+pub mod explain;
+pub mod rate_limit;
+pub mod scenario;
 
 pub use traits::{ActivityEngine, EngineType};
-pub use config::GameConfig;
+pub use config::{AssistConfig, GameConfig, GameSettings, HudConfig, InterviewConfig, USE_LLM_ENV};
 pub use context::{GameContext, SkillInfo};
 pub use cache::ResponseCache;
 pub use npc::{NpcEngine, NpcInput, NpcOutput};
+pub use game_llm::GameLlm;
+pub use advice::{AdviceEngine, AdviceOutput};
+pub use explain::{ExplainEngine, ExplainOutput};
+pub use rate_limit::{RateLimiter, SharedLimiter, Throttled};
+pub use scenario::{Scenario, ScenarioAction, ScenarioReport, ScenarioStep, StepResult};
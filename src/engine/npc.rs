@@ -6,8 +6,9 @@
 //! # Architecture
 //! ```text
 //! NpcEngine
-//! ├── provider: Provider      (shared LLM client)
+//! ├── provider: Arc<Provider> (shared LLM client)
 //! ├── cache: ResponseCache    (response caching)
+//! ├── limiter: SharedLimiter  (LLM call caps)
 //! ├── config: GameConfig      (class definitions)
 //! └── conversations: HashMap<NpcId, ConversationHistory>
 //! ```
@@ -18,19 +19,23 @@
 //! 3. If engine == Llm:
 //!    a. Check cache
 //!    b. Build prompt with persona + context
-//!    c. Call LLM
+//!    c. Call LLM, unless the rate limiter refuses (then use rule dialog)
 //!    d. Cache response
 //! 4. If engine == Hybrid: Try LLM, fallback to rule
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Instant;
 use anyhow::Result;
 use rand::seq::SliceRandom;
 
-use crate::llm::{LlmMessage, LlmProvider};
+use crate::llm::{LlmMessage, LlmProvider, Provider};
+use crate::player::Relationship;
+use super::budget;
 use super::cache::ResponseCache;
 use super::config::GameConfig;
 use super::context::GameContext;
+use super::rate_limit::{self, RateLimiter, SharedLimiter, Throttled};
 use super::traits::EngineType;
 
 /// Conversation history per NPC instance
@@ -92,6 +97,8 @@ pub struct NpcInput {
     pub npc_name: String,
     /// Optional player message (for conversation mode)
     pub player_message: Option<String>,
+    /// What this NPC remembers of the player from earlier sessions
+    pub relationship: Option<Relationship>,
 }
 
 /// Output from NPC dialog generation
@@ -100,16 +107,20 @@ pub struct NpcOutput {
     pub text: String,
     /// Whether this was generated by LLM or rule engine
     pub from_llm: bool,
+    /// Diagnostics about how the reply was produced, e.g. trimmed history
+    pub notes: Vec<String>,
 }
 
 /// NPC Dialog Engine
 ///
 /// Manages dialog generation for all NPCs in the game.
 pub struct NpcEngine {
-    /// LLM provider for dynamic responses
-    provider: crate::llm::Provider,
+    /// LLM provider for dynamic responses (shared with other engines)
+    provider: Arc<Provider>,
     /// Response cache
     cache: ResponseCache,
+    /// Caps on LLM calls (shared with other engines when built by `GameLlm`)
+    limiter: SharedLimiter,
     /// Game configuration
     config: GameConfig,
     /// Conversation history per NPC
@@ -125,29 +136,34 @@ impl NpcEngine {
     /// # Errors
     /// Returns error if LLM provider creation fails
     pub fn new(config: GameConfig) -> Result<Self> {
-        let provider = crate::llm::create_provider(&crate::llm::LlmConfig {
-            provider: config.llm.provider.clone(),
-            model: config.llm.model.clone(),
-        })?;
-        
-        Ok(Self {
+        let provider = super::game_llm::build_provider(&config)?;
+
+        Ok(Self::with_provider(config, Arc::new(provider)))
+    }
+    
+    /// Create engine using a shared provider
+    pub fn with_provider(config: GameConfig, provider: Arc<Provider>) -> Self {
+        Self {
             provider,
             cache: ResponseCache::new(),
+            limiter: RateLimiter::from_config(&config.llm).shared(),
             config,
             conversations: HashMap::new(),
-        })
+        }
+    }
+
+    /// Count LLM calls against a limiter shared with other engines
+    pub fn with_limiter(mut self, limiter: SharedLimiter) -> Self {
+        self.limiter = limiter;
+        self
     }
     
     /// Create engine with mock provider (for testing)
     pub fn with_mock(config: GameConfig, response: &str) -> Self {
-        Self {
-            provider: crate::llm::Provider::Mock(
-                crate::llm::MockProvider::new(response)
-            ),
-            cache: ResponseCache::new(),
+        Self::with_provider(
             config,
-            conversations: HashMap::new(),
-        }
+            Arc::new(Provider::Mock(crate::llm::MockProvider::new(response))),
+        )
     }
     
     /// Get the engine type for an NPC class
@@ -169,19 +185,30 @@ impl NpcEngine {
         context: &GameContext,
     ) -> Result<NpcOutput> {
         let engine_type = self.config.get_npc_engine(&input.npc_class);
+        let mut notes = Vec::new();
         
         let (text, from_llm) = match engine_type {
             EngineType::Rule => (self.rule_dialog(&input.npc_class)?, false),
-            EngineType::Llm => (self.llm_dialog(input, context).await?, true),
+            EngineType::Llm => match self.llm_dialog(input, context, &mut notes).await {
+                Ok(text) => (text, true),
+                Err(e) if e.is::<Throttled>() => {
+                    notes.push(format!("Used rule dialog: {}", e));
+                    (self.rule_dialog(&input.npc_class)?, false)
+                }
+                Err(e) => return Err(e),
+            },
             EngineType::Hybrid => {
-                match self.llm_dialog(input, context).await {
+                match self.llm_dialog(input, context, &mut notes).await {
                     Ok(text) => (text, true),
-                    Err(_) => (self.rule_dialog(&input.npc_class)?, false),
+                    Err(e) => {
+                        notes.push(format!("Used rule dialog: {}", e));
+                        (self.rule_dialog(&input.npc_class)?, false)
+                    }
                 }
             }
         };
         
-        Ok(NpcOutput { text, from_llm })
+        Ok(NpcOutput { text, from_llm, notes })
     }
     
     /// Get rule-based dialog for an NPC class
@@ -198,16 +225,24 @@ impl NpcEngine {
         Ok(text)
     }
     
-    /// Get LLM-powered dialog
+    /// Get LLM-powered dialog, adding anything worth reporting to `notes`
     async fn llm_dialog(
         &mut self,
         input: &NpcInput,
         context: &GameContext,
+        notes: &mut Vec<String>,
     ) -> Result<String> {
-        // Check cache first
+        // Check cache first, keyed on where we are in the conversation
+        let history: &[LlmMessage] = self.conversations
+            .get(&input.npc_id)
+            .map(|h| h.messages.as_slice())
+            .unwrap_or(&[]);
+        let times_talked = input.relationship.as_ref().map_or(0, |r| r.times_talked);
         let cache_key = ResponseCache::make_key(
-            &format!("npc_{}", input.npc_class),
+            &format!("npc_{}_{}", input.npc_class, times_talked),
             &input.player_message.clone().unwrap_or_default(),
+            history.len() / 2,
+            history,
             context,
         );
         
@@ -219,17 +254,21 @@ impl NpcEngine {
         let persona = self.config.get_npc_persona(&input.npc_class)
             .unwrap_or("You are a friendly NPC.");
         
-        let system = format!(
+        let mut system = format!(
             "{}\n\n{}\n\nYour name is {}. Respond naturally.",
             persona,
             context.to_prompt_section(),
             input.npc_name,
         );
+        if let Some(relationship) = &input.relationship {
+            system.push_str("\n\n");
+            system.push_str(&relationship.prompt_section());
+        }
         
         // Get or create conversation history
         let history = self.conversations
             .entry(input.npc_id)
-            .or_insert_with(ConversationHistory::new);
+            .or_default();
         
         // Build messages
         let mut messages = history.messages.clone();
@@ -241,6 +280,12 @@ impl NpcEngine {
             messages.push(LlmMessage::user("Hello!".to_string()));
         }
         
+        let trimmed = budget::trim_to_budget(&system, &mut messages, self.config.llm.max_prompt_tokens);
+        if trimmed > 0 {
+            notes.push(format!("Trimmed {} old message(s) to fit the prompt budget", trimmed));
+        }
+        rate_limit::acquire(&self.limiter)?;
+
         // Call LLM
         let response = self.provider.complete(&system, messages).await?;
         
@@ -295,6 +340,7 @@ mod tests {
             npc_class: "barista".to_string(),
             npc_name: "Morgan".to_string(),
             player_message: None,
+            relationship: None,
         };
         
         let output = engine.get_dialog(&input, &GameContext::empty()).await.unwrap();
@@ -302,4 +348,97 @@ mod tests {
         // Barista is rule-based, so should get fallback dialog
         assert!(!output.from_llm);
     }
+
+    #[tokio::test]
+    async fn test_session_cap_routes_hybrid_npc_to_fallback() {
+        let mut config = GameConfig::load().unwrap();
+        config.game.use_llm = true;
+        config.llm.max_calls_per_session = 1;
+        config.npc.classes.get_mut("recruiter").unwrap().engine = Some("hybrid".to_string());
+        let mock = crate::llm::MockProvider::new("LLM reply");
+        let mut engine = NpcEngine::with_provider(config, Arc::new(Provider::Mock(mock.clone())));
+        let input = |message: &str| NpcInput {
+            npc_id: 1,
+            npc_class: "recruiter".to_string(),
+            npc_name: "Alex".to_string(),
+            player_message: Some(message.to_string()),
+            relationship: None,
+        };
+
+        let first = engine.get_dialog(&input("Hi"), &GameContext::empty()).await.unwrap();
+        let second = engine.get_dialog(&input("Any openings?"), &GameContext::empty()).await.unwrap();
+
+        assert!(first.from_llm);
+        assert!(!second.from_llm);
+        assert_eq!(second.notes, vec!["Used rule dialog: LLM session limit reached (1 calls)".to_string()]);
+        assert_eq!(mock.get_requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_throttled_llm_npc_notes_the_fallback() {
+        let mut config = GameConfig::load().unwrap();
+        config.game.use_llm = true;
+        config.llm.max_calls_per_minute = 0;
+        config.npc.classes.get_mut("recruiter").unwrap().engine = Some("llm".to_string());
+        let mut engine = NpcEngine::with_mock(config, "LLM reply");
+        let input = NpcInput {
+            npc_id: 1,
+            npc_class: "recruiter".to_string(),
+            npc_name: "Alex".to_string(),
+            player_message: None,
+            relationship: None,
+        };
+
+        let output = engine.get_dialog(&input, &GameContext::empty()).await.unwrap();
+
+        assert!(!output.from_llm);
+        assert_eq!(output.notes, vec!["Used rule dialog: LLM rate limit reached (0 calls per minute)".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_trimmed_history_is_reported() {
+        let mut config = GameConfig::load().unwrap();
+        config.game.use_llm = true;
+        config.llm.max_prompt_tokens = 1;
+        config.npc.classes.get_mut("recruiter").unwrap().engine = Some("llm".to_string());
+        let mut engine = NpcEngine::with_mock(config, "LLM reply");
+        let input = |message: &str| NpcInput {
+            npc_id: 1,
+            npc_class: "recruiter".to_string(),
+            npc_name: "Alex".to_string(),
+            player_message: Some(message.to_string()),
+            relationship: None,
+        };
+
+        let first = engine.get_dialog(&input("Hi"), &GameContext::empty()).await.unwrap();
+        let second = engine.get_dialog(&input("Any openings?"), &GameContext::empty()).await.unwrap();
+
+        assert!(first.notes.is_empty());
+        assert_eq!(second.notes, vec!["Trimmed 2 old message(s) to fit the prompt budget".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_relationship_reaches_llm_prompt() {
+        let mut config = GameConfig::load().unwrap();
+        config.game.use_llm = true;
+        config.npc.classes.get_mut("recruiter").unwrap().engine = Some("llm".to_string());
+        let mock = crate::llm::MockProvider::new("Welcome back!");
+        let mut engine = NpcEngine::with_provider(config, Arc::new(Provider::Mock(mock.clone())));
+        let mut player = crate::player::Player::new("Ada");
+        for _ in 0..3 {
+            player.record_talk("Alex", "Hi there");
+        }
+        let input = NpcInput {
+            npc_id: 1,
+            npc_class: "recruiter".to_string(),
+            npc_name: "Alex".to_string(),
+            player_message: None,
+            relationship: player.relationship("Alex").cloned(),
+        };
+
+        engine.get_dialog(&input, &GameContext::empty()).await.unwrap();
+
+        let (system, _) = &mock.get_requests()[0];
+        assert!(system.contains("spoken with this player 3 time(s)"));
+    }
 }
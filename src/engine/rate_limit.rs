@@ -0,0 +1,117 @@
+//! LLM Rate Limiting
+//!
+//! Caps how many completions the engines may request, per rolling minute
+//! and per game session, so a looping interaction can't run up API
+//! usage. One limiter is shared by every engine created from `GameLlm`.
+//! Cached responses don't count against the limits.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::config::LlmConfig;
+
+/// Default for `[llm] max_calls_per_minute`
+pub const DEFAULT_MAX_CALLS_PER_MINUTE: u32 = 20;
+/// Default for `[llm] max_calls_per_session`
+pub const DEFAULT_MAX_CALLS_PER_SESSION: u32 = 300;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Returned instead of calling the provider once a limit is reached
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Throttled {
+    #[error("LLM rate limit reached ({0} calls per minute)")]
+    PerMinute(u32),
+    #[error("LLM session limit reached ({0} calls)")]
+    PerSession(u32),
+}
+
+/// Counts LLM calls against per-minute and per-session caps
+#[derive(Debug)]
+pub struct RateLimiter {
+    max_per_minute: u32,
+    max_per_session: u32,
+    /// Start times of calls within the last minute
+    recent: VecDeque<Instant>,
+    session_calls: u32,
+}
+
+/// Limiter handle shared between engines
+pub type SharedLimiter = Arc<Mutex<RateLimiter>>;
+
+impl RateLimiter {
+    pub fn new(max_per_minute: u32, max_per_session: u32) -> Self {
+        Self {
+            max_per_minute,
+            max_per_session,
+            recent: VecDeque::new(),
+            session_calls: 0,
+        }
+    }
+
+    pub fn from_config(config: &LlmConfig) -> Self {
+        Self::new(config.max_calls_per_minute, config.max_calls_per_session)
+    }
+
+    /// Wrap in a handle that engines can share
+    pub fn shared(self) -> SharedLimiter {
+        Arc::new(Mutex::new(self))
+    }
+
+    /// Record a call if both limits allow it
+    pub fn try_acquire(&mut self) -> Result<(), Throttled> {
+        self.try_acquire_at(Instant::now())
+    }
+
+    fn try_acquire_at(&mut self, now: Instant) -> Result<(), Throttled> {
+        while self.recent.front().is_some_and(|&t| now.duration_since(t) >= WINDOW) {
+            self.recent.pop_front();
+        }
+        if self.session_calls >= self.max_per_session {
+            return Err(Throttled::PerSession(self.max_per_session));
+        }
+        if self.recent.len() as u32 >= self.max_per_minute {
+            return Err(Throttled::PerMinute(self.max_per_minute));
+        }
+        self.recent.push_back(now);
+        self.session_calls += 1;
+        Ok(())
+    }
+
+    pub fn session_calls(&self) -> u32 {
+        self.session_calls
+    }
+}
+
+/// Take a call from a shared limiter
+///
+/// A refusal comes back as the error, which the engines pass up so the
+/// caller falls back instead of calling the provider.
+pub fn acquire(limiter: &SharedLimiter) -> Result<(), Throttled> {
+    limiter.lock().expect("rate limiter lock poisoned").try_acquire()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_per_minute_window_rolls_over() {
+        let mut limiter = RateLimiter::new(2, 100);
+        let start = Instant::now();
+        assert!(limiter.try_acquire_at(start).is_ok());
+        assert!(limiter.try_acquire_at(start).is_ok());
+        assert_eq!(limiter.try_acquire_at(start), Err(Throttled::PerMinute(2)));
+        assert!(limiter.try_acquire_at(start + WINDOW).is_ok());
+    }
+
+    #[test]
+    fn test_session_cap_is_permanent() {
+        let mut limiter = RateLimiter::new(10, 1);
+        let start = Instant::now();
+        assert!(limiter.try_acquire_at(start).is_ok());
+        assert_eq!(limiter.try_acquire_at(start + WINDOW * 5), Err(Throttled::PerSession(1)));
+        assert_eq!(limiter.session_calls(), 1);
+    }
+}
@@ -0,0 +1,278 @@
+//! Mock Scenarios
+//!
+//! Scripted smoke tests for the LLM-backed engines. A scenario is a list
+//! of NPC and interview interactions, each with the response the mock
+//! provider gives and the substrings the player-facing text must contain.
+//! Running one needs no API key, so prompt changes can be checked quickly.
+//!
+//! ```rust,ignore
+//! let report = Scenario::new("recruiter", GameConfig::load()?)
+//!     .npc("recruiter", "Alex", Some("What jobs do you have?"))
+//!     .responds("We're hiring ML engineers!")
+//!     .expect("ML engineers")
+//!     .run()
+//!     .await;
+//! assert!(report.passed());
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use super::advice::AdviceEngine;
+use super::config::GameConfig;
+use super::context::GameContext;
+use super::explain::ExplainEngine;
+use super::npc::{NpcEngine, NpcInput};
+use crate::interview::quiz::QuizQuestion;
+use crate::jobs::Job;
+use crate::llm::{MockProvider, Provider};
+use crate::player::Player;
+
+/// One interaction a scenario runs
+#[derive(Debug, Clone)]
+pub enum ScenarioAction {
+    /// Talk to an NPC, optionally saying something first
+    Npc { class: String, name: String, message: Option<String> },
+    /// Ask the mentor for advice after failing a job's interview
+    Advice { job: Job, failed_questions: Vec<String> },
+    /// Ask why a quiz question's correct answer is right
+    Explain { question: QuizQuestion },
+}
+
+impl ScenarioAction {
+    fn label(&self) -> String {
+        match self {
+            ScenarioAction::Npc { name, message: Some(message), .. } => format!("{}: \"{}\"", name, message),
+            ScenarioAction::Npc { name, message: None, .. } => format!("{}: greeting", name),
+            ScenarioAction::Advice { job, .. } => format!("Mentor advice for {}", job.title),
+            ScenarioAction::Explain { question } => format!("Explain \"{}\"", question.question),
+        }
+    }
+}
+
+/// An interaction with its mock response and expectations
+#[derive(Debug, Clone)]
+pub struct ScenarioStep {
+    pub action: ScenarioAction,
+    /// What the mock provider answers with
+    pub mock_response: String,
+    /// Substrings the response must contain
+    pub expected: Vec<String>,
+}
+
+/// How one step went
+#[derive(Debug, Clone)]
+pub struct StepResult {
+    pub label: String,
+    /// Text the engine returned, empty if it failed
+    pub response: String,
+    pub from_llm: bool,
+    /// Expected substrings the response lacked
+    pub missing: Vec<String>,
+    /// Engine error, if the call failed outright
+    pub error: Option<String>,
+}
+
+impl StepResult {
+    pub fn passed(&self) -> bool {
+        self.error.is_none() && self.missing.is_empty()
+    }
+}
+
+/// Pass/fail for every step of a scenario run
+#[derive(Debug, Clone)]
+pub struct ScenarioReport {
+    pub name: String,
+    pub steps: Vec<StepResult>,
+}
+
+impl ScenarioReport {
+    pub fn passed(&self) -> bool {
+        self.steps.iter().all(StepResult::passed)
+    }
+
+    pub fn failures(&self) -> usize {
+        self.steps.iter().filter(|s| !s.passed()).count()
+    }
+}
+
+impl fmt::Display for ScenarioReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Scenario: {}", self.name)?;
+        for (i, step) in self.steps.iter().enumerate() {
+            let mark = if step.passed() { "✓" } else { "✗" };
+            let source = if step.from_llm { "llm" } else { "rule" };
+            writeln!(f, "  {} {}. {} [{}]", mark, i + 1, step.label, source)?;
+            if let Some(error) = &step.error {
+                writeln!(f, "      error: {}", error)?;
+            } else if !step.missing.is_empty() {
+                writeln!(f, "      missing {:?} in \"{}\"", step.missing, step.response)?;
+            }
+        }
+        write!(f, "  {}/{} steps passed", self.steps.len() - self.failures(), self.steps.len())
+    }
+}
+
+/// Builder for a sequence of mocked engine interactions
+///
+/// `responds` and `expect` apply to the most recently added step.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    name: String,
+    config: GameConfig,
+    context: GameContext,
+    player: Player,
+    steps: Vec<ScenarioStep>,
+}
+
+impl Scenario {
+    pub fn new(name: &str, config: GameConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            config,
+            context: GameContext::empty(),
+            player: Player::builder("Player").build(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Game state NPCs see in their prompts
+    pub fn context(mut self, context: GameContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Player whose skills advice steps are based on
+    pub fn player(mut self, player: Player) -> Self {
+        self.player = player;
+        self
+    }
+
+    pub fn step(mut self, action: ScenarioAction) -> Self {
+        self.steps.push(ScenarioStep { action, mock_response: String::new(), expected: Vec::new() });
+        self
+    }
+
+    pub fn npc(self, class: &str, name: &str, message: Option<&str>) -> Self {
+        self.step(ScenarioAction::Npc {
+            class: class.to_string(),
+            name: name.to_string(),
+            message: message.map(str::to_string),
+        })
+    }
+
+    pub fn advice(self, job: &Job, failed_questions: &[&str]) -> Self {
+        self.step(ScenarioAction::Advice {
+            job: job.clone(),
+            failed_questions: failed_questions.iter().map(|q| q.to_string()).collect(),
+        })
+    }
+
+    pub fn explain(self, question: &QuizQuestion) -> Self {
+        self.step(ScenarioAction::Explain { question: question.clone() })
+    }
+
+    /// Set what the mock answers for the last step
+    pub fn responds(mut self, response: &str) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.mock_response = response.to_string();
+        }
+        self
+    }
+
+    /// Require the last step's response to contain `substring`
+    pub fn expect(mut self, substring: &str) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.expected.push(substring.to_string());
+        }
+        self
+    }
+
+    /// Run every step against a mock provider and check its response
+    ///
+    /// Each step gets fresh engines, so one step's cached response can't
+    /// answer for another.
+    pub async fn run(&self) -> ScenarioReport {
+        let mut steps = Vec::new();
+        for (i, step) in self.steps.iter().enumerate() {
+            let provider = Arc::new(Provider::Mock(MockProvider::new(step.mock_response.clone())));
+            let result = match &step.action {
+                ScenarioAction::Npc { class, name, message } => {
+                    let input = NpcInput {
+                        npc_id: i,
+                        npc_class: class.clone(),
+                        npc_name: name.clone(),
+                        player_message: message.clone(),
+                        relationship: None,
+                    };
+                    NpcEngine::with_provider(self.config.clone(), provider)
+                        .get_dialog(&input, &self.context)
+                        .await
+                        .map(|out| (out.text, out.from_llm))
+                }
+                ScenarioAction::Advice { job, failed_questions } => {
+                    AdviceEngine::with_provider(self.config.clone(), provider)
+                        .get_advice(job, &self.player, failed_questions)
+                        .await
+                        .map(|out| (out.text, out.from_llm))
+                }
+                ScenarioAction::Explain { question } => ExplainEngine::with_provider(self.config.clone(), provider)
+                    .explain(question)
+                    .await
+                    .map(|out| (out.text, out.from_llm)),
+            };
+
+            let label = step.action.label();
+            steps.push(match result {
+                Ok((response, from_llm)) => StepResult {
+                    label,
+                    missing: step.expected.iter().filter(|e| !response.contains(e.as_str())).cloned().collect(),
+                    response,
+                    from_llm,
+                    error: None,
+                },
+                Err(e) => StepResult {
+                    label,
+                    response: String::new(),
+                    from_llm: false,
+                    missing: step.expected.clone(),
+                    error: Some(format!("{:#}", e)),
+                },
+            });
+        }
+        ScenarioReport { name: self.name.clone(), steps }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> GameConfig {
+        let mut config = GameConfig::load().unwrap();
+        config.game.use_llm = true;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_matching_mock_response_passes_and_other_fails() {
+        let report = Scenario::new("recruiter", config())
+            .npc("recruiter", "Alex", Some("What jobs do you have?"))
+            .responds("We're hiring ML engineers this week!")
+            .expect("ML engineers")
+            .npc("recruiter", "Alex", Some("Any remote roles?"))
+            .responds("Nothing remote right now.")
+            .expect("remote roles available")
+            .run()
+            .await;
+
+        assert_eq!(report.steps.len(), 2);
+        assert!(report.steps[0].passed(), "{}", report);
+        assert!(report.steps[0].from_llm);
+        assert!(!report.steps[1].passed());
+        assert_eq!(report.steps[1].missing, vec!["remote roles available".to_string()]);
+        assert!(!report.passed());
+        assert_eq!(report.failures(), 1);
+        assert!(report.to_string().contains("1/2 steps passed"));
+    }
+}
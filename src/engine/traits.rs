@@ -12,9 +12,10 @@ use anyhow::Result;
 use std::future::Future;
 
 /// How an activity generates content
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum EngineType {
     /// Hardcoded logic (fast, no API cost)
+    #[default]
     Rule,
     /// LLM-powered (dynamic responses)
     Llm,
@@ -22,12 +23,6 @@ pub enum EngineType {
     Hybrid,
 }
 
-impl Default for EngineType {
-    fn default() -> Self {
-        Self::Rule
-    }
-}
-
 impl std::fmt::Display for EngineType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -57,7 +52,7 @@ impl std::str::FromStr for EngineType {
 /// and decides how to handle Rule vs Llm modes internally.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// struct MyActivity {
 ///     engine_type: EngineType,
 ///     provider: Arc<dyn LlmProvider>,
@@ -0,0 +1,139 @@
+//! Action Results
+//!
+//! Outcome of something the player tried to do. Every interaction handler
+//! returns one, so the UI can always say why an action didn't happen
+//! instead of silently doing nothing.
+
+use crate::jobs::Job;
+use crate::player::{MenuItem, Player, StudyVenue, WARM_UP_ENERGY};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionResult {
+    /// The action went through; the message describes what changed
+    Done(String),
+    /// Not enough money, energy or reputation
+    Insufficient(String),
+    /// Not possible right now, e.g. on cooldown or already done
+    Blocked(String),
+}
+
+impl ActionResult {
+    pub fn succeeded(&self) -> bool {
+        matches!(self, ActionResult::Done(_))
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ActionResult::Done(m) | ActionResult::Insufficient(m) | ActionResult::Blocked(m) => m,
+        }
+    }
+}
+
+/// Buy a drink at the coffee shop
+pub fn buy_drink(player: &mut Player, item: &MenuItem) -> ActionResult {
+    if player.money < item.price {
+        return ActionResult::Insufficient(format!(
+            "A {} costs ${}, but you only have ${}",
+            item.name, item.price, player.money
+        ));
+    }
+    match player.buy(item) {
+        Ok(message) => ActionResult::Done(message),
+        Err(reason) => ActionResult::Insufficient(reason),
+    }
+}
+
+/// One study session at a venue, or with the Student's study group
+///
+/// Studying a mastered or unknown skill is blocked; any other failure is
+/// a shortage of energy or money.
+pub fn study_session(
+    player: &mut Player,
+    venue: &StudyVenue,
+    skill_name: &str,
+    hours: u32,
+    time_of_day: f32,
+    with_group: bool,
+) -> ActionResult {
+    let blocked = player.skills.get(skill_name).is_none_or(|s| s.is_maxed());
+    let result = if with_group {
+        player.study_with_group(skill_name, hours, time_of_day)
+    } else {
+        player.study_at(venue, skill_name, hours, time_of_day)
+    };
+    match result {
+        Ok(message) => ActionResult::Done(message),
+        Err(reason) if blocked => ActionResult::Blocked(reason),
+        Err(reason) => ActionResult::Insufficient(reason),
+    }
+}
+
+/// Learn a job's interview rounds from the recruiter
+pub fn research_job(player: &mut Player, job_id: u32) -> ActionResult {
+    if player.has_researched(job_id) {
+        return ActionResult::Blocked("You've already researched this interview".to_string());
+    }
+    player.research_job(job_id);
+    ActionResult::Done("The recruiter walked you through the interview rounds".to_string())
+}
+
+/// Run through a job's interview topics before applying
+pub fn warm_up(player: &mut Player, job: &Job) -> ActionResult {
+    match player.warm_up(job) {
+        Ok(topics) if topics.is_empty() => {
+            ActionResult::Done("No set topics for this one - you'll be quicker off the mark on the first question".to_string())
+        }
+        Ok(topics) => ActionResult::Done(format!(
+            "Warmed up on: {}. You'll be sharper on the first question.",
+            topics.join(", ")
+        )),
+        Err(reason) if !player.is_warmed_up_for(job.id) && player.energy < WARM_UP_ENERGY => {
+            ActionResult::Insufficient(reason)
+        }
+        Err(reason) => ActionResult::Blocked(reason),
+    }
+}
+
+/// Check a job isn't on interview cooldown; the error says when it lifts
+pub fn check_interview_cooldown(player: &Player, job_id: u32) -> Result<(), String> {
+    match player.cooldown_remaining(job_id) {
+        Some(days) => Err(format!(
+            "You recently interviewed here. Try again in {} day(s).",
+            days
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::CoffeeMenu;
+    use crate::skills::Proficiency;
+
+    #[test]
+    fn test_too_poor_coffee_purchase_is_insufficient() {
+        let menu = CoffeeMenu::load();
+        let latte = menu.find_by_label("Latte ($6)").expect("latte on menu");
+        let mut player = Player::builder("Test").money(4).build();
+
+        let result = buy_drink(&mut player, latte);
+
+        assert_eq!(
+            result,
+            ActionResult::Insufficient("A Latte costs $6, but you only have $4".to_string())
+        );
+        assert!(!result.succeeded());
+        assert_eq!(player.money, 4);
+    }
+
+    #[test]
+    fn test_study_failures_say_why() {
+        let mut player = Player::builder("Test").energy(0).build();
+        assert!(matches!(study_session(&mut player, &StudyVenue::LIBRARY, "Python", 2, 14.0, false), ActionResult::Insufficient(_)));
+
+        player.energy = player.max_energy;
+        player.set_skill("Python", Proficiency::Expert).unwrap();
+        assert!(matches!(study_session(&mut player, &StudyVenue::LIBRARY, "Python", 2, 14.0, false), ActionResult::Blocked(_)));
+    }
+}
@@ -0,0 +1,89 @@
+//! Auto-Rest Assist
+//!
+//! Opt-in help with running out of energy. When an action near home falls
+//! short on energy, the player can rest at the apartment and have the
+//! action retried, instead of walking home, sleeping and walking back.
+
+use super::action::ActionResult;
+use super::day_log::{DayLog, TimeCategory};
+use super::state::GameState;
+
+/// Hours a rest at home takes
+pub const HOME_REST_HOURS: f32 = 8.0;
+
+impl GameState {
+    /// Rest at the apartment: energy back to full, and `HOME_REST_HOURS` pass
+    ///
+    /// Returns the finished day's log if the rest ran past midnight.
+    pub fn rest_at_home(&mut self) -> Option<DayLog> {
+        self.player.energy = self.player.max_energy;
+        self.advance_time(HOME_REST_HOURS, TimeCategory::Resting)
+    }
+
+    /// Whether to offer a rest and retry after `result`: the assist is on,
+    /// the player is home, and the action only ran short on energy
+    pub fn offers_auto_rest(&self, assist: bool, at_home: bool, result: &ActionResult) -> bool {
+        assist
+            && at_home
+            && matches!(result, ActionResult::Insufficient(_))
+            && self.player.energy < self.player.max_energy
+    }
+
+    /// Rest at home, then run `action` again
+    ///
+    /// Returns the retried result and the log of any day the rest finished.
+    pub fn rest_and_retry(
+        &mut self,
+        mut action: impl FnMut(&mut GameState) -> ActionResult,
+    ) -> (ActionResult, Option<DayLog>) {
+        let finished = self.rest_at_home();
+        (action(self), finished)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::study_session;
+    use crate::player::StudyVenue;
+
+    fn study(state: &mut GameState) -> ActionResult {
+        let time_of_day = state.time_of_day;
+        study_session(&mut state.player, &StudyVenue::LIBRARY, "Python", 2, time_of_day, false)
+    }
+
+    fn tired_state() -> GameState {
+        let mut state = GameState::with_seed("Test", 1);
+        state.player.energy = 1;
+        state
+    }
+
+    #[test]
+    fn test_assist_at_home_rests_then_studies() {
+        let mut state = tired_state();
+        let start = state.time_of_day;
+
+        let first = study(&mut state);
+        assert!(state.offers_auto_rest(true, true, &first));
+        let (result, _) = state.rest_and_retry(study);
+
+        assert!(result.succeeded(), "{:?}", result);
+        assert!(state.player.skills["Python"].experience_points > 0);
+        assert_ne!(state.time_of_day, start);
+    }
+
+    #[test]
+    fn test_without_assist_or_away_from_home_study_just_fails() {
+        for (assist, at_home) in [(false, true), (true, false)] {
+            let mut state = tired_state();
+            let start = state.time_of_day;
+
+            let result = study(&mut state);
+
+            assert!(matches!(result, ActionResult::Insufficient(_)));
+            assert!(!state.offers_auto_rest(assist, at_home, &result));
+            assert_eq!(state.player.energy, 1);
+            assert_eq!(state.time_of_day, start);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Auto Study
+//!
+//! Repeats study sessions on one skill, one session per step, so the game
+//! loop keeps control and the player can cancel part-way through.
+
+use super::day_log::TimeCategory;
+use super::state::GameState;
+use crate::player::STUDY_SESSION_HOURS;
+
+/// Sessions queued when the player starts auto-study
+pub const AUTO_STUDY_SESSIONS: u32 = 4;
+
+/// What an auto-study run achieved before it stopped
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoStudySummary {
+    pub skill: String,
+    pub sessions_done: u32,
+    pub sessions_planned: u32,
+    /// True when the player cancelled before the run finished
+    pub interrupted: bool,
+    /// Why the run stopped early on its own (e.g. out of energy)
+    pub stopped_reason: Option<String>,
+}
+
+impl AutoStudySummary {
+    pub fn message(&self) -> String {
+        let progress = format!(
+            "Studied {} for {}/{} sessions",
+            self.skill, self.sessions_done, self.sessions_planned
+        );
+        if self.interrupted {
+            format!("{} before you stopped. Progress was kept.", progress)
+        } else if let Some(reason) = &self.stopped_reason {
+            format!("{}. Stopped: {}", progress, reason)
+        } else {
+            format!("{}.", progress)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AutoStudyStep {
+    Running,
+    Finished(AutoStudySummary),
+}
+
+/// A cancellable run of consecutive study sessions
+#[derive(Debug, Clone)]
+pub struct AutoStudy {
+    skill: String,
+    sessions_planned: u32,
+    sessions_done: u32,
+    cancel_requested: bool,
+}
+
+impl AutoStudy {
+    pub fn new(skill: &str, sessions: u32) -> Self {
+        Self {
+            skill: skill.to_string(),
+            sessions_planned: sessions,
+            sessions_done: 0,
+            cancel_requested: false,
+        }
+    }
+
+    pub fn skill(&self) -> &str {
+        &self.skill
+    }
+
+    pub fn sessions_done(&self) -> u32 {
+        self.sessions_done
+    }
+
+    /// Ask the run to stop before its next session
+    pub fn cancel(&mut self) {
+        self.cancel_requested = true;
+    }
+
+    /// Run one study session, or finish if cancelled, done, or out of energy
+    pub fn step(&mut self, state: &mut GameState) -> AutoStudyStep {
+        if self.cancel_requested {
+            return AutoStudyStep::Finished(self.summary(true, None));
+        }
+        if self.sessions_done >= self.sessions_planned {
+            return AutoStudyStep::Finished(self.summary(false, None));
+        }
+
+        let time_of_day = state.time_of_day;
+        match state.track_progress(|player| player.study(&self.skill, STUDY_SESSION_HOURS, time_of_day)) {
+            Ok(_) => {
+                self.sessions_done += 1;
+                state.advance_time(STUDY_SESSION_HOURS as f32, TimeCategory::Studying);
+                AutoStudyStep::Running
+            }
+            Err(reason) => AutoStudyStep::Finished(self.summary(false, Some(reason))),
+        }
+    }
+
+    fn summary(&self, interrupted: bool, stopped_reason: Option<String>) -> AutoStudySummary {
+        AutoStudySummary {
+            skill: self.skill.clone(),
+            sessions_done: self.sessions_done,
+            sessions_planned: self.sessions_planned,
+            interrupted,
+            stopped_reason,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_after_two_sessions_keeps_progress() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.time_of_day = 8.0;
+        let mut auto = AutoStudy::new("Python", AUTO_STUDY_SESSIONS);
+        let progress = |state: &GameState| {
+            let skill = &state.player.skills["Python"];
+            (skill.proficiency, skill.experience_points)
+        };
+        let before = progress(&state);
+
+        assert_eq!(auto.step(&mut state), AutoStudyStep::Running);
+        assert_eq!(auto.step(&mut state), AutoStudyStep::Running);
+        let after_two = progress(&state);
+        auto.cancel();
+
+        match auto.step(&mut state) {
+            AutoStudyStep::Finished(summary) => {
+                assert!(summary.interrupted);
+                assert_eq!(summary.sessions_done, 2);
+            }
+            AutoStudyStep::Running => panic!("auto-study ignored cancel"),
+        }
+        assert_eq!(progress(&state), after_two);
+        assert_ne!(after_two, before);
+    }
+
+    #[test]
+    fn test_runs_to_completion() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.time_of_day = 8.0;
+        let mut auto = AutoStudy::new("Python", 1);
+
+        assert_eq!(auto.step(&mut state), AutoStudyStep::Running);
+        match auto.step(&mut state) {
+            AutoStudyStep::Finished(summary) => {
+                assert!(!summary.interrupted);
+                assert!(summary.stopped_reason.is_none());
+                assert_eq!(summary.sessions_done, 1);
+            }
+            AutoStudyStep::Running => panic!("auto-study should have finished"),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Balance Analysis
+//!
+//! Theoretical best XP per day under a few study strategies, worked out
+//! from the balance constants alone, for tuning the economy. Each strategy
+//! studies one skill in back-to-back sessions from waking until the tired
+//! hours, as long as energy lasts. Burnout, trends and the energy cap are
+//! left out, so the numbers are upper bounds, but a strategy that beats
+//! another on both XP and cost here will beat it in play too.
+
+use super::sim::SIM_WAKE_HOUR;
+use crate::player::{
+    BuffKind, CoffeeMenu, MenuItem, Player, StudyVenue, STUDY_ENERGY_PER_HOUR, STUDY_SESSION_HOURS,
+    STUDY_XP_PER_HOUR, TIRED_FROM_HOUR,
+};
+
+/// Balance constants the analysis works from
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceParams {
+    pub max_energy: u32,
+    /// Hours from waking until the player gets tired
+    pub waking_hours: f32,
+    pub session_hours: u32,
+    pub xp_per_hour: u32,
+    pub energy_per_hour: u32,
+    /// Difficulty of the skill being studied
+    pub skill_difficulty: u8,
+}
+
+impl BalanceParams {
+    /// The game's constants, studying a difficulty-1 skill
+    pub fn current() -> Self {
+        Self {
+            max_energy: Player::new("Balance").max_energy,
+            waking_hours: TIRED_FROM_HOUR - SIM_WAKE_HOUR,
+            session_hours: STUDY_SESSION_HOURS,
+            xp_per_hour: STUDY_XP_PER_HOUR,
+            energy_per_hour: STUDY_ENERGY_PER_HOUR,
+            skill_difficulty: 1,
+        }
+    }
+
+    /// Sessions that fit between waking and getting tired
+    fn sessions_in_day(&self) -> u32 {
+        (self.waking_hours / self.session_hours.max(1) as f32) as u32
+    }
+
+    fn session_energy(&self) -> u32 {
+        self.session_hours * self.energy_per_hour * self.skill_difficulty.max(1) as u32
+    }
+
+    /// XP for one session, rounded the way studying rounds it
+    fn session_xp(&self, multiplier: f32) -> u32 {
+        ((self.session_hours * self.xp_per_hour) as f32 * multiplier).round() as u32
+    }
+}
+
+/// Best case for one strategy over a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyReport {
+    pub name: String,
+    pub sessions_per_day: u32,
+    pub xp_per_day: u32,
+    /// Money spent per day
+    pub cost_per_day: u32,
+}
+
+impl StrategyReport {
+    /// Whether `other` earns at least as much XP for no more money, and
+    /// is strictly better on one of them
+    pub fn is_dominated_by(&self, other: &StrategyReport) -> bool {
+        other.xp_per_day >= self.xp_per_day
+            && other.cost_per_day <= self.cost_per_day
+            && (other.xp_per_day > self.xp_per_day || other.cost_per_day < self.cost_per_day)
+    }
+}
+
+/// Study at a venue on one day's energy
+fn venue_strategy(params: &BalanceParams, venue: &StudyVenue) -> StrategyReport {
+    let sessions = (params.max_energy / params.session_energy().max(1)).min(params.sessions_in_day());
+    StrategyReport {
+        name: format!("{} study", venue.name),
+        sessions_per_day: sessions,
+        xp_per_day: sessions * params.session_xp(venue.xp_multiplier),
+        cost_per_day: sessions * venue.cost,
+    }
+}
+
+/// Library study with one drink before every session
+///
+/// A drink's crash is counted against the same day, and its buff only
+/// helps if it lasts the whole session.
+fn coffee_strategy(params: &BalanceParams, item: &MenuItem) -> StrategyReport {
+    let buff = |kind: BuffKind| {
+        item.buff
+            .as_ref()
+            .filter(|b| b.kind == kind && b.hours >= params.session_hours as f32)
+            .map_or(0.0, |b| b.magnitude)
+    };
+    let session_energy = (params.session_energy() as f32 * (1.0 - buff(BuffKind::Focus))).round() as i64;
+    let net_drink_energy = item.energy as i64 - item.crash.as_ref().map_or(0, |c| c.energy as i64);
+    let by_time = params.sessions_in_day();
+    let sessions = match session_energy - net_drink_energy {
+        shortfall if shortfall > 0 => ((params.max_energy as i64 / shortfall) as u32).min(by_time),
+        _ => by_time,
+    };
+    StrategyReport {
+        name: format!("Library + {}", item.name),
+        sessions_per_day: sessions,
+        xp_per_day: sessions * params.session_xp(1.0 + buff(BuffKind::StudyXp)),
+        cost_per_day: sessions * item.price,
+    }
+}
+
+/// Free library grinding, paid University courses and each coffee-boosted
+/// variant, in that order
+pub fn study_strategies(params: &BalanceParams, menu: &CoffeeMenu) -> Vec<StrategyReport> {
+    let mut reports = vec![
+        venue_strategy(params, &StudyVenue::LIBRARY),
+        venue_strategy(params, &StudyVenue::UNIVERSITY),
+    ];
+    reports.extend(menu.items.iter().map(|item| coffee_strategy(params, item)));
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_free_library_xp_matches_hand_calculation() {
+        let params = BalanceParams::current();
+        let reports = study_strategies(&params, &CoffeeMenu::load());
+        let free = &reports[0];
+
+        // 100 energy / (2h x 10 energy/h) = 5 sessions, each 2h x 25 XP/h
+        assert_eq!(free.sessions_per_day, 5);
+        assert_eq!(free.xp_per_day, 250);
+        assert_eq!(free.cost_per_day, 0);
+
+        // Tuition buys x1.5 XP on the same energy
+        assert_eq!(reports[1].xp_per_day, 375);
+        assert!(!free.is_dominated_by(&reports[1]));
+    }
+}
@@ -0,0 +1,110 @@
+//! Per-day breakdown of where the player's hours went.
+
+use serde::{Deserialize, Serialize};
+
+/// What the player spent time on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeCategory {
+    Studying,
+    Resting,
+    Interviewing,
+    Networking,
+    Commuting,
+    Working,
+}
+
+impl TimeCategory {
+    pub const ALL: [TimeCategory; 6] = [
+        TimeCategory::Studying,
+        TimeCategory::Resting,
+        TimeCategory::Interviewing,
+        TimeCategory::Networking,
+        TimeCategory::Commuting,
+        TimeCategory::Working,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeCategory::Studying => "Studying",
+            TimeCategory::Resting => "Resting",
+            TimeCategory::Interviewing => "Interviewing",
+            TimeCategory::Networking => "Networking",
+            TimeCategory::Commuting => "Commuting",
+            TimeCategory::Working => "Working",
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            TimeCategory::Studying => 0,
+            TimeCategory::Resting => 1,
+            TimeCategory::Interviewing => 2,
+            TimeCategory::Networking => 3,
+            TimeCategory::Commuting => 4,
+            TimeCategory::Working => 5,
+        }
+    }
+}
+
+/// Hours spent per category during a single day
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DayLog {
+    /// Indexed by category; saves from before a category existed have fewer entries
+    hours: Vec<f32>,
+}
+
+impl DayLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn log(&mut self, category: TimeCategory, hours: f32) {
+        let index = category.index();
+        if self.hours.len() <= index {
+            self.hours.resize(TimeCategory::ALL.len(), 0.0);
+        }
+        self.hours[index] += hours;
+    }
+
+    pub fn hours(&self, category: TimeCategory) -> f32 {
+        self.hours.get(category.index()).copied().unwrap_or(0.0)
+    }
+
+    pub fn total(&self) -> f32 {
+        self.hours.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0.0
+    }
+
+    /// One line per category that had any time logged
+    pub fn summary(&self, day: u32) -> String {
+        let mut lines = vec![format!("Day {} summary:", day)];
+        for category in TimeCategory::ALL {
+            let hours = self.hours(category);
+            if hours > 0.0 {
+                lines.push(format!("{}: {:.1}h", category.as_str(), hours));
+            }
+        }
+        if self.is_empty() {
+            lines.push("Nothing logged".to_string());
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_lists_only_used_categories() {
+        let mut log = DayLog::new();
+        log.log(TimeCategory::Studying, 2.0);
+        let summary = log.summary(3);
+        assert!(summary.starts_with("Day 3 summary:"));
+        assert!(summary.contains("Studying: 2.0h"));
+        assert!(!summary.contains("Resting"));
+    }
+}
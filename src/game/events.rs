@@ -0,0 +1,258 @@
+//! Event Log
+//!
+//! Append-only history of notable moments (skills leveling up, jobs
+//! landed, career promotions, startups, missed interviews, declined and
+//! lapsed offers), shown on the Event Log screen and kept in saves.
+
+use std::collections::HashMap;
+
+use super::action::ActionResult;
+use super::rng::GameRng;
+use super::state::GameState;
+use crate::interview::Interview;
+use crate::jobs::Job;
+use crate::player::{PartTimeJob, Player, VentureOutcome, FOUNDING_COST};
+use crate::skills::Proficiency;
+
+/// Oldest entries are dropped once the log grows past this
+pub const MAX_EVENT_LOG: usize = 200;
+
+/// An event as shown on screen, e.g. "Day 4: Python reached Basic"
+pub fn format_event((day, text): &(u32, String)) -> String {
+    format!("Day {}: {}", day, text)
+}
+
+impl GameState {
+    /// Record something that happened today
+    pub fn log_event(&mut self, text: impl Into<String>) {
+        self.event_log.push((self.day, text.into()));
+        if self.event_log.len() > MAX_EVENT_LOG {
+            let excess = self.event_log.len() - MAX_EVENT_LOG;
+            self.event_log.drain(..excess);
+        }
+    }
+
+    /// Logged events, most recent first
+    pub fn recent_events(&self) -> impl Iterator<Item = &(u32, String)> {
+        self.event_log.iter().rev()
+    }
+
+    /// Start working at a job and log the hire
+    pub fn land_job(&mut self, job: &Job, salary: u32) {
+        self.player.accept_job(job, salary);
+        self.log_event(format!("Hired as {} at {} (${}/year)", job.title, job.company, salary));
+    }
+
+    /// Take a pending offer, and log the hire
+    pub fn accept_offer(&mut self, job_id: u32) -> ActionResult {
+        let offer = match self.player.accept_offer(job_id) {
+            Ok(offer) => offer,
+            Err(reason) => return ActionResult::Blocked(reason),
+        };
+        self.log_event(format!("Hired as {} at {} (${}/year)", offer.job.title, offer.job.company, offer.salary));
+        ActionResult::Done(format!(
+            "You accepted the offer! You now work as {} at {} for ${}/year.",
+            offer.job.title, offer.job.company, offer.salary
+        ))
+    }
+
+    /// Turn down a pending offer, and log it
+    pub fn decline_offer(&mut self, job_id: u32) -> ActionResult {
+        let Some(offer) = self.player.take_offer(job_id) else {
+            return ActionResult::Blocked("That offer is no longer open".to_string());
+        };
+        self.log_event(format!("Declined the offer from {}", offer.job.company));
+        ActionResult::Done(format!("You turned down the {} offer from {}.", offer.job.title, offer.job.company))
+    }
+
+    /// Found a startup with the game seed driving its market, and log it
+    pub fn found_startup(&mut self) -> ActionResult {
+        if let Err(reason) = self.player.found_startup(self.seed) {
+            let running = self.player.active_venture().is_some();
+            return if !running && self.player.money < FOUNDING_COST {
+                ActionResult::Insufficient(reason)
+            } else {
+                ActionResult::Blocked(reason)
+            };
+        }
+        self.log_event(format!("Founded a startup with ${}", FOUNDING_COST));
+        ActionResult::Done(format!(
+            "You put ${} into your own startup. No more salary: the company now earns or loses money every day.",
+            FOUNDING_COST
+        ))
+    }
+
+    /// Take a part-time job, and log it
+    pub fn take_part_time_job(&mut self, job: PartTimeJob) -> ActionResult {
+        if let Err(reason) = self.player.take_part_time_job(job) {
+            return ActionResult::Blocked(reason);
+        }
+        self.log_event(format!("Started part-time as a {}", job.title()));
+        ActionResult::Done(format!(
+            "You're a {} now: ${} and {} energy every workday, for {}h. It won't count as ML experience.",
+            job.title(),
+            job.daily_pay(),
+            job.energy_cost(),
+            job.hours()
+        ))
+    }
+
+    /// Sit a skill's certification exam, and log the certificate if it's passed
+    ///
+    /// The exam is the skill's technical interview round held at Advanced
+    /// level; the fee and energy are spent either way.
+    pub fn take_certification_exam(&mut self, skill_name: &str) -> ActionResult {
+        if let Err(reason) = self.player.pay_for_certification_exam(skill_name) {
+            let unavailable = self.player.is_certified(skill_name) || !self.player.skills.contains_key(skill_name);
+            return if unavailable {
+                ActionResult::Blocked(reason)
+            } else {
+                ActionResult::Insufficient(reason)
+            };
+        }
+        let round = Interview::certification_round(skill_name);
+        let mut rng = GameRng::for_exam(self.seed, self.day, self.time_of_day as u32);
+        let result = Interview::conduct_round_with(&self.player, &round, &mut rng);
+        if !result.passed {
+            return ActionResult::Done(format!(
+                "You scored {:.0}% on the {} exam but needed {:.0}%. Study up and try again.",
+                result.score * 100.0,
+                round.name,
+                round.pass_threshold * 100.0
+            ));
+        }
+        self.player.certify(skill_name);
+        self.log_event(format!("Certified in {}", skill_name));
+        ActionResult::Done(format!(
+            "Passed with {:.0}%! Interviewers can now verify your {} skills.",
+            result.score * 100.0,
+            skill_name
+        ))
+    }
+
+    /// Run a player action, logging any skill level-ups, promotions and startup endings it causes
+    pub fn track_progress<T>(&mut self, action: impl FnOnce(&mut Player) -> T) -> T {
+        let before: HashMap<String, Proficiency> = self
+            .player
+            .skills
+            .iter()
+            .map(|(name, skill)| (name.clone(), skill.proficiency))
+            .collect();
+        let level_before = self.player.career_level();
+        let venture_running = self.player.active_venture().is_some();
+
+        let result = action(&mut self.player);
+
+        let mut leveled: Vec<(String, Proficiency)> = self
+            .player
+            .skills
+            .iter()
+            .filter(|(name, skill)| before.get(*name).is_some_and(|&p| skill.proficiency > p))
+            .map(|(name, skill)| (name.clone(), skill.proficiency))
+            .collect();
+        leveled.sort_by(|a, b| a.0.cmp(&b.0));
+        for (name, proficiency) in leveled {
+            self.log_event(format!("{} reached {}", name, proficiency.as_str()));
+        }
+        let level_after = self.player.career_level();
+        if level_after > level_before {
+            self.log_event(format!("Promoted to career level {}", level_after));
+        }
+        if let Some(venture) = self.player.venture.clone().filter(|_| venture_running) {
+            match venture.outcome {
+                Some(VentureOutcome::Acquired) => {
+                    self.log_event(format!("Startup acquired! ${} paid out", venture.balance))
+                }
+                Some(VentureOutcome::Bankrupt) => self.log_event("Startup went bankrupt"),
+                None => {}
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies::get_all_companies;
+
+    #[test]
+    fn test_landing_a_job_logs_it() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut state = GameState::with_seed("Test", 1);
+        state.day = 6;
+
+        state.land_job(&job, 85_000);
+
+        let expected = format!("Hired as {} at {} ($85000/year)", job.title, job.company);
+        assert_eq!(state.event_log.last(), Some(&(6, expected.clone())));
+        assert_eq!(format_event(state.recent_events().next().unwrap()), format!("Day 6: {}", expected));
+        assert!(state.player.employed);
+    }
+
+    #[test]
+    fn test_accepting_an_offer_while_employed_is_blocked() {
+        let companies = get_all_companies();
+        let (first, second) = (companies[0].open_positions[0].clone(), companies[1].open_positions[0].clone());
+        let mut state = GameState::with_seed("Test", 1);
+        state.player.receive_offer(&first, 90_000);
+        state.player.receive_offer(&second, 120_000);
+
+        assert!(state.accept_offer(first.id).succeeded());
+        let logged = state.event_log.len();
+        assert!(matches!(state.accept_offer(second.id), ActionResult::Blocked(_)));
+        assert_eq!(state.player.current_salary, 90_000);
+        assert_eq!(state.event_log.len(), logged);
+        assert!(state.player.pending_offer(second.id).is_some());
+    }
+
+    #[test]
+    fn test_level_ups_are_logged_and_log_is_capped() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.track_progress(|player| player.set_skill("Python", Proficiency::Basic).unwrap());
+        assert_eq!(state.event_log.last().unwrap().1, "Python reached Basic");
+
+        for i in 0..MAX_EVENT_LOG + 10 {
+            state.log_event(format!("Event {}", i));
+        }
+        assert_eq!(state.event_log.len(), MAX_EVENT_LOG);
+        assert_eq!(state.recent_events().next().unwrap().1, format!("Event {}", MAX_EVENT_LOG + 9));
+    }
+
+    #[test]
+    fn test_passing_a_certification_exam_certifies_and_logs_it() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.player.money = 1_000;
+        assert!(state.take_certification_exam("SQL").succeeded());
+        assert!(!state.player.is_certified("SQL"));
+
+        state.player.set_skill("Python", Proficiency::Expert).unwrap();
+        assert!(state.take_certification_exam("Python").succeeded());
+        assert!(state.player.is_certified("Python"));
+        assert_eq!(state.event_log.last().unwrap().1, "Certified in Python");
+        assert!(matches!(state.take_certification_exam("Python"), ActionResult::Blocked(_)));
+
+        state.player.money = 0;
+        assert!(matches!(state.take_certification_exam("SQL"), ActionResult::Insufficient(_)));
+    }
+
+    #[test]
+    fn test_startup_founding_and_ending_are_logged() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.player.money = FOUNDING_COST;
+        assert!(matches!(state.found_startup(), ActionResult::Blocked(_)));
+
+        for skill in ["Python", "SQL", "Statistics"] {
+            state.player.set_skill(skill, Proficiency::Advanced).unwrap();
+        }
+        assert!(state.found_startup().succeeded());
+        assert_eq!(state.event_log.last().unwrap().1, format!("Founded a startup with ${}", FOUNDING_COST));
+
+        state.player.venture.as_mut().unwrap().balance = 1;
+        for skill in state.player.skills.values_mut() {
+            skill.proficiency = Proficiency::None;
+        }
+        state.track_progress(Player::advance_day);
+        assert_eq!(state.event_log.last().unwrap().1, "Startup went bankrupt");
+    }
+}
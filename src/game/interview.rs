@@ -0,0 +1,216 @@
+//! Interview Outcomes
+//!
+//! The scored result of a finished interview quiz, and how it changes the
+//! game: a pass makes an offer, a failure puts the job on cooldown. Screens
+//! only decide how to present the outcome.
+
+use super::state::GameState;
+use crate::interview::quiz::{QuizAnswer, QuizQuestion};
+use crate::interview::{InterviewResult, InterviewRound};
+use crate::jobs::{CompanyTier, Job};
+use crate::player::Player;
+
+/// How a finished interview went
+#[derive(Debug, Clone)]
+pub struct InterviewOutcome {
+    pub job: Job,
+    pub tier: CompanyTier,
+    /// Points scored, after any negative marking
+    pub score: f32,
+    /// Questions asked
+    pub total: u32,
+    /// Points each wrong answer lost; 0 without negative marking
+    pub wrong_penalty: f32,
+    pub passed: bool,
+    /// Salary offered, set only when the interview was passed
+    pub offered_salary: Option<u32>,
+}
+
+impl InterviewOutcome {
+    /// Score a finished quiz; passing takes at least half the questions' worth of points
+    ///
+    /// `answers` go with `questions` in order; questions left unanswered
+    /// still count toward the total. Wrong answers lose `wrong_penalty`
+    /// each (see `InterviewConfig::marking_penalty`), and burnout scales
+    /// the score down. An answer on a skill the player is certified in
+    /// never earns less than the certification floor. A pass comes with an
+    /// offer sized to `player` as a candidate.
+    pub fn from_quiz(
+        job: Job,
+        tier: CompanyTier,
+        player: &Player,
+        questions: &[QuizQuestion],
+        answers: &[QuizAnswer],
+        wrong_penalty: f32,
+    ) -> Self {
+        let multiplier = player.burnout.score_multiplier();
+        let score = answers
+            .iter()
+            .zip(questions)
+            .map(|(answer, question)| {
+                let points = answer.marked_points(wrong_penalty) * multiplier;
+                match question.related_skill.as_deref().and_then(|skill| player.certified_score_floor(skill)) {
+                    Some(floor) => points.max(floor),
+                    None => points,
+                }
+            })
+            .sum::<f32>()
+            .max(0.0);
+        let total = questions.len() as u32;
+        let passed = score >= (total / 2) as f32;
+        let offered_salary = passed.then(|| job.offer_for(player, tier));
+        Self {
+            job,
+            tier,
+            score,
+            total,
+            wrong_penalty,
+            passed,
+            offered_salary,
+        }
+    }
+}
+
+impl InterviewOutcome {
+    /// This quiz as one round of an `InterviewSession`, scored as the share
+    /// of points won and passed at the round's threshold
+    pub fn round_result(&self, round: &InterviewRound) -> InterviewResult {
+        let share = if self.total == 0 { 0.0 } else { self.score / self.total as f32 };
+        InterviewResult {
+            round_name: round.name.clone(),
+            score: share,
+            passed: share >= round.pass_threshold,
+            feedback: Vec::new(),
+        }
+    }
+
+    /// Settle the whole interview on the session's verdict, however this
+    /// quiz went on its own
+    pub fn settle(mut self, hired: bool, player: &Player) -> Self {
+        self.passed = hired;
+        self.offered_salary = hired.then(|| self.job.offer_for(player, self.tier));
+        self
+    }
+
+    /// Score as shown on the results screen, e.g. "2.5/4 (-0.25 per wrong answer)"
+    pub fn score_text(&self) -> String {
+        let score = format!("{:.1}/{}", self.score, self.total);
+        if self.wrong_penalty > 0.0 {
+            format!("{} (-{} per wrong answer)", score, self.wrong_penalty)
+        } else {
+            score
+        }
+    }
+}
+
+impl GameState {
+    /// Make the player an offer on a pass, or start the job's interview cooldown on a failure
+    pub fn apply_interview_outcome(&mut self, outcome: &InterviewOutcome) {
+        match outcome.offered_salary {
+            Some(salary) if outcome.passed => {
+                self.player.receive_offer(&outcome.job, salary);
+            }
+            _ => self.player.start_interview_cooldown(outcome.job.id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies::get_all_companies;
+    use crate::interview::quiz::{create_question_for_skill, Confidence, SURE_WRONG_PENALTY};
+    use crate::player::CERT_SCORE_FLOOR;
+
+    fn answers(correct: &[bool]) -> Vec<QuizAnswer> {
+        correct
+            .iter()
+            .map(|&correct| QuizAnswer { correct, confidence: Confidence::Sure })
+            .collect()
+    }
+
+    fn python_questions(count: usize) -> Vec<QuizQuestion> {
+        vec![create_question_for_skill("Python"); count]
+    }
+
+    #[test]
+    fn test_passing_outcome_makes_an_offer_at_offered_salary() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut state = GameState::with_seed("Test", 1);
+
+        let outcome =
+            InterviewOutcome::from_quiz(job.clone(), CompanyTier::BigTech, &state.player, &python_questions(3), &answers(&[true, true, false]), 0.0);
+        state.apply_interview_outcome(&outcome);
+
+        let offer = job.offer_for(&state.player, CompanyTier::BigTech);
+        assert!(outcome.passed);
+        assert_eq!(outcome.offered_salary, Some(offer));
+        assert!(!state.player.employed);
+        assert_eq!(state.player.pending_offer(job.id).unwrap().salary, offer);
+        assert!(state.player.cooldown_remaining(job.id).is_none());
+    }
+
+    #[test]
+    fn test_negative_marking_can_turn_a_pass_into_a_failure() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let player = Player::new("Test");
+        let mut guesses = answers(&[true, true, false, false]);
+        for answer in &mut guesses[2..] {
+            answer.confidence = Confidence::Unsure;
+        }
+
+        let plain = InterviewOutcome::from_quiz(job.clone(), CompanyTier::Startup, &player, &python_questions(4), &guesses, 0.0);
+        let marked = InterviewOutcome::from_quiz(job, CompanyTier::Startup, &player, &python_questions(4), &guesses, 0.25);
+
+        assert!(plain.passed);
+        assert!(!marked.passed);
+        assert!(marked.score < plain.score);
+        assert_eq!(plain.score_text(), "2.0/4");
+        assert_eq!(marked.score_text(), "1.5/4 (-0.25 per wrong answer)");
+    }
+
+    #[test]
+    fn test_burnout_lowers_the_score() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut player = Player::new("Test");
+        let four_right = answers(&[true, true, true, true]);
+        let rested = InterviewOutcome::from_quiz(job.clone(), CompanyTier::Startup, &player, &python_questions(4), &four_right, 0.0);
+
+        player.burnout.intensity = 2;
+        let burnt_out = InterviewOutcome::from_quiz(job, CompanyTier::Startup, &player, &python_questions(4), &four_right, 0.0);
+
+        assert_eq!(rested.score, 4.0);
+        assert!((burnt_out.score - 4.0 * player.burnout.score_multiplier()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_certified_skill_answers_never_score_below_the_floor() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut player = Player::new("Test");
+        player.certifications.insert("Python".to_string());
+        let mut questions = python_questions(2);
+        questions.push(create_question_for_skill("SQL"));
+
+        let outcome =
+            InterviewOutcome::from_quiz(job, CompanyTier::Startup, &player, &questions, &answers(&[false, false, false]), 0.25);
+
+        // The uncertified SQL miss still costs its penalty
+        let expected = 2.0 * CERT_SCORE_FLOOR + SURE_WRONG_PENALTY - 0.25;
+        assert!((outcome.score - expected).abs() < 1e-6, "{}", outcome.score);
+    }
+
+    #[test]
+    fn test_failing_outcome_sets_cooldown() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut state = GameState::with_seed("Test", 1);
+
+        let outcome =
+            InterviewOutcome::from_quiz(job.clone(), CompanyTier::Startup, &state.player, &python_questions(4), &answers(&[false, false, true, false]), 0.0);
+        state.apply_interview_outcome(&outcome);
+
+        assert!(!outcome.passed);
+        assert_eq!(outcome.offered_salary, None);
+        assert!(!state.player.employed);
+        assert!(state.player.cooldown_remaining(job.id).is_some());
+    }
+}
@@ -1,3 +1,33 @@
+mod action;
+mod auto_rest;
+mod auto_study;
+mod balance;
+mod day_log;
+mod events;
+mod interview;
+mod money_history;
+mod rng;
+mod save;
+mod schedule;
+mod shutdown;
+mod sim;
 mod state;
+mod trends;
 
+pub use action::{buy_drink, check_interview_cooldown, research_job, study_session, warm_up, ActionResult};
+pub use auto_rest::HOME_REST_HOURS;
+pub use auto_study::{AutoStudy, AutoStudyStep, AutoStudySummary, AUTO_STUDY_SESSIONS};
+pub use balance::{study_strategies, BalanceParams, StrategyReport};
+pub use day_log::{DayLog, TimeCategory};
+pub use events::{format_event, MAX_EVENT_LOG};
+pub use interview::InterviewOutcome;
+pub use money_history::{MoneyHistory, MoneySnapshot, MONEY_HISTORY_DAYS};
+pub use rng::GameRng;
+pub use save::{
+    autosave_path, delete_save, encode_save, list_slots, load_autosave, load_game, read_header, save_game, save_game_as,
+    slot_path, unix_now, SaveFormat, SaveHeader, SAVE_DIR, SAVE_FORMAT_ENV, SAVE_SLOTS,
+};
+pub use shutdown::{DiskSink, FileSink, ShutdownFlush, ShutdownHook};
+pub use sim::{GameSim, SIM_INTERVIEW_THRESHOLD, SIM_WAKE_HOUR};
 pub use state::{GameScreen, GameState};
+pub use trends::{trends_for_day, TREND_MULTIPLIER, TREND_PERIOD_DAYS};
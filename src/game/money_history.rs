@@ -0,0 +1,101 @@
+//! Money History
+//!
+//! One snapshot of the player's money and salary per day, for the chart on
+//! the skills screen. Only the most recent `MONEY_HISTORY_DAYS` are kept,
+//! in memory and in saves.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use super::state::GameState;
+
+/// Days of history kept before the oldest snapshot is dropped
+pub const MONEY_HISTORY_DAYS: usize = 120;
+
+/// Money and salary at the start of a day
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MoneySnapshot {
+    pub day: u32,
+    pub money: u32,
+    pub salary: u32,
+}
+
+/// Daily snapshots, oldest first, capped at `MONEY_HISTORY_DAYS`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MoneyHistory {
+    snapshots: VecDeque<MoneySnapshot>,
+}
+
+impl MoneyHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, snapshot: MoneySnapshot) {
+        if self.snapshots.len() >= MONEY_HISTORY_DAYS {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MoneySnapshot> {
+        self.snapshots.iter()
+    }
+
+    pub fn latest(&self) -> Option<&MoneySnapshot> {
+        self.snapshots.back()
+    }
+}
+
+impl GameState {
+    /// Snapshot the player's money for today; called when a new day starts
+    pub(super) fn record_money(&mut self) {
+        self.money_history.record(MoneySnapshot {
+            day: self.day,
+            money: self.player.money,
+            salary: self.player.current_salary,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::TimeCategory;
+
+    #[test]
+    fn test_each_day_adds_a_snapshot() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.player.current_salary = 22_000;
+        state.player.employed = true;
+        for _ in 0..3 {
+            state.advance_time(24.0, TimeCategory::Resting);
+        }
+
+        let days: Vec<u32> = state.money_history.iter().map(|s| s.day).collect();
+        assert_eq!(days, vec![2, 3, 4]);
+        let latest = state.money_history.latest().unwrap();
+        assert_eq!(latest.money, state.player.money);
+        assert_eq!(latest.salary, 22_000);
+    }
+
+    #[test]
+    fn test_history_caps_at_max_length() {
+        let mut history = MoneyHistory::new();
+        for day in 0..MONEY_HISTORY_DAYS as u32 + 10 {
+            history.record(MoneySnapshot { day, money: day * 10, salary: 0 });
+        }
+        assert_eq!(history.len(), MONEY_HISTORY_DAYS);
+        assert_eq!(history.iter().next().unwrap().day, 10);
+        assert_eq!(history.latest().unwrap().day, MONEY_HISTORY_DAYS as u32 + 9);
+    }
+}
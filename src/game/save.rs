@@ -0,0 +1,405 @@
+//! Save Slots
+//!
+//! Each slot is a file in the save directory. The first line is a small
+//! JSON header (player name, day, salary, save time) so the slot list can
+//! be shown without reading the whole game; the rest holds the full saved
+//! game, as JSON in `slotN.save` files or bincode in `slotN.bsave` files.
+//! JSON stays the default; set `RPG_SAVE_FORMAT=binary` for compact saves.
+//! An autosave written on quit sits beside the slots in `autosave.save`.
+
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::day_log::DayLog;
+use super::events::MAX_EVENT_LOG;
+use super::money_history::MoneyHistory;
+use super::state::{GameScreen, GameState};
+use super::trends::trends_for_day;
+use crate::player::Player;
+
+/// Number of save slots offered to the player
+pub const SAVE_SLOTS: usize = 3;
+/// Directory save files are written to, relative to the working directory
+pub const SAVE_DIR: &str = "saves";
+/// Environment variable choosing the format new saves are written in
+pub const SAVE_FORMAT_ENV: &str = "RPG_SAVE_FORMAT";
+
+/// Encoding of the saved game that follows the header line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SaveFormat {
+    /// Readable JSON, the default
+    #[default]
+    Json,
+    /// Compact bincode
+    Binary,
+}
+
+impl SaveFormat {
+    pub const ALL: [SaveFormat; 2] = [SaveFormat::Json, SaveFormat::Binary];
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            SaveFormat::Json => "save",
+            SaveFormat::Binary => "bsave",
+        }
+    }
+
+    /// Format a save file is in, judged by its extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?;
+        Self::ALL.into_iter().find(|format| format.extension() == ext)
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "json" => Some(SaveFormat::Json),
+            "binary" | "bincode" => Some(SaveFormat::Binary),
+            _ => None,
+        }
+    }
+
+    /// Format named by `RPG_SAVE_FORMAT`, or JSON if it's unset
+    ///
+    /// # Errors
+    /// Returns a message naming the value if it's neither json nor binary
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(SAVE_FORMAT_ENV) {
+            Ok(value) => Self::parse(&value)
+                .ok_or_else(|| format!("Ignoring {}={:?}: expected json or binary", SAVE_FORMAT_ENV, value)),
+            Err(_) => Ok(SaveFormat::default()),
+        }
+    }
+
+    fn encode(self, game: &SavedGame) -> Result<Vec<u8>> {
+        Ok(match self {
+            SaveFormat::Json => serde_json::to_vec(game)?,
+            SaveFormat::Binary => bincode::serialize(game)?,
+        })
+    }
+
+    fn decode(self, body: &[u8]) -> Result<SavedGame> {
+        Ok(match self {
+            SaveFormat::Json => serde_json::from_slice(body)?,
+            SaveFormat::Binary => bincode::deserialize(body)?,
+        })
+    }
+}
+
+/// Summary of a save, readable without loading the game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaveHeader {
+    pub player_name: String,
+    pub day: u32,
+    pub salary: u32,
+    /// Unix timestamp (seconds) of when the slot was written
+    pub saved_at: u64,
+}
+
+impl SaveHeader {
+    /// How long ago the save was written, e.g. "5m ago"
+    pub fn saved_ago(&self, now: u64) -> String {
+        let secs = now.saturating_sub(self.saved_at);
+        match secs {
+            0..=59 => "just now".to_string(),
+            60..=3599 => format!("{}m ago", secs / 60),
+            3600..=86399 => format!("{}h ago", secs / 3600),
+            _ => format!("{}d ago", secs / 86400),
+        }
+    }
+}
+
+/// The parts of `GameState` that persist between sessions
+#[derive(Debug, Serialize, Deserialize)]
+struct SavedGame {
+    player: Player,
+    day: u32,
+    time_of_day: f32,
+    seed: u64,
+    day_log: DayLog,
+    #[serde(default)]
+    event_log: Vec<(u32, String)>,
+    #[serde(default)]
+    money_history: MoneyHistory,
+}
+
+/// Current time as a Unix timestamp in seconds
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// File backing a slot in the given format
+pub fn slot_path(dir: &Path, slot: usize, format: SaveFormat) -> PathBuf {
+    dir.join(format!("slot{}.{}", slot + 1, format.extension()))
+}
+
+/// The file a slot is currently saved in, whichever format it is
+fn find_slot_file(dir: &Path, slot: usize) -> Option<(PathBuf, SaveFormat)> {
+    SaveFormat::ALL
+        .into_iter()
+        .map(|format| (slot_path(dir, slot, format), format))
+        .find(|(path, _)| path.exists())
+}
+
+fn remove_if_present(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e).with_context(|| format!("deleting {}", path.display())),
+    }
+}
+
+fn check_slot(slot: usize) -> Result<()> {
+    if slot >= SAVE_SLOTS {
+        bail!("No save slot {} (there are {})", slot + 1, SAVE_SLOTS);
+    }
+    Ok(())
+}
+
+/// Write the game to a slot as JSON, replacing whatever was there
+pub fn save_game(dir: &Path, slot: usize, state: &GameState) -> Result<()> {
+    save_game_as(dir, slot, state, SaveFormat::default())
+}
+
+/// File the autosave is written to in the given format
+pub fn autosave_path(dir: &Path, format: SaveFormat) -> PathBuf {
+    dir.join(format!("autosave.{}", format.extension()))
+}
+
+/// A save file's contents: the header line, then the encoded game
+pub fn encode_save(state: &GameState, format: SaveFormat) -> Result<Vec<u8>> {
+    let header = SaveHeader {
+        player_name: state.player.name.clone(),
+        day: state.day,
+        salary: state.player.current_salary,
+        saved_at: unix_now(),
+    };
+    let game = SavedGame {
+        player: state.player.clone(),
+        day: state.day,
+        time_of_day: state.time_of_day,
+        seed: state.seed,
+        day_log: state.day_log.clone(),
+        event_log: state.event_log[state.event_log.len().saturating_sub(MAX_EVENT_LOG)..].to_vec(),
+        money_history: state.money_history.clone(),
+    };
+    let mut contents = serde_json::to_vec(&header)?;
+    contents.push(b'\n');
+    contents.extend(format.encode(&game)?);
+    Ok(contents)
+}
+
+/// Write the game to a slot in the given format, replacing whatever was there
+pub fn save_game_as(dir: &Path, slot: usize, state: &GameState, format: SaveFormat) -> Result<()> {
+    check_slot(slot)?;
+    let contents = encode_save(state, format)?;
+    fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    let path = slot_path(dir, slot, format);
+    fs::write(&path, contents).with_context(|| format!("writing {}", path.display()))?;
+    // A slot holds one save: drop any copy left in another format
+    for other in SaveFormat::ALL.into_iter().filter(|&f| f != format) {
+        remove_if_present(&slot_path(dir, slot, other))?;
+    }
+    Ok(())
+}
+
+/// Decode the saved game in a save file
+fn read_saved_game(path: &Path, format: SaveFormat) -> Result<SavedGame> {
+    let contents = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    let body = contents
+        .iter()
+        .position(|&b| b == b'\n')
+        .map(|newline| &contents[newline + 1..])
+        .filter(|body| !body.is_empty())
+        .with_context(|| format!("{} has no saved game", path.display()))?;
+    format.decode(body).with_context(|| format!("parsing {}", path.display()))
+}
+
+/// Load the game in a slot, starting on the world screen
+pub fn load_game(dir: &Path, slot: usize) -> Result<GameState> {
+    check_slot(slot)?;
+    let (path, format) = find_slot_file(dir, slot).with_context(|| format!("Slot {} is empty", slot + 1))?;
+    read_saved_game(&path, format).map(restore)
+}
+
+/// Load the game autosaved on the last quit, starting on the world screen
+pub fn load_autosave(dir: &Path) -> Result<GameState> {
+    let (path, format) = SaveFormat::ALL
+        .into_iter()
+        .map(|format| (autosave_path(dir, format), format))
+        .find(|(path, _)| path.exists())
+        .context("There's no autosave yet")?;
+    read_saved_game(&path, format).map(restore)
+}
+
+/// Rebuild the running game from what was saved
+fn restore(game: SavedGame) -> GameState {
+    let mut state = GameState::with_seed(&game.player.name, game.seed);
+    state.player = game.player;
+    state.day = game.day;
+    state.time_of_day = game.time_of_day;
+    state.day_log = game.day_log;
+    state.event_log = game.event_log;
+    state.money_history = game.money_history;
+    state.trends = trends_for_day(game.seed, game.day);
+    state.reset_screen(GameScreen::World);
+    state
+}
+
+/// Header of the save in a slot, or `None` if the slot is empty
+pub fn read_header(dir: &Path, slot: usize) -> Result<Option<SaveHeader>> {
+    check_slot(slot)?;
+    let Some((path, _)) = find_slot_file(dir, slot) else { return Ok(None) };
+    let file = match fs::File::open(&path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("opening {}", path.display())),
+    };
+    let mut first_line = String::new();
+    BufReader::new(file).read_line(&mut first_line)?;
+    let header = serde_json::from_str(&first_line).with_context(|| format!("parsing {}", path.display()))?;
+    Ok(Some(header))
+}
+
+/// Headers for every slot, in slot order
+pub fn list_slots(dir: &Path) -> Vec<Result<Option<SaveHeader>>> {
+    (0..SAVE_SLOTS).map(|slot| read_header(dir, slot)).collect()
+}
+
+/// Remove the save in a slot; deleting an empty slot is not an error
+pub fn delete_save(dir: &Path, slot: usize) -> Result<()> {
+    check_slot(slot)?;
+    for format in SaveFormat::ALL {
+        remove_if_present(&slot_path(dir, slot, format))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::MoneySnapshot;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ai_career_rpg_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_slots_are_independent() {
+        let dir = temp_dir("slots");
+        let mut first = GameState::with_seed("Ada", 1);
+        first.day = 4;
+        first.player.current_salary = 90_000;
+        save_game(&dir, 0, &first).unwrap();
+
+        let mut second = GameState::with_seed("Grace", 2);
+        second.day = 12;
+        save_game(&dir, 1, &second).unwrap();
+
+        let header = read_header(&dir, 0).unwrap().unwrap();
+        assert_eq!(header.player_name, "Ada");
+        assert_eq!(header.day, 4);
+        assert_eq!(header.salary, 90_000);
+        assert_eq!(read_header(&dir, 1).unwrap().unwrap().player_name, "Grace");
+        assert_eq!(read_header(&dir, 2).unwrap(), None);
+
+        let loaded = load_game(&dir, 0).unwrap();
+        assert_eq!(loaded.player.name, "Ada");
+        assert_eq!(loaded.day, 4);
+        assert_eq!(loaded.seed, 1);
+
+        delete_save(&dir, 1).unwrap();
+        assert_eq!(read_header(&dir, 1).unwrap(), None);
+        assert!(read_header(&dir, 0).unwrap().is_some());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_relationships_survive_round_trip() {
+        let dir = temp_dir("relationships");
+        let mut state = GameState::with_seed("Ada", 1);
+        state.player.record_talk("Alex", "We're always hiring!");
+        state.player.record_talk("Alex", "Keep your skills sharp.");
+        save_game(&dir, 0, &state).unwrap();
+
+        let loaded = load_game(&dir, 0).unwrap();
+        let rel = loaded.player.relationship("Alex").expect("relationship saved");
+        assert_eq!(rel.times_talked, 2);
+        assert_eq!(rel, state.player.relationship("Alex").unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_json_and_binary_saves_load_the_same_game() {
+        let json_dir = temp_dir("format_json");
+        let binary_dir = temp_dir("format_binary");
+        let mut state = GameState::with_seed("Ada", 7);
+        state.day = 9;
+        state.time_of_day = 14.5;
+        state.player.money = 1234;
+        state.player.record_talk("Alex", "We're always hiring!");
+        state.player.certify("Python");
+        state.log_event("Python reached Basic");
+        state.money_history.record(MoneySnapshot { day: 9, money: 1234, salary: 0 });
+        save_game_as(&json_dir, 0, &state, SaveFormat::Json).unwrap();
+        save_game_as(&binary_dir, 0, &state, SaveFormat::Binary).unwrap();
+
+        let from_json = read_saved_game(&slot_path(&json_dir, 0, SaveFormat::Json), SaveFormat::Json).unwrap();
+        let from_binary = read_saved_game(&slot_path(&binary_dir, 0, SaveFormat::Binary), SaveFormat::Binary).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&from_json).unwrap(),
+            serde_json::to_value(&from_binary).unwrap()
+        );
+        assert_eq!(from_binary.time_of_day, 14.5);
+        assert_eq!(from_binary.event_log, vec![(9, "Python reached Basic".to_string())]);
+        assert_eq!(from_binary.money_history, state.money_history);
+        assert!(from_binary.player.is_certified("Python"));
+        assert_eq!(read_header(&binary_dir, 0).unwrap().unwrap().day, 9);
+        let json_len = fs::metadata(slot_path(&json_dir, 0, SaveFormat::Json)).unwrap().len();
+        let binary_len = fs::metadata(slot_path(&binary_dir, 0, SaveFormat::Binary)).unwrap().len();
+        assert!(binary_len < json_len);
+        fs::remove_dir_all(&json_dir).unwrap();
+        fs::remove_dir_all(&binary_dir).unwrap();
+    }
+
+    #[test]
+    fn test_saving_in_new_format_replaces_old_file() {
+        let dir = temp_dir("format_switch");
+        let state = GameState::with_seed("Ada", 1);
+        save_game(&dir, 0, &state).unwrap();
+        save_game_as(&dir, 0, &state, SaveFormat::Binary).unwrap();
+
+        assert!(!slot_path(&dir, 0, SaveFormat::Json).exists());
+        assert_eq!(SaveFormat::from_path(&slot_path(&dir, 0, SaveFormat::Binary)), Some(SaveFormat::Binary));
+        assert_eq!(load_game(&dir, 0).unwrap().player.name, "Ada");
+
+        delete_save(&dir, 0).unwrap();
+        assert_eq!(read_header(&dir, 0).unwrap(), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_out_of_range_slot_rejected() {
+        let dir = temp_dir("range");
+        let state = GameState::with_seed("Ada", 1);
+        assert!(save_game(&dir, SAVE_SLOTS, &state).is_err());
+    }
+
+    #[test]
+    fn test_saved_ago() {
+        let header = SaveHeader { player_name: String::new(), day: 1, salary: 0, saved_at: 1000 };
+        assert_eq!(header.saved_ago(1030), "just now");
+        assert_eq!(header.saved_ago(1000 + 300), "5m ago");
+        assert_eq!(header.saved_ago(1000 + 7200), "2h ago");
+    }
+}
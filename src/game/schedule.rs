@@ -0,0 +1,81 @@
+//! Attending Scheduled Interviews
+//!
+//! Moves the clock forward to a booked interview slot. Booking and
+//! forfeiting slots live on `Player`.
+
+use super::day_log::{DayLog, TimeCategory};
+use super::state::GameState;
+use crate::player::ScheduledInterview;
+
+impl GameState {
+    /// Let time pass until the given day and hour, one midnight at a time
+    ///
+    /// Returns the log of the last day that finished on the way, if any.
+    pub fn wait_until(&mut self, day: u32, hour: f32) -> Option<DayLog> {
+        let mut finished = None;
+        while self.day < day || (self.day == day && self.time_of_day < hour) {
+            let step = if self.day < day {
+                24.0 - self.time_of_day
+            } else {
+                hour - self.time_of_day
+            };
+            finished = self.advance_time(step, TimeCategory::Resting).or(finished);
+        }
+        finished
+    }
+
+    /// Skip ahead to a booked interview and take it off the schedule
+    pub fn attend_interview(&mut self, job_id: u32) -> Result<(ScheduledInterview, Option<DayLog>), String> {
+        let slot = self
+            .player
+            .take_scheduled_interview(job_id)
+            .ok_or_else(|| "You don't have an interview booked for this job".to_string())?;
+        if slot.is_missed(self.day, self.time_of_day) {
+            self.player.start_interview_cooldown(job_id);
+            return Err(format!("You missed your {} interview at {}", slot.company, slot.slot_label()));
+        }
+        let finished = self.wait_until(slot.day, slot.hour);
+        Ok((slot, finished))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies::get_all_companies;
+    use crate::player::{next_business_day, INTERVIEW_HOUR};
+
+    #[test]
+    fn test_apply_books_next_business_day_and_attending_jumps_there() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut state = GameState::with_seed("Test", 1);
+        state.day = 5;
+        state.player.day = 5;
+        state.time_of_day = 15.0;
+
+        let slot = state.player.schedule_interview(&job, state.day).unwrap();
+        assert_eq!(slot.day, 8);
+        assert_eq!(slot.day, next_business_day(5));
+
+        let (attended, finished) = state.attend_interview(job.id).unwrap();
+
+        assert_eq!(attended, slot);
+        assert!(finished.is_some());
+        assert_eq!(state.day, 8);
+        assert_eq!(state.time_of_day, INTERVIEW_HOUR);
+        assert!(state.player.scheduled_interview(job.id).is_none());
+    }
+
+    #[test]
+    fn test_sleeping_past_slot_forfeits_it() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut state = GameState::with_seed("Test", 1);
+        state.time_of_day = 20.0;
+        state.player.schedule_interview(&job, state.day).unwrap();
+
+        state.wait_until(2, INTERVIEW_HOUR + 2.0);
+
+        assert!(state.attend_interview(job.id).is_err());
+        assert!(state.player.cooldown_remaining(job.id).is_some());
+    }
+}
@@ -0,0 +1,127 @@
+//! Shutdown
+//!
+//! Last writes before the game exits: an autosave of the game in progress
+//! and, for owners that persist it, the LLM response cache. Files go
+//! through a `FileSink` so tests can capture them instead of touching the
+//! disk, and `ShutdownHook` makes sure the flush happens only once however
+//! the exit was triggered.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use super::save::{autosave_path, encode_save, SaveFormat};
+use super::state::GameState;
+use crate::engine::ResponseCache;
+
+/// Destination for the files written on shutdown
+pub trait FileSink {
+    fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()>;
+}
+
+/// Writes to the filesystem, creating parent directories as needed
+#[derive(Debug, Default)]
+pub struct DiskSink;
+
+impl FileSink for DiskSink {
+    fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        fs::write(path, contents).with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+/// What to write on the way out
+pub struct ShutdownFlush<'a> {
+    /// Game to autosave; `None` before a game has started
+    pub state: Option<&'a GameState>,
+    pub save_dir: &'a Path,
+    pub format: SaveFormat,
+    /// Response cache and the file it persists to, when persistence is on
+    pub cache: Option<(&'a ResponseCache, &'a Path)>,
+}
+
+/// Runs the shutdown flush exactly once
+#[derive(Debug, Default)]
+pub struct ShutdownHook {
+    done: bool,
+}
+
+impl ShutdownHook {
+    /// Write the autosave and cache, returning the files written
+    ///
+    /// Calls after the first write nothing and return no files.
+    pub fn run(&mut self, flush: &ShutdownFlush, sink: &mut impl FileSink) -> Result<Vec<PathBuf>> {
+        if std::mem::replace(&mut self.done, true) {
+            return Ok(Vec::new());
+        }
+        let mut written = Vec::new();
+        if let Some(state) = flush.state {
+            let path = autosave_path(flush.save_dir, flush.format);
+            sink.write_file(&path, &encode_save(state, flush.format)?)?;
+            written.push(path);
+        }
+        if let Some((cache, path)) = flush.cache {
+            sink.write_file(path, &cache.to_json()?)?;
+            written.push(path.to_path_buf());
+        }
+        Ok(written)
+    }
+
+    pub fn has_run(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MemorySink {
+        files: HashMap<PathBuf, Vec<u8>>,
+        writes: usize,
+    }
+
+    impl FileSink for MemorySink {
+        fn write_file(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+            self.writes += 1;
+            self.files.insert(path.to_path_buf(), contents.to_vec());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_flush_writes_save_and_cache_exactly_once() {
+        let mut state = GameState::with_seed("Ada", 3);
+        state.day = 6;
+        let mut cache = ResponseCache::new();
+        cache.set("npc|hi".to_string(), "Hello!".to_string());
+        let cache_path = Path::new("cache/responses.json");
+        let flush = ShutdownFlush {
+            state: Some(&state),
+            save_dir: Path::new("saves"),
+            format: SaveFormat::Json,
+            cache: Some((&cache, cache_path)),
+        };
+        let mut hook = ShutdownHook::default();
+        let mut sink = MemorySink::default();
+
+        let written = hook.run(&flush, &mut sink).unwrap();
+
+        let save_path = autosave_path(Path::new("saves"), SaveFormat::Json);
+        assert_eq!(written, vec![save_path.clone(), cache_path.to_path_buf()]);
+        let save = String::from_utf8(sink.files[&save_path].clone()).unwrap();
+        assert!(save.lines().next().unwrap().contains("\"player_name\":\"Ada\""));
+        let mut restored = ResponseCache::new();
+        restored.restore_json(&sink.files[cache_path]).unwrap();
+        assert_eq!(restored.get("npc|hi"), Some("Hello!".to_string()));
+
+        assert!(hook.has_run());
+        assert!(hook.run(&flush, &mut sink).unwrap().is_empty());
+        assert_eq!(sink.writes, 2);
+    }
+}
@@ -0,0 +1,269 @@
+//! Headless Game Simulation
+//!
+//! Drives the simulation core (studying, interviewing, resting) without a
+//! window, for benchmarks and long-running stress tests. Every random
+//! choice comes from the game seed, so a run is reproducible.
+
+use rand::Rng;
+
+use super::action::ActionResult;
+use super::day_log::{DayLog, TimeCategory};
+use super::rng::GameRng;
+use super::interview::InterviewOutcome;
+use super::state::GameState;
+use crate::companies::get_all_companies;
+use crate::engine::{GameConfig, InterviewConfig};
+use crate::interview::adaptive::AdaptiveQuiz;
+use crate::interview::questions::InterviewQuestionDb;
+use crate::interview::quiz::{Confidence, QuizAnswer};
+use crate::interview::session::RoundOutcome;
+use crate::interview::Interview;
+use crate::jobs::{Company, CompanyTier, Job};
+use crate::player::{INTERVIEW_LENGTH_HOURS, STUDY_SESSION_HOURS};
+
+/// Match score at which the simulated player goes for an interview
+pub const SIM_INTERVIEW_THRESHOLD: f32 = 0.7;
+/// Hour the simulated player gets up
+pub const SIM_WAKE_HOUR: f32 = 8.0;
+/// Upper bound on study sessions attempted per simulated day
+const MAX_SESSIONS_PER_DAY: u32 = 8;
+
+/// A game driven by simple scripted decisions instead of player input
+pub struct GameSim {
+    pub state: GameState,
+    /// Marking and passing rules, the same ones the window judges interviews by
+    pub interview_config: InterviewConfig,
+    questions: InterviewQuestionDb,
+    companies: Vec<Company>,
+    rng: GameRng,
+}
+
+impl GameSim {
+    pub fn new(seed: u64) -> Self {
+        let mut state = GameState::with_seed("Sim", seed);
+        state.time_of_day = SIM_WAKE_HOUR;
+        Self {
+            state,
+            interview_config: GameConfig::load().map(|config| config.interview).unwrap_or_default(),
+            questions: InterviewQuestionDb::load(),
+            companies: get_all_companies(),
+            rng: GameRng::new(seed),
+        }
+    }
+
+    /// One study session; advances the clock only if it succeeded
+    pub fn study(&mut self, skill_name: &str) -> Result<String, String> {
+        self.study_for(skill_name, STUDY_SESSION_HOURS)
+    }
+
+    /// Study for the given hours; advances the clock only if it succeeded
+    pub fn study_for(&mut self, skill_name: &str, hours: u32) -> Result<String, String> {
+        let time_of_day = self.state.time_of_day;
+        let message = self
+            .state
+            .track_progress(|player| player.study(skill_name, hours, time_of_day))?;
+        self.state.advance_time(hours as f32, TimeCategory::Studying);
+        Ok(message)
+    }
+
+    /// Any open position by id
+    pub fn find_job(&self, job_id: u32) -> Option<&Job> {
+        self.companies.iter().flat_map(|c| &c.open_positions).find(|job| job.id == job_id)
+    }
+
+    /// Sleep until the next morning; returns the log of the day that ended
+    pub fn sleep(&mut self) -> Option<DayLog> {
+        let hours = 24.0 - self.state.time_of_day + SIM_WAKE_HOUR;
+        self.state.advance_time(hours, TimeCategory::Resting)
+    }
+
+    /// Best job the player can apply to right now, with its match score
+    pub fn best_job(&self) -> Option<(Job, f32)> {
+        let player = &self.state.player;
+        let level = player.career_level();
+        self.companies
+            .iter()
+            .filter(|c| c.tier.min_career_level() <= level)
+            .flat_map(|c| &c.open_positions)
+            .filter(|job| player.cooldown_remaining(job.id).is_none())
+            .filter(|job| job.check_eligibility(player).is_ok())
+            .map(|job| {
+                let score = job.calculate_match_with_trends(&player.skills, Some(&self.state.trends));
+                (job.clone(), score)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Play through the job's interview rounds; returns whether it passed
+    ///
+    /// Rounds, marking and the passing policy follow `interview_config`,
+    /// as in the window, and a failed round is retried while the session
+    /// allows it. Each answer is correct with a probability that grows
+    /// with the player's match for the job, starting from a 1-in-4 guess.
+    /// A pass leaves an offer open, to be taken with `accept_offer`.
+    pub fn interview(&mut self, job: &Job) -> bool {
+        let tier = self.tier_of(job);
+        let attempt = self.state.player.record_interview_attempt(job.id);
+        let mut rng = GameRng::for_job_attempt(self.state.seed, job.id, attempt);
+        let chance = 0.25 + 0.75 * job.calculate_match(&self.state.player.skills);
+        let company = self.companies.iter().find(|c| c.name == job.company);
+        let mut session = self.interview_config.new_session(Interview::quiz_rounds(job, tier));
+
+        let outcome = loop {
+            let Some(round) = session.current_round() else { break None };
+            let mut quiz = AdaptiveQuiz::for_round(job, round, company, &self.questions);
+            let mut questions = Vec::new();
+            let mut answers = Vec::new();
+            while let Some(question) = quiz.next_question(&mut rng) {
+                let correct = rng.gen::<f32>() < chance;
+                quiz.record_answer(correct);
+                questions.push(question);
+                answers.push(QuizAnswer { correct, confidence: Confidence::Sure });
+            }
+
+            let player = &self.state.player;
+            let outcome = InterviewOutcome::from_quiz(
+                job.clone(), tier, player, &questions, &answers, self.interview_config.marking_penalty(),
+            );
+            match session.record(outcome.round_result(round)) {
+                RoundOutcome::Hired => break Some(outcome.settle(true, player)),
+                RoundOutcome::Passed | RoundOutcome::Scored => session.start_next_round(),
+                RoundOutcome::Failed { retry_available: true } => {
+                    session.retry();
+                }
+                RoundOutcome::Failed { retry_available: false } => break Some(outcome.settle(false, player)),
+            }
+        };
+
+        self.state.advance_time(INTERVIEW_LENGTH_HOURS as f32, TimeCategory::Interviewing);
+        let Some(outcome) = outcome else { return false };
+        self.state.apply_interview_outcome(&outcome);
+        outcome.passed
+    }
+
+    /// Take the job from a pending offer
+    pub fn accept_offer(&mut self, job_id: u32) -> ActionResult {
+        self.state.accept_offer(job_id)
+    }
+
+    /// Turn down a pending offer
+    pub fn decline_offer(&mut self, job_id: u32) -> ActionResult {
+        self.state.decline_offer(job_id)
+    }
+
+    /// Study toward the best job, interview if it's a good match and take
+    /// the offer, then sleep
+    pub fn run_day(&mut self) -> Option<DayLog> {
+        let target = self.best_job();
+        if !self.state.player.employed {
+            if let Some((job, score)) = &target {
+                if *score >= SIM_INTERVIEW_THRESHOLD && self.interview(job) {
+                    self.accept_offer(job.id);
+                }
+            }
+        }
+
+        for _ in 0..MAX_SESSIONS_PER_DAY {
+            let Some(skill) = self.next_skill_to_study(target.as_ref().map(|(job, _)| job)) else {
+                break;
+            };
+            if self.study(&skill).is_err() {
+                break;
+            }
+        }
+        self.sleep()
+    }
+
+    /// Widest gap for the target job, otherwise a random unmastered skill
+    fn next_skill_to_study(&mut self, target: Option<&Job>) -> Option<String> {
+        let player = &self.state.player;
+        if let Some(gap) = target.and_then(|job| job.skill_gaps(&player.skills).into_iter().next()) {
+            return Some(gap.skill_name);
+        }
+        let mut open: Vec<&String> = player
+            .skills
+            .iter()
+            .filter(|(_, skill)| !skill.is_maxed())
+            .map(|(name, _)| name)
+            .collect();
+        open.sort();
+        if open.is_empty() {
+            return None;
+        }
+        let idx = self.rng.gen_range(0..open.len());
+        Some(open[idx].clone())
+    }
+
+    fn tier_of(&self, job: &Job) -> CompanyTier {
+        self.companies
+            .iter()
+            .find(|c| c.name == job.company)
+            .map(|c| c.tier)
+            .unwrap_or(CompanyTier::MidSize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interview::session::PassingPolicy;
+    use crate::skills::Proficiency;
+
+    #[test]
+    fn test_sim_advances_days_and_learns() {
+        let mut sim = GameSim::new(7);
+        for _ in 0..5 {
+            assert!(sim.run_day().is_some());
+        }
+        assert_eq!(sim.state.day, 6);
+        let progressed = sim.state.player.skills.values()
+            .any(|s| s.proficiency != Proficiency::None || s.experience_points > 0);
+        assert!(progressed);
+    }
+
+    #[test]
+    fn test_offers_can_be_accepted_or_declined() {
+        let companies = get_all_companies();
+        let (first, second) = (companies[0].open_positions[0].clone(), companies[1].open_positions[0].clone());
+        let mut sim = GameSim::new(7);
+        sim.state.player.receive_offer(&first, 90_000);
+        sim.state.player.receive_offer(&second, 120_000);
+
+        assert!(sim.decline_offer(first.id).succeeded());
+        assert!(!sim.accept_offer(first.id).succeeded());
+        assert!(sim.accept_offer(second.id).succeeded());
+        assert!(sim.state.player.employed);
+        assert_eq!(sim.state.player.current_salary, 120_000);
+    }
+
+    #[test]
+    fn test_interview_follows_the_configured_passing_policy() {
+        let job = get_all_companies()
+            .into_iter()
+            .flat_map(|c| c.open_positions)
+            .find(|job| Interview::quiz_rounds(job, crate::companies::tier_of(&job.company).unwrap()).len() > 1)
+            .expect("a job with more than one round");
+
+        let mut strict = GameSim::new(7);
+        assert!(!strict.interview(&job));
+        assert!(strict.state.player.cooldown_remaining(job.id).is_some());
+
+        let mut lenient = GameSim::new(7);
+        lenient.interview_config.passing_policy = PassingPolicy::Average;
+        lenient.interview_config.average_threshold = 0.0;
+        assert!(lenient.interview(&job));
+        assert!(lenient.state.player.pending_offer(job.id).is_some());
+    }
+
+    #[test]
+    fn test_sim_is_deterministic() {
+        let run = |seed| {
+            let mut sim = GameSim::new(seed);
+            for _ in 0..20 {
+                sim.run_day();
+            }
+            (sim.state.player.money, sim.state.player.employed, sim.state.player.career_level())
+        };
+        assert_eq!(run(3), run(3));
+    }
+}
@@ -1,4 +1,9 @@
-use crate::player::Player;
+use std::collections::HashMap;
+
+use super::day_log::{DayLog, TimeCategory};
+use super::money_history::MoneyHistory;
+use super::trends::trends_for_day;
+use crate::player::{Offer, Player, ScheduledInterview};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GameScreen {
@@ -10,35 +15,116 @@ pub enum GameScreen {
     JobBoard,
     Interview,
     Study,
+    SaveSlots,
+    Offers,
+    EventLog,
 }
 
 #[derive(Debug, Clone)]
 pub struct GameState {
     pub screen: GameScreen,
+    /// Screens to return to when backing out of the current one
+    pub screen_stack: Vec<GameScreen>,
     pub player: Player,
     pub day: u32,
     pub time_of_day: f32,
     pub paused: bool,
+    /// Seed for reproducible gameplay randomness
+    pub seed: u64,
+    /// Where today's hours went, cleared at midnight
+    pub day_log: DayLog,
+    /// Skill weight multipliers for skills currently in high demand
+    pub trends: HashMap<String, f32>,
+    /// Offers that lapsed undecided, waiting to be reported
+    pub expired_offers: Vec<Offer>,
+    /// Booked interviews whose slot passed, waiting to be reported
+    pub missed_interviews: Vec<ScheduledInterview>,
+    /// Notable events as (day, description), oldest first
+    pub event_log: Vec<(u32, String)>,
+    /// Money at the start of each recent day, for the history chart
+    pub money_history: MoneyHistory,
 }
 
 impl GameState {
     pub fn new(player_name: &str) -> Self {
+        Self::with_seed(player_name, rand::random())
+    }
+
+    pub fn with_seed(player_name: &str, seed: u64) -> Self {
         Self {
             screen: GameScreen::Title,
+            screen_stack: Vec::new(),
             player: Player::new(player_name),
             day: 1,
             time_of_day: 8.0,
             paused: false,
+            seed,
+            day_log: DayLog::new(),
+            trends: trends_for_day(seed, 1),
+            expired_offers: Vec::new(),
+            missed_interviews: Vec::new(),
+            event_log: Vec::new(),
+            money_history: MoneyHistory::new(),
         }
     }
 
-    pub fn advance_time(&mut self, hours: f32) {
+    /// Open a screen on top of the current one
+    pub fn push_screen(&mut self, screen: GameScreen) {
+        self.screen_stack.push(self.screen);
+        self.screen = screen;
+    }
+
+    /// Return to the previous screen, falling back to the world
+    pub fn pop_screen(&mut self) -> GameScreen {
+        self.screen = self.screen_stack.pop().unwrap_or(GameScreen::World);
+        self.screen
+    }
+
+    /// Swap the current screen without changing what's underneath it
+    pub fn replace_screen(&mut self, screen: GameScreen) {
+        self.screen = screen;
+    }
+
+    /// Jump to a screen and forget the navigation history
+    pub fn reset_screen(&mut self, screen: GameScreen) {
+        self.screen_stack.clear();
+        self.screen = screen;
+    }
+
+    /// Spend hours on an activity. Returns the finished day's log if midnight passed.
+    pub fn advance_time(&mut self, hours: f32, category: TimeCategory) -> Option<DayLog> {
+        let before_midnight = hours.min(24.0 - self.time_of_day);
+        self.day_log.log(category, before_midnight);
+        self.player.tick_buffs(hours);
+        self.player.tick_scheduled(hours);
         self.time_of_day += hours;
+        let mut finished = None;
         if self.time_of_day >= 24.0 {
             self.time_of_day -= 24.0;
             self.day += 1;
+            let expired = self.track_progress(Player::advance_day);
+            for offer in &expired {
+                self.log_event(format!("Your offer from {} expired", offer.job.company));
+            }
+            self.expired_offers.extend(expired);
+            self.record_money();
             self.player.rest();
+            self.trends = trends_for_day(self.seed, self.day);
+            finished = Some(std::mem::take(&mut self.day_log));
+            if self.time_of_day > 0.0 {
+                self.day_log.log(category, self.time_of_day);
+            }
+            if let Some(shift) = self.player.work_part_time_shift() {
+                self.day_log.log(TimeCategory::Working, shift);
+                self.time_of_day += shift;
+            }
+        }
+        let missed = self.player.forfeit_missed_interviews(self.day, self.time_of_day);
+        for slot in &missed {
+            self.log_event(format!("Missed the {} interview at {}", slot.title, slot.company));
         }
+        self.missed_interviews.extend(missed);
+        finished
     }
 
     pub fn time_string(&self) -> String {
@@ -47,7 +133,153 @@ impl GameState {
         format!("{:02}:{:02}", hour, minute)
     }
 
+    /// Energy ceiling the player can use at the current time of day
+    pub fn effective_energy_cap(&self) -> u32 {
+        self.player.max_effective_energy(self.time_of_day)
+    }
+
+    /// Whether it's late enough that the player's energy is capped
+    pub fn is_tired(&self) -> bool {
+        self.effective_energy_cap() < self.player.max_energy
+    }
+
+    /// The skill that's currently trending, if any
+    pub fn hot_skill(&self) -> Option<&str> {
+        self.trends
+            .iter()
+            .filter(|(_, &multiplier)| multiplier > 1.0)
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(skill, _)| skill.as_str())
+    }
+
     pub fn is_night(&self) -> bool {
         self.time_of_day < 6.0 || self.time_of_day >= 20.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::{EnergyCrash, PartTimeJob, NIGHT_ENERGY_CAP};
+
+    #[test]
+    fn test_screen_stack_returns_to_previous_screen() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.reset_screen(GameScreen::World);
+        state.push_screen(GameScreen::Dialog);
+        state.push_screen(GameScreen::JobBoard);
+        state.push_screen(GameScreen::Interview);
+        state.replace_screen(GameScreen::Dialog);
+
+        assert_eq!(state.pop_screen(), GameScreen::JobBoard);
+        assert_eq!(state.pop_screen(), GameScreen::Dialog);
+        assert_eq!(state.pop_screen(), GameScreen::World);
+    }
+
+    #[test]
+    fn test_pop_empty_screen_stack_goes_to_world() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.push_screen(GameScreen::Menu);
+        state.reset_screen(GameScreen::Skills);
+        assert_eq!(state.pop_screen(), GameScreen::World);
+        assert!(state.screen_stack.is_empty());
+    }
+
+    #[test]
+    fn test_lapsed_offer_is_logged_and_waits_to_be_reported() {
+        let job = crate::companies::get_all_companies()[0].open_positions[0].clone();
+        let mut state = GameState::with_seed("Test", 1);
+        state.player.receive_offer(&job, 120000);
+        for _ in 0..4 {
+            state.advance_time(24.0, TimeCategory::Resting);
+        }
+        assert_eq!(state.day, 5);
+        assert!(state.player.pending_offers.is_empty());
+        assert_eq!(state.expired_offers.len(), 1);
+        assert_eq!(state.event_log.last().unwrap().1, format!("Your offer from {} expired", job.company));
+    }
+
+    #[test]
+    fn test_day_log_totals_before_midnight() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.time_of_day = 8.0;
+        assert!(state.advance_time(2.0, TimeCategory::Studying).is_none());
+        assert!(state.advance_time(8.0, TimeCategory::Resting).is_none());
+        assert_eq!(state.day_log.hours(TimeCategory::Studying), 2.0);
+        assert_eq!(state.day_log.hours(TimeCategory::Resting), 8.0);
+        assert_eq!(state.day_log.total(), 10.0);
+    }
+
+    #[test]
+    fn test_day_log_splits_at_midnight() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.time_of_day = 20.0;
+        let finished = state.advance_time(8.0, TimeCategory::Resting).unwrap();
+        assert_eq!(finished.hours(TimeCategory::Resting), 4.0);
+        assert_eq!(state.day_log.hours(TimeCategory::Resting), 4.0);
+        assert_eq!(state.day, 2);
+    }
+
+    #[test]
+    fn test_part_time_shift_pays_and_tires_on_workdays_only() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.player.money = 0;
+        state.player.take_part_time_job(PartTimeJob::DataLabeler).unwrap();
+        let job = PartTimeJob::DataLabeler;
+        let sleep = |state: &mut GameState| state.advance_time(24.0 - state.time_of_day + 8.0, TimeCategory::Resting);
+
+        // Day 1 is a Monday; sleeping through to Tuesday works a shift on waking
+        sleep(&mut state);
+        assert_eq!(state.day, 2);
+        assert_eq!(state.player.money, job.daily_pay());
+        assert_eq!(state.player.energy, state.player.max_energy - job.energy_cost());
+        assert_eq!(state.time_of_day, 8.0 + job.hours());
+        assert_eq!(state.day_log.hours(TimeCategory::Working), job.hours());
+        assert_eq!(state.player.experience_days, 0);
+
+        // Saturday and Sunday are off
+        for _ in 2..6 {
+            sleep(&mut state);
+        }
+        assert_eq!(state.day, 6);
+        let paid = state.player.money;
+        assert_eq!(paid, 4 * job.daily_pay());
+        sleep(&mut state);
+        assert_eq!(state.player.money, paid);
+        assert_eq!(state.player.energy, state.player.max_energy);
+        assert_eq!(state.player.experience_days, 0);
+    }
+
+    #[test]
+    fn test_energy_crash_hits_at_scheduled_hour() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.time_of_day = 8.0;
+        state.player.energy = 90;
+        state.player.schedule_crash(EnergyCrash { energy: 35, hours: 4.0 });
+
+        state.advance_time(2.0, TimeCategory::Studying);
+        state.advance_time(1.0, TimeCategory::Studying);
+        assert_eq!(state.player.energy, 90);
+
+        state.advance_time(1.0, TimeCategory::Studying);
+        assert_eq!(state.time_of_day, 12.0);
+        assert_eq!(state.player.energy, 55);
+        assert!(state.player.next_crash().is_none());
+    }
+
+    #[test]
+    fn test_effective_energy_cap_reduced_at_night() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.time_of_day = 3.0;
+        assert_eq!(state.effective_energy_cap(), NIGHT_ENERGY_CAP);
+        assert!(state.is_tired());
+    }
+
+    #[test]
+    fn test_effective_energy_cap_full_during_day() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.time_of_day = 14.0;
+        assert_eq!(state.effective_energy_cap(), state.player.max_energy);
+        assert!(!state.is_tired());
+    }
+}
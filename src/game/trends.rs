@@ -0,0 +1,42 @@
+//! Industry Trends
+//!
+//! Every few days one skill becomes "hot" (e.g. a RAG boom) and its
+//! requirements count for more when matching the player against jobs.
+
+use std::collections::HashMap;
+
+use rand::seq::SliceRandom;
+
+use super::rng::GameRng;
+use crate::skills::get_all_skills;
+
+/// Days a trend lasts before the next one takes over
+pub const TREND_PERIOD_DAYS: u32 = 7;
+/// Weight multiplier for requirements on the hot skill
+pub const TREND_MULTIPLIER: f32 = 1.5;
+
+/// Skill weight multipliers active on the given day
+///
+/// The hot skill is fixed for each period, so the same seed and day
+/// always give the same trend.
+pub fn trends_for_day(seed: u64, day: u32) -> HashMap<String, f32> {
+    let period = day.saturating_sub(1) / TREND_PERIOD_DAYS;
+    let mut rng = GameRng::for_trend_period(seed, period);
+    let skills = get_all_skills();
+    skills
+        .choose(&mut rng)
+        .map(|skill| HashMap::from([(skill.name.clone(), TREND_MULTIPLIER)]))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trend_stable_within_period() {
+        let first = trends_for_day(42, 1);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first, trends_for_day(42, TREND_PERIOD_DAYS));
+    }
+}
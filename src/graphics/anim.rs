@@ -0,0 +1,64 @@
+/// Pausable clock that drives UI animations independently of frame rate
+#[derive(Debug, Clone, Default)]
+pub struct AnimClock {
+    elapsed: f32,
+    paused: bool,
+}
+
+impl AnimClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        if !self.paused {
+            self.elapsed += dt;
+        }
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Seconds of unpaused time since the clock started
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Alternates between on and off `rate` times per second
+    pub fn blink(&self, rate: f32) -> bool {
+        ((self.elapsed * rate) as u32).is_multiple_of(2)
+    }
+
+    /// Smooth 0.0..=1.0 oscillation with the given period in seconds
+    pub fn pulse(&self, period: f32) -> f32 {
+        ((self.elapsed / period * std::f32::consts::TAU).sin() + 1.0) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_advances_when_running() {
+        let mut clock = AnimClock::new();
+        clock.tick(0.5);
+        clock.tick(0.25);
+        assert_eq!(clock.elapsed(), 0.75);
+    }
+
+    #[test]
+    fn test_clock_does_not_advance_when_paused() {
+        let mut clock = AnimClock::new();
+        clock.tick(1.0);
+        clock.set_paused(true);
+        clock.tick(1.0);
+        assert_eq!(clock.elapsed(), 1.0);
+        assert!(!clock.blink(1.0));
+    }
+}
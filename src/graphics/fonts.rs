@@ -4,10 +4,28 @@ use std::sync::OnceLock;
 static FONT: OnceLock<Option<Font>> = OnceLock::new();
 static mut USE_CUSTOM_FONT: bool = true;
 
-pub fn init_fonts() {
+/// Load the bundled pixel font; if it fails, text uses macroquad's built-in font
+///
+/// # Errors
+/// Returns why the font failed to load, for the caller to report
+pub fn init_fonts() -> Result<(), String> {
     let font_data = include_bytes!("../../assets/PixelifySans-Regular.ttf");
-    let font = load_ttf_font_from_bytes(font_data).ok();
+    install_font(load_ttf_font_from_bytes(font_data))
+}
+
+/// Store the outcome of loading the custom font, passing on the failure
+fn install_font<E: std::fmt::Display>(loaded: Result<Font, E>) -> Result<(), String> {
+    let (font, result) = match loaded {
+        Ok(font) => (Some(font), Ok(())),
+        Err(e) => (None, Err(format!("custom font failed to load, falling back to the default font: {}", e))),
+    };
     FONT.set(font).ok();
+    result
+}
+
+/// Whether the custom font loaded; false before `init_fonts` or after a failure
+pub fn fonts_loaded() -> bool {
+    FONT.get().is_some_and(|font| font.is_some())
 }
 
 pub fn use_custom_font(enabled: bool) {
@@ -29,6 +47,8 @@ fn get_font() -> Option<&'static Font> {
     }
 }
 
+/// Draw pixel-aligned text, in macroquad's built-in font if the custom one
+/// is missing or disabled
 pub fn draw_text_crisp(text: &str, x: f32, y: f32, font_size: f32, color: Color) {
     let x = x.round();
     let y = y.round();
@@ -49,6 +69,13 @@ pub fn draw_text_crisp(text: &str, x: f32, y: f32, font_size: f32, color: Color)
     );
 }
 
+/// Width of text as `draw_text_crisp` would draw it
+pub fn measure_text_crisp(text: &str, font_size: f32) -> f32 {
+    let scale = 2.0;
+    let size = (font_size * scale) as u16;
+    measure_text(text, get_font(), size, 1.0 / scale).width
+}
+
 pub fn draw_text_crisp_centered(text: &str, x: f32, y: f32, font_size: f32, color: Color) {
     let scale = 2.0;
     let size = (font_size * scale) as u16;
@@ -70,3 +97,16 @@ pub fn draw_text_crisp_centered(text: &str, x: f32, y: f32, font_size: f32, colo
         },
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_load_is_reported() {
+        let result = install_font(Err::<Font, _>("forced failure"));
+        assert!(result.unwrap_err().contains("forced failure"));
+        assert!(!fonts_loaded());
+        assert!(get_font().is_none());
+    }
+}
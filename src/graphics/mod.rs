@@ -1,5 +1,7 @@
+mod anim;
 mod fonts;
 mod sprites;
 
+pub use anim::*;
 pub use fonts::*;
 pub use sprites::*;
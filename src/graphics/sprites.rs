@@ -1,5 +1,5 @@
 use super::draw_text_crisp;
-use crate::world::Direction;
+use crate::world::{BuildingType, Direction};
 use crate::world::TILE_SIZE;
 use macroquad::prelude::*;
 
@@ -71,33 +71,44 @@ pub fn draw_building(x: f32, y: f32, width: u32, height: u32, name: &str, color:
     draw_text_crisp(name, x + 5.0, y + h + 15.0, 16.0, WHITE);
 }
 
+/// Wall color used for each kind of building
+pub fn building_color(building_type: BuildingType) -> Color {
+    match building_type {
+        BuildingType::Apartment => GRAY,
+        BuildingType::Library => Color::from_rgba(139, 90, 43, 255),
+        BuildingType::University => Color::from_rgba(120, 40, 60, 255),
+        BuildingType::CoffeeShop => BROWN,
+        BuildingType::Company { tier: 0 } => GREEN,
+        BuildingType::Company { tier: 1 } => BLUE,
+        BuildingType::Company { tier: 2 } => PURPLE,
+        BuildingType::Company { tier: 3 } => RED,
+        BuildingType::Company { .. } => GRAY,
+        BuildingType::JobCenter => Color::from_rgba(150, 150, 200, 255),
+        BuildingType::Park => GREEN,
+    }
+}
+
 pub fn draw_library(x: f32, y: f32) {
-    draw_building(x, y, 4, 3, "Library", Color::from_rgba(139, 90, 43, 255));
+    draw_building(x, y, 4, 3, "Library", building_color(BuildingType::Library));
 }
 
 pub fn draw_company(x: f32, y: f32, name: &str, tier: u8) {
-    let color = match tier {
-        0 => GREEN,
-        1 => BLUE,
-        2 => PURPLE,
-        3 => RED,
-        _ => GRAY,
-    };
+    let color = building_color(BuildingType::Company { tier });
     let width = 3 + tier as u32;
     let height = 2 + tier as u32;
     draw_building(x, y, width, height, name, color);
 }
 
 pub fn draw_apartment(x: f32, y: f32) {
-    draw_building(x, y, 3, 2, "Apartment", GRAY);
+    draw_building(x, y, 3, 2, "Apartment", building_color(BuildingType::Apartment));
 }
 
 pub fn draw_coffee_shop(x: f32, y: f32) {
-    draw_building(x, y, 3, 2, "Coffee", BROWN);
+    draw_building(x, y, 3, 2, "Coffee", building_color(BuildingType::CoffeeShop));
 }
 
 pub fn draw_park(x: f32, y: f32, width: u32, height: u32) {
     let w = width as f32 * TILE_SIZE;
     let h = height as f32 * TILE_SIZE;
-    draw_rectangle(x, y, w, h, GREEN);
+    draw_rectangle(x, y, w, h, building_color(BuildingType::Park));
 }
@@ -0,0 +1,334 @@
+//! Adaptive Interview
+//!
+//! Picks interview questions one at a time from `InterviewQuestionDb`,
+//! steering toward harder questions after correct answers and easier
+//! ones after mistakes. Among equally suitable questions, those on the
+//! skills the hiring company emphasizes come up more often.
+
+use rand::seq::SliceRandom;
+
+use crate::game::GameRng;
+use crate::jobs::{Company, Job};
+use super::questions::{InterviewQuestion, InterviewQuestionDb};
+use super::quiz::{fallback_question, QuizQuestion, MAX_QUIZ_QUESTIONS};
+use super::InterviewRound;
+
+/// Lowest difficulty a question can have
+pub const MIN_QUESTION_DIFFICULTY: f32 = 1.0;
+/// Highest difficulty a question can have
+pub const MAX_QUESTION_DIFFICULTY: f32 = 4.0;
+/// How far the difficulty target moves after each answer
+pub const DIFFICULTY_STEP: f32 = 0.75;
+
+impl From<&InterviewQuestion> for QuizQuestion {
+    fn from(q: &InterviewQuestion) -> Self {
+        Self {
+            question: q.question.clone(),
+            options: q.options.clone(),
+            correct_idx: q.correct_idx,
+            difficulty: q.difficulty,
+            explanation: q.explanation.clone(),
+            related_skill: None,
+        }
+    }
+}
+
+/// Question selector whose difficulty target follows the player's answers
+#[derive(Debug, Clone)]
+pub struct AdaptiveQuiz {
+    pool: Vec<QuizQuestion>,
+    /// Relative chance of each pool question when several are equally close
+    weights: Vec<f32>,
+    asked: Vec<bool>,
+    target: f32,
+    length: usize,
+}
+
+impl AdaptiveQuiz {
+    pub fn new(pool: Vec<QuizQuestion>, start_difficulty: f32, length: usize) -> Self {
+        let length = length.min(pool.len());
+        Self {
+            weights: vec![1.0; pool.len()],
+            asked: vec![false; pool.len()],
+            pool,
+            target: start_difficulty.clamp(MIN_QUESTION_DIFFICULTY, MAX_QUESTION_DIFFICULTY),
+            length,
+        }
+    }
+
+    /// Build the question pool for a job from its mandatory skills
+    ///
+    /// The interview starts at the job's difficulty and asks one question
+    /// per skill, up to `MAX_QUIZ_QUESTIONS`. The hiring company's
+    /// `emphasis` is added to each skill's requirement weight, and
+    /// emphasized skills the job doesn't list join the pool too.
+    pub fn for_job(job: &Job, company: Option<&Company>, db: &InterviewQuestionDb) -> Self {
+        let extra = |skill: &str| {
+            company
+                .and_then(|c| c.emphasis.get(skill))
+                .copied()
+                .unwrap_or(0.0)
+                .max(0.0)
+        };
+        let mut skills: Vec<(&str, f32)> = job
+            .requirements
+            .iter()
+            .filter(|r| r.mandatory)
+            .map(|r| (r.skill_name.as_str(), r.weight.max(0.0) + extra(&r.skill_name)))
+            .collect();
+        let mut emphasized: Vec<&str> = company
+            .map(|c| c.emphasis.keys().map(String::as_str).filter(|skill| extra(skill) > 0.0).collect())
+            .unwrap_or_default();
+        emphasized.sort();
+        for skill in emphasized {
+            if !skills.iter().any(|(name, _)| *name == skill) {
+                skills.push((skill, extra(skill)));
+            }
+        }
+
+        let mut pool: Vec<QuizQuestion> = Vec::new();
+        let mut weights = Vec::new();
+        for (skill, weight) in &skills {
+            for q in db.get_questions(skill) {
+                if !pool.iter().any(|p| p.question == q.question) {
+                    pool.push(QuizQuestion { related_skill: Some(skill.to_string()), ..q.into() });
+                    weights.push(*weight);
+                }
+            }
+        }
+        if pool.is_empty() {
+            pool.push(fallback_question());
+            weights.push(1.0);
+        }
+
+        let length = skills.len().clamp(1, MAX_QUIZ_QUESTIONS);
+        Self { weights, ..Self::new(pool, job.difficulty as f32, length) }
+    }
+
+    /// Build the quiz for one round of `Interview::quiz_rounds`
+    ///
+    /// A round without fixed questions is the adaptive technical quiz from
+    /// `for_job`. Any other round asks its own questions, with their
+    /// options looked up in `db`.
+    pub fn for_round(job: &Job, round: &InterviewRound, company: Option<&Company>, db: &InterviewQuestionDb) -> Self {
+        if round.questions.is_empty() {
+            return Self::for_job(job, company, db);
+        }
+        let mut pool: Vec<QuizQuestion> = round
+            .questions
+            .iter()
+            .filter_map(|q| {
+                db.get_questions(&q.related_skill)
+                    .iter()
+                    .find(|entry| entry.question == q.question)
+                    .map(|entry| QuizQuestion { related_skill: Some(q.related_skill.clone()), ..entry.into() })
+            })
+            .collect();
+        if pool.is_empty() {
+            pool.push(fallback_question());
+        }
+        let length = pool.len();
+        Self::new(pool, job.difficulty as f32, length)
+    }
+
+    /// Number of questions this interview will ask
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Current difficulty the next question is aimed at
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Move the difficulty target up after a correct answer, down otherwise
+    pub fn record_answer(&mut self, correct: bool) {
+        let step = if correct { DIFFICULTY_STEP } else { -DIFFICULTY_STEP };
+        self.target = (self.target + step).clamp(MIN_QUESTION_DIFFICULTY, MAX_QUESTION_DIFFICULTY);
+    }
+
+    /// Pick the unasked question closest to the difficulty target
+    ///
+    /// Ties are broken with `rng`, favouring heavier weighted questions,
+    /// and `rng` also shuffles the options. Returns None once the
+    /// interview's length is reached.
+    pub fn next_question(&mut self, rng: &mut GameRng) -> Option<QuizQuestion> {
+        if self.asked.iter().filter(|a| **a).count() >= self.length {
+            return None;
+        }
+
+        let distance = |q: &QuizQuestion| (q.difficulty as f32 - self.target).abs();
+        let best = self
+            .pool
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.asked[*i])
+            .map(|(_, q)| distance(q))
+            .fold(f32::INFINITY, f32::min);
+        let candidates: Vec<usize> = (0..self.pool.len())
+            .filter(|i| !self.asked[*i] && distance(&self.pool[*i]) == best)
+            .collect();
+
+        // A tiny floor keeps zero-weight questions drawable
+        let idx = *candidates.choose_weighted(rng, |&i| self.weights[i].max(0.01)).ok()?;
+        self.asked[idx] = true;
+        Some(self.pool[idx].shuffled(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question(difficulty: u8, n: usize) -> QuizQuestion {
+        QuizQuestion {
+            question: format!("Q{} (difficulty {})", n, difficulty),
+            options: vec!["A".to_string(), "B".to_string()],
+            correct_idx: 0,
+            difficulty,
+            explanation: None,
+            related_skill: None,
+        }
+    }
+
+    fn mixed_pool() -> Vec<QuizQuestion> {
+        [1, 1, 2, 2, 3, 3, 4, 4]
+            .iter()
+            .enumerate()
+            .map(|(n, d)| question(*d, n))
+            .collect()
+    }
+
+    #[test]
+    fn test_correct_answers_raise_difficulty() {
+        let mut quiz = AdaptiveQuiz::new(mixed_pool(), 1.0, 5);
+        let mut rng = GameRng::new(3);
+        let mut difficulties = Vec::new();
+        while let Some(q) = quiz.next_question(&mut rng) {
+            difficulties.push(q.difficulty);
+            quiz.record_answer(true);
+        }
+
+        assert_eq!(difficulties.len(), 5);
+        assert!(difficulties.windows(2).all(|w| w[1] >= w[0]));
+        assert!(difficulties.last() > difficulties.first());
+    }
+
+    #[test]
+    fn test_wrong_answers_ease_off() {
+        let mut quiz = AdaptiveQuiz::new(mixed_pool(), 4.0, 4);
+        let mut rng = GameRng::new(3);
+        let first = quiz.next_question(&mut rng).unwrap();
+        quiz.record_answer(false);
+        quiz.record_answer(false);
+        let later = quiz.next_question(&mut rng).unwrap();
+        assert!(later.difficulty < first.difficulty);
+    }
+
+    #[test]
+    fn test_target_is_clamped() {
+        let mut quiz = AdaptiveQuiz::new(mixed_pool(), 1.0, 3);
+        for _ in 0..10 {
+            quiz.record_answer(false);
+        }
+        assert_eq!(quiz.target(), MIN_QUESTION_DIFFICULTY);
+    }
+
+    #[test]
+    fn test_for_job_uses_question_db() {
+        let db = InterviewQuestionDb::load();
+        let job = crate::companies::get_all_companies()
+            .into_iter()
+            .flat_map(|c| c.open_positions)
+            .find(|j| j.requirements.iter().any(|r| r.mandatory))
+            .unwrap();
+        let mut quiz = AdaptiveQuiz::for_job(&job, None, &db);
+        let mut rng = GameRng::new(1);
+        let mut asked = 0;
+        while quiz.next_question(&mut rng).is_some() {
+            asked += 1;
+        }
+        assert_eq!(asked, quiz.len());
+    }
+
+    #[test]
+    fn test_quiz_rounds_ask_the_round_questions_with_options() {
+        use crate::interview::Interview;
+        use crate::jobs::CompanyTier;
+
+        let db = InterviewQuestionDb::load();
+        let job = crate::companies::get_all_companies()
+            .into_iter()
+            .flat_map(|c| c.open_positions)
+            .find(|j| j.difficulty >= 3)
+            .unwrap();
+        let rounds = Interview::quiz_rounds(&job, CompanyTier::Startup);
+        assert_eq!(rounds[0].name, "Technical");
+        assert!(rounds.iter().any(|r| r.name == "System Design"));
+
+        for round in &rounds[1..] {
+            let mut quiz = AdaptiveQuiz::for_round(&job, round, None, &db);
+            let mut rng = GameRng::new(1);
+            let mut asked = Vec::new();
+            while let Some(q) = quiz.next_question(&mut rng) {
+                assert!(q.options.len() > 1);
+                asked.push(q.question);
+            }
+            assert_eq!(asked.len(), round.questions.len());
+            assert!(round.questions.iter().all(|q| asked.contains(&q.question)));
+        }
+    }
+
+    #[test]
+    fn test_company_emphasis_weights_questions_toward_its_skills() {
+        use std::collections::HashMap;
+
+        use crate::jobs::tests::test_job_requiring;
+        use crate::jobs::CompanyTier;
+        use crate::skills::Proficiency;
+
+        let db = InterviewQuestionDb::load();
+        let job_with_skills = |skills: &[&str]| {
+            test_job_requiring(&skills.iter().map(|&s| (s, Proficiency::Basic)).collect::<Vec<_>>())
+        };
+        let company = |emphasis: &[(&str, f32)]| Company {
+            name: "Test Co".to_string(),
+            description: String::new(),
+            tier: CompanyTier::MidSize,
+            open_positions: Vec::new(),
+            emphasis: emphasis.iter().map(|(skill, weight)| (skill.to_string(), *weight)).collect::<HashMap<_, _>>(),
+        };
+        let asks_rag = |quiz: &mut AdaptiveQuiz, rng: &mut GameRng| {
+            let mut asked = false;
+            while let Some(q) = quiz.next_question(rng) {
+                asked |= q.related_skill.as_deref() == Some("RAG");
+                quiz.record_answer(true);
+            }
+            asked
+        };
+
+        let job = job_with_skills(&["Python", "PyTorch", "Transformers", "SQL", "Statistics", "RAG", "MLOps"]);
+        let rag_interviews = |company: &Company| {
+            (0..200)
+                .filter(|&attempt| {
+                    let mut quiz = AdaptiveQuiz::for_job(&job, Some(company), &db);
+                    asks_rag(&mut quiz, &mut GameRng::for_job_attempt(42, job.id, attempt))
+                })
+                .count()
+        };
+        let neutral = rag_interviews(&company(&[]));
+        let rag_focused = rag_interviews(&company(&[("RAG", 3.0)]));
+        assert!(rag_focused > neutral + 40, "{} vs {}", rag_focused, neutral);
+
+        // Emphasized skills are asked about even when the job doesn't list them
+        let python_only = job_with_skills(&["Python"]);
+        let quiz = AdaptiveQuiz::for_job(&python_only, Some(&company(&[("RAG", 1.0)])), &db);
+        assert_eq!(quiz.len(), 2);
+        assert!(quiz.pool.iter().any(|q| q.related_skill.as_deref() == Some("RAG")));
+        assert!(!asks_rag(&mut AdaptiveQuiz::for_job(&python_only, None, &db), &mut GameRng::new(1)));
+    }
+}
@@ -1,17 +1,41 @@
+pub mod adaptive;
+pub mod questions;
+pub mod quiz;
+pub mod session;
+
+use std::ops::RangeInclusive;
+
 use rand::Rng;
+use serde::Deserialize;
+
+use self::questions::InterviewQuestionDb;
 
-use crate::jobs::Job;
+use crate::jobs::{CompanyTier, Job};
 use crate::player::Player;
 use crate::skills::Proficiency;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum QuestionType {
+    #[default]
     Technical,
     Behavioral,
     SystemDesign,
     Coding,
 }
 
+impl QuestionType {
+    /// What the candidate should expect, for round announcements
+    pub fn expectation(&self) -> &'static str {
+        match self {
+            QuestionType::Technical => "you'll be quizzed on core concepts",
+            QuestionType::Behavioral => "you'll talk about your experience and how you work",
+            QuestionType::SystemDesign => "you'll be asked to architect a system",
+            QuestionType::Coding => "you'll write code to solve a problem",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InterviewQuestion {
     pub question: String,
@@ -27,6 +51,27 @@ pub struct InterviewRound {
     pub pass_threshold: f32,
 }
 
+impl InterviewRound {
+    /// Line shown before the round starts, e.g. "Next: System Design - ..."
+    pub fn announcement(&self) -> String {
+        let question_type = self
+            .questions
+            .first()
+            .map(|q| &q.question_type)
+            .unwrap_or(&QuestionType::Technical);
+        format!("Next: {} - {}", self.name, question_type.expectation())
+    }
+}
+
+/// What a round will cover, without its questions
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundPreview {
+    pub name: String,
+    /// Skills the round's questions test, in order of first appearance
+    pub skills: Vec<String>,
+    pub pass_threshold: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct InterviewResult {
     pub round_name: String,
@@ -35,10 +80,22 @@ pub struct InterviewResult {
     pub feedback: Vec<String>,
 }
 
+/// Pass threshold increase per point of company difficulty modifier
+const TIER_THRESHOLD_STEP: f32 = 0.05;
+/// Highest pass threshold any round can demand
+const MAX_PASS_THRESHOLD: f32 = 0.95;
+/// Questions in a round drawn from the question db by type
+const POOLED_ROUND_QUESTIONS: usize = 2;
+/// Level a certification exam holds the candidate to
+const CERT_EXAM_LEVEL: Proficiency = Proficiency::Advanced;
+/// Share of the technical quiz's points needed to pass it
+const TECHNICAL_QUIZ_THRESHOLD: f32 = 0.5;
+
 pub struct Interview;
 
 impl Interview {
-    pub fn generate_rounds(job: &Job) -> Vec<InterviewRound> {
+    /// Build the interview loop; tougher tiers add rounds and raise the bar
+    pub fn generate_rounds(job: &Job, tier: CompanyTier) -> Vec<InterviewRound> {
         let mut rounds = vec![Self::screening_round()];
         
         for req in &job.requirements {
@@ -47,38 +104,73 @@ impl Interview {
             }
         }
 
-        if job.difficulty >= 2 {
+        rounds.extend(Self::closing_rounds(job, tier));
+        Self::raise_thresholds(&mut rounds, tier);
+        rounds
+    }
+
+    /// Rounds of the multiple-choice interview the game plays
+    ///
+    /// Opens with an adaptive technical quiz on the job's skills, a round
+    /// with no fixed questions (see `AdaptiveQuiz::for_round`), then the
+    /// same system design and behavioral rounds as `generate_rounds`.
+    pub fn quiz_rounds(job: &Job, tier: CompanyTier) -> Vec<InterviewRound> {
+        let mut rounds = vec![InterviewRound {
+            name: "Technical".to_string(),
+            questions: Vec::new(),
+            pass_threshold: TECHNICAL_QUIZ_THRESHOLD,
+        }];
+        rounds.extend(Self::closing_rounds(job, tier));
+        Self::raise_thresholds(&mut rounds, tier);
+        rounds
+    }
+
+    /// System design and behavioral rounds, added as the job gets harder
+    fn closing_rounds(job: &Job, tier: CompanyTier) -> Vec<InterviewRound> {
+        let difficulty = job.difficulty + tier.difficulty_modifier();
+        let mut rounds = Vec::new();
+        if difficulty >= 2 {
             rounds.push(Self::system_design_round());
         }
 
-        if job.difficulty >= 3 {
+        if difficulty >= 3 {
             rounds.push(Self::behavioral_round());
         }
-
         rounds
     }
 
-    fn screening_round() -> InterviewRound {
-        InterviewRound {
-            name: "HR Screening".to_string(),
-            questions: vec![
-                InterviewQuestion {
-                    question: "Tell me about yourself and your experience with AI/ML".to_string(),
-                    question_type: QuestionType::Behavioral,
-                    related_skill: "Communication".to_string(),
-                    difficulty: 1,
-                },
-                InterviewQuestion {
-                    question: "Why are you interested in this role?".to_string(),
-                    question_type: QuestionType::Behavioral,
-                    related_skill: "Communication".to_string(),
-                    difficulty: 1,
-                },
-            ],
-            pass_threshold: 0.5,
+    /// Tougher tiers raise every round's bar
+    fn raise_thresholds(rounds: &mut [InterviewRound], tier: CompanyTier) {
+        let bump = tier.difficulty_modifier() as f32 * TIER_THRESHOLD_STEP;
+        for round in rounds {
+            round.pass_threshold = (round.pass_threshold + bump).min(MAX_PASS_THRESHOLD);
         }
     }
 
+    /// Round structure a player learns by researching the company
+    pub fn preview(job: &Job, tier: CompanyTier) -> Vec<RoundPreview> {
+        Self::generate_rounds(job, tier)
+            .into_iter()
+            .map(|round| {
+                let mut skills: Vec<String> = Vec::new();
+                for question in &round.questions {
+                    if !skills.contains(&question.related_skill) {
+                        skills.push(question.related_skill.clone());
+                    }
+                }
+                RoundPreview {
+                    name: round.name,
+                    skills,
+                    pass_threshold: round.pass_threshold,
+                }
+            })
+            .collect()
+    }
+
+    fn screening_round() -> InterviewRound {
+        Self::pooled_round("HR Screening", QuestionType::Behavioral, 1..=1, 0.5)
+    }
+
     fn technical_round(skill: &str, proficiency: Proficiency) -> InterviewRound {
         let questions = match skill {
             "Python" => vec![
@@ -184,49 +276,59 @@ impl Interview {
         }
     }
 
-    fn system_design_round() -> InterviewRound {
+    /// Single-skill round sat as a certification exam
+    pub fn certification_round(skill: &str) -> InterviewRound {
         InterviewRound {
-            name: "System Design".to_string(),
-            questions: vec![
-                InterviewQuestion {
-                    question: "Design a real-time recommendation system for an e-commerce platform".to_string(),
-                    question_type: QuestionType::SystemDesign,
-                    related_skill: "System Design".to_string(),
-                    difficulty: 3,
-                },
-                InterviewQuestion {
-                    question: "How would you design an ML pipeline for continuous model training?".to_string(),
-                    question_type: QuestionType::SystemDesign,
-                    related_skill: "MLOps".to_string(),
-                    difficulty: 3,
-                },
-            ],
-            pass_threshold: 0.6,
+            name: format!("{} Certification", skill),
+            ..Self::technical_round(skill, CERT_EXAM_LEVEL)
         }
     }
 
+    fn system_design_round() -> InterviewRound {
+        Self::pooled_round("System Design", QuestionType::SystemDesign, 1..=4, 0.6)
+    }
+
     fn behavioral_round() -> InterviewRound {
+        Self::pooled_round("Behavioral", QuestionType::Behavioral, 2..=4, 0.6)
+    }
+
+    /// Round of the question db's entries of one type and difficulty range
+    ///
+    /// Takes the first `POOLED_ROUND_QUESTIONS` in skill order, so the same
+    /// job always gets the same round and previews stay accurate.
+    fn pooled_round(
+        name: &str,
+        question_type: QuestionType,
+        difficulty: RangeInclusive<u8>,
+        pass_threshold: f32,
+    ) -> InterviewRound {
+        let questions = InterviewQuestionDb::shared()
+            .questions_of_type(question_type)
+            .into_iter()
+            .filter(|(_, q)| difficulty.contains(&q.difficulty))
+            .take(POOLED_ROUND_QUESTIONS)
+            .map(|(skill, q)| InterviewQuestion {
+                question: q.question.clone(),
+                question_type,
+                related_skill: skill.to_string(),
+                difficulty: q.difficulty,
+            })
+            .collect();
         InterviewRound {
-            name: "Behavioral".to_string(),
-            questions: vec![
-                InterviewQuestion {
-                    question: "Tell me about a time you had to explain complex ML concepts to non-technical stakeholders".to_string(),
-                    question_type: QuestionType::Behavioral,
-                    related_skill: "Communication".to_string(),
-                    difficulty: 2,
-                },
-                InterviewQuestion {
-                    question: "Describe a project where you had to balance technical debt with delivering features".to_string(),
-                    question_type: QuestionType::Behavioral,
-                    related_skill: "Communication".to_string(),
-                    difficulty: 2,
-                },
-            ],
-            pass_threshold: 0.6,
+            name: name.to_string(),
+            questions,
+            pass_threshold,
         }
     }
 
     pub fn answer_question(player: &Player, question: &InterviewQuestion) -> f32 {
+        Self::answer_question_with(player, question, &mut rand::thread_rng())
+    }
+
+    /// Score an answer using the given RNG for the random variance
+    ///
+    /// Certified skills never score below their certification floor.
+    pub fn answer_question_with(player: &Player, question: &InterviewQuestion, rng: &mut impl Rng) -> f32 {
         let proficiency = player.get_skill_proficiency(&question.related_skill);
         let base_score = match proficiency {
             Proficiency::None => 0.2,
@@ -236,19 +338,27 @@ impl Interview {
             Proficiency::Expert => 0.95,
         };
 
-        let mut rng = rand::thread_rng();
         let variance = 0.15;
         let adjustment: f32 = rng.gen_range(-variance..variance);
         
-        (base_score + adjustment).clamp(0.0, 1.0)
+        let score = ((base_score + adjustment) * player.burnout.score_multiplier()).clamp(0.0, 1.0);
+        match player.certified_score_floor(&question.related_skill) {
+            Some(floor) => score.max(floor),
+            None => score,
+        }
     }
 
     pub fn conduct_round(player: &Player, round: &InterviewRound) -> InterviewResult {
+        Self::conduct_round_with(player, round, &mut rand::thread_rng())
+    }
+
+    /// Run a round using the given RNG, e.g. a seeded `GameRng`
+    pub fn conduct_round_with(player: &Player, round: &InterviewRound, rng: &mut impl Rng) -> InterviewResult {
         let mut total_score = 0.0;
         let mut feedback = Vec::new();
 
         for question in &round.questions {
-            let score = Self::answer_question(player, question);
+            let score = Self::answer_question_with(player, question, rng);
             total_score += score;
             
             feedback.push(format!(
@@ -269,3 +379,72 @@ impl Interview {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameRng;
+    use crate::jobs::tests::test_job_requiring;
+    use crate::player::CERT_SCORE_FLOOR;
+
+    fn job(difficulty: u8) -> Job {
+        Job {
+            difficulty,
+            ..test_job_requiring(&[("Python", Proficiency::Intermediate)])
+        }
+    }
+
+    #[test]
+    fn test_faang_rounds_harder_than_startup() {
+        let startup = Interview::generate_rounds(&job(2), CompanyTier::Startup);
+        let faang = Interview::generate_rounds(&job(2), CompanyTier::Faang);
+
+        assert!(faang.len() > startup.len());
+        for (s, f) in startup.iter().zip(&faang) {
+            assert_eq!(s.name, f.name);
+            assert!(f.pass_threshold > s.pass_threshold);
+        }
+    }
+
+    #[test]
+    fn test_preview_reveals_system_design_for_hard_job() {
+        let preview = Interview::preview(&job(3), CompanyTier::Startup);
+        let design = preview.iter().find(|r| r.name == "System Design").expect("system design round");
+        assert!(design.skills.contains(&"System Design".to_string()));
+        assert_eq!(preview[0].name, "HR Screening");
+    }
+
+    #[test]
+    fn test_certified_skill_never_scores_below_the_floor() {
+        let question = &Interview::certification_round("Python").questions[0];
+        let mut player = Player::builder("Test").build();
+        player.burnout.intensity = crate::player::MAX_BURNOUT_INTENSITY;
+        let worst = |player: &Player| {
+            (0..200)
+                .map(|seed| Interview::answer_question_with(player, question, &mut GameRng::new(seed)))
+                .fold(f32::INFINITY, f32::min)
+        };
+        assert!(worst(&player) < CERT_SCORE_FLOOR);
+
+        player.certify("Python");
+        assert_eq!(worst(&player), CERT_SCORE_FLOOR);
+    }
+
+    #[test]
+    fn test_screening_round_draws_behavioral_questions_from_db() {
+        let behavioral = InterviewQuestionDb::shared().questions_of_type(QuestionType::Behavioral);
+        let screening = &Interview::generate_rounds(&job(1), CompanyTier::Startup)[0];
+
+        assert_eq!(screening.questions.len(), POOLED_ROUND_QUESTIONS);
+        for q in &screening.questions {
+            assert_eq!(q.question_type, QuestionType::Behavioral);
+            assert!(behavioral.iter().any(|(skill, b)| b.question == q.question && *skill == q.related_skill));
+        }
+    }
+
+    #[test]
+    fn test_pass_threshold_capped() {
+        let rounds = Interview::generate_rounds(&job(5), CompanyTier::Faang);
+        assert!(rounds.iter().all(|r| r.pass_threshold <= MAX_PASS_THRESHOLD));
+    }
+}
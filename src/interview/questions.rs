@@ -1,16 +1,34 @@
 //! Interview Questions Module
 //!
 //! Loads interview questions from config/interview_questions.toml.
-//! Questions are organized by skill name.
+//! Questions are organized by skill name and tagged with a `QuestionType`,
+//! so rounds can also draw, say, behavioral questions across all skills.
+
+use std::sync::OnceLock;
 
 use serde::Deserialize;
 
+use super::QuestionType;
+
 /// A single interview question
 #[derive(Debug, Clone, Deserialize)]
 pub struct InterviewQuestion {
     pub question: String,
     pub options: Vec<String>,
     pub correct_idx: usize,
+    /// 1 (warm-up) to 4 (expert)
+    #[serde(default = "default_difficulty")]
+    pub difficulty: u8,
+    /// Short note on why the correct option is right
+    #[serde(default)]
+    pub explanation: Option<String>,
+    /// Kind of round the question suits; untyped questions are technical
+    #[serde(default)]
+    pub question_type: QuestionType,
+}
+
+fn default_difficulty() -> u8 {
+    1
 }
 
 /// Questions for a single skill
@@ -35,11 +53,21 @@ pub struct InterviewQuestionDb {
 }
 
 impl InterviewQuestionDb {
+    /// The embedded question db, parsed once
+    pub fn shared() -> &'static Self {
+        static DB: OnceLock<InterviewQuestionDb> = OnceLock::new();
+        DB.get_or_init(Self::load)
+    }
+
     /// Load questions from embedded config file
     pub fn load() -> Self {
         const CONFIG: &str = include_str!("../config/interview_questions.toml");
+        Self::parse(CONFIG)
+    }
+
+    fn parse(source: &str) -> Self {
         let config: InterviewQuestionsConfig =
-            toml::from_str(CONFIG).expect("Failed to parse interview_questions.toml");
+            toml::from_str(source).expect("Failed to parse interview_questions.toml");
 
         let mut questions_by_skill = std::collections::HashMap::new();
         let mut default_questions = Vec::new();
@@ -78,6 +106,62 @@ impl InterviewQuestionDb {
         &self.default_questions
     }
 
+    /// All configured question sets as (skill name, questions), including "default"
+    pub fn skills(&self) -> impl Iterator<Item = (&str, &[InterviewQuestion])> {
+        self.questions_by_skill
+            .iter()
+            .map(|(name, questions)| (name.as_str(), questions.as_slice()))
+            .chain(std::iter::once(("default", self.default_questions.as_slice())))
+    }
+
+    /// Questions of one type across all skills, as (skill name, question)
+    ///
+    /// Sorted by skill name, keeping each skill's config order. The
+    /// "default" fallback questions are left out.
+    pub fn questions_of_type(&self, question_type: QuestionType) -> Vec<(&str, &InterviewQuestion)> {
+        let mut skills: Vec<_> = self.questions_by_skill.iter().collect();
+        skills.sort_by_key(|(name, _)| name.as_str());
+        skills
+            .into_iter()
+            .flat_map(|(name, questions)| questions.iter().map(move |q| (name.as_str(), q)))
+            .filter(|(_, q)| q.question_type == question_type)
+            .collect()
+    }
+
+    /// Check every question, collecting all problems
+    ///
+    /// Reports `correct_idx` values pointing past the options, questions
+    /// with fewer than two options and difficulties outside 1-4.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut skills: Vec<_> = self.skills().collect();
+        skills.sort_by_key(|(name, _)| *name);
+        let mut problems = Vec::new();
+        for (skill, questions) in skills {
+            for (i, q) in questions.iter().enumerate() {
+                let at = format!("{} question {} ({:?})", skill, i + 1, q.question);
+                if q.options.len() < 2 {
+                    problems.push(format!("{} has {} option(s), needs at least 2", at, q.options.len()));
+                }
+                if q.correct_idx >= q.options.len() {
+                    problems.push(format!(
+                        "{} has correct_idx {} but only {} options",
+                        at,
+                        q.correct_idx,
+                        q.options.len()
+                    ));
+                }
+                if !(1..=4).contains(&q.difficulty) {
+                    problems.push(format!("{} has difficulty {}, expected 1-4", at, q.difficulty));
+                }
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
     /// Get a random question for a skill
     pub fn get_random_question(&self, skill_name: &str) -> Option<&InterviewQuestion> {
         use rand::seq::SliceRandom;
@@ -110,6 +194,68 @@ mod tests {
         assert!(!questions.is_empty());
     }
 
+    #[test]
+    fn test_questions_have_difficulty() {
+        let db = InterviewQuestionDb::load();
+        let questions = db.get_questions("Python");
+        assert!(questions.iter().all(|q| (1..=4).contains(&q.difficulty)));
+        assert!(questions.iter().any(|q| q.difficulty > 1));
+    }
+
+    #[test]
+    fn test_validate_reports_bad_questions() {
+        assert_eq!(InterviewQuestionDb::load().validate(), Ok(()));
+
+        let db = InterviewQuestionDb::parse(
+            r#"
+            [[skill]]
+            name = "Python"
+            [[skill.questions]]
+            question = "Q1"
+            options = ["a", "b"]
+            correct_idx = 2
+            [[skill.questions]]
+            question = "Q2"
+            options = ["a"]
+            correct_idx = 0
+            difficulty = 7
+            "#,
+        );
+
+        assert_eq!(
+            db.validate().unwrap_err(),
+            vec![
+                "Python question 1 (\"Q1\") has correct_idx 2 but only 2 options",
+                "Python question 2 (\"Q2\") has 1 option(s), needs at least 2",
+                "Python question 2 (\"Q2\") has difficulty 7, expected 1-4",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_untyped_questions_default_to_technical() {
+        let db = InterviewQuestionDb::parse(
+            r#"
+            [[skill]]
+            name = "Python"
+            [[skill.questions]]
+            question = "Q1"
+            options = ["a", "b"]
+            correct_idx = 0
+            [[skill.questions]]
+            question = "Q2"
+            options = ["a", "b"]
+            correct_idx = 0
+            question_type = "system_design"
+            "#,
+        );
+
+        let technical = db.questions_of_type(QuestionType::Technical);
+        assert_eq!(technical.len(), 1);
+        assert_eq!(technical[0].1.question, "Q1");
+        assert_eq!(db.questions_of_type(QuestionType::SystemDesign)[0].1.question, "Q2");
+    }
+
     #[test]
     fn test_skill_with_space() {
         let db = InterviewQuestionDb::load();
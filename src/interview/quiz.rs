@@ -0,0 +1,512 @@
+//! Interview Quiz
+//!
+//! Multiple-choice quiz shown on the Interview screen.
+//! Questions are picked from a job's mandatory requirements, and their
+//! options are shuffled each time one is asked so the answer key can't be
+//! memorized by position.
+
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::Rng;
+
+use crate::game::GameRng;
+use crate::jobs::Job;
+use crate::player::Player;
+use crate::skills::Proficiency;
+
+/// A multiple-choice quiz question
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuizQuestion {
+    pub question: String,
+    pub options: Vec<String>,
+    pub correct_idx: usize,
+    pub difficulty: u8,
+    /// Why the correct option is right, shown on request after a miss
+    pub explanation: Option<String>,
+    /// Skill the question tests, if it tests one
+    pub related_skill: Option<String>,
+}
+
+impl QuizQuestion {
+    /// Copy with the options in random order and `correct_idx` moved to
+    /// wherever the correct option landed
+    pub fn shuffled(&self, rng: &mut GameRng) -> Self {
+        let mut order: Vec<usize> = (0..self.options.len()).collect();
+        order.shuffle(rng);
+        Self {
+            options: order.iter().map(|&i| self.options[i].clone()).collect(),
+            correct_idx: order.iter().position(|&i| i == self.correct_idx).unwrap_or(self.correct_idx),
+            ..self.clone()
+        }
+    }
+
+    /// How the question compares to the player's level in its skill
+    pub fn gauge_for(&self, player: &Player) -> Option<DifficultyGauge> {
+        let skill = self.related_skill.as_deref()?;
+        Some(difficulty_gauge(self.difficulty, player.get_skill_proficiency(skill)))
+    }
+}
+
+/// A question's difficulty measured against the player's proficiency;
+/// shown during interviews, never used in scoring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DifficultyGauge {
+    BelowLevel,
+    AtLevel,
+    AboveLevel,
+    FarAboveLevel,
+}
+
+impl DifficultyGauge {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DifficultyGauge::BelowLevel => "You've got this",
+            DifficultyGauge::AtLevel => "Right at your level",
+            DifficultyGauge::AboveLevel => "This is above your level",
+            DifficultyGauge::FarAboveLevel => "This is well above your level",
+        }
+    }
+}
+
+/// Compare a question difficulty (1-4) with a proficiency on the same
+/// scale, Basic being 1 and Expert 4
+pub fn difficulty_gauge(difficulty: u8, proficiency: Proficiency) -> DifficultyGauge {
+    match difficulty as i32 - proficiency as i32 {
+        gap if gap < 0 => DifficultyGauge::BelowLevel,
+        0 => DifficultyGauge::AtLevel,
+        1 => DifficultyGauge::AboveLevel,
+        _ => DifficultyGauge::FarAboveLevel,
+    }
+}
+
+/// How sure the player is about an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Confidence {
+    #[default]
+    Sure,
+    Unsure,
+}
+
+impl Confidence {
+    pub fn toggle(self) -> Self {
+        match self {
+            Confidence::Sure => Confidence::Unsure,
+            Confidence::Unsure => Confidence::Sure,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Confidence::Sure => "Sure",
+            Confidence::Unsure => "Unsure",
+        }
+    }
+}
+
+/// Points for a confident wrong answer
+pub const SURE_WRONG_PENALTY: f32 = -0.5;
+/// Points for an unsure correct answer
+pub const UNSURE_CORRECT_POINTS: f32 = 0.5;
+/// Share of a point each wrong answer costs when negative marking is on
+pub const DEFAULT_WRONG_ANSWER_PENALTY: f32 = 0.25;
+
+/// An answered quiz question
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuizAnswer {
+    pub correct: bool,
+    pub confidence: Confidence,
+}
+
+impl QuizAnswer {
+    /// Confident answers risk a penalty for full credit; unsure ones play it safe
+    pub fn points(&self) -> f32 {
+        match (self.confidence, self.correct) {
+            (Confidence::Sure, true) => 1.0,
+            (Confidence::Sure, false) => SURE_WRONG_PENALTY,
+            (Confidence::Unsure, true) => UNSURE_CORRECT_POINTS,
+            (Confidence::Unsure, false) => 0.0,
+        }
+    }
+
+    /// Points with negative marking: a wrong answer also loses `wrong_penalty`
+    pub fn marked_points(&self, wrong_penalty: f32) -> f32 {
+        if self.correct {
+            self.points()
+        } else {
+            self.points() - wrong_penalty
+        }
+    }
+}
+
+/// Total interview score, never below zero
+pub fn quiz_score(answers: &[QuizAnswer]) -> f32 {
+    marked_quiz_score(answers, 0.0)
+}
+
+/// Total score with `wrong_penalty` taken off per wrong answer, never below zero
+///
+/// A penalty of 0 is plain marking. Any penalty makes a blind guess cost
+/// something even when the player marks it unsure.
+pub fn marked_quiz_score(answers: &[QuizAnswer], wrong_penalty: f32) -> f32 {
+    answers.iter().map(|a| a.marked_points(wrong_penalty)).sum::<f32>().max(0.0)
+}
+
+/// Hints each interview comes with before they start costing reputation
+pub const FREE_HINTS_PER_INTERVIEW: u32 = 1;
+/// Reputation spent on each hint beyond the free ones
+pub const HINT_REPUTATION_COST: u32 = 1;
+
+/// Pick a wrong option to hide for a 50/50-style hint
+///
+/// Never picks the correct answer or an already hidden option, and always
+/// leaves at least one wrong option visible. Returns `None` when there is
+/// nothing left to eliminate.
+pub fn eliminate_wrong_option(
+    question: &QuizQuestion,
+    eliminated: &[usize],
+    rng: &mut GameRng,
+) -> Option<usize> {
+    let remaining_wrong: Vec<usize> = (0..question.options.len())
+        .filter(|&i| i != question.correct_idx && !eliminated.contains(&i))
+        .collect();
+    if remaining_wrong.len() < 2 {
+        return None;
+    }
+    remaining_wrong.into_iter().choose(rng)
+}
+
+/// Chance a candidate simply knows the answer at a proficiency level
+fn knows_answer_chance(proficiency: Proficiency) -> f64 {
+    match proficiency {
+        Proficiency::None => 0.0,
+        Proficiency::Basic => 0.3,
+        Proficiency::Intermediate => 0.55,
+        Proficiency::Advanced => 0.75,
+        Proficiency::Expert => 0.95,
+    }
+}
+
+/// Pick an option the way a candidate with the player's level in `skill` would
+///
+/// The quiz counterpart of `Interview::answer_question`, for AI opponents and
+/// balancing runs: the candidate either knows the answer or guesses among
+/// all options, so even a None candidate is right by luck sometimes.
+pub fn auto_answer(player: &Player, skill: &str, question: &QuizQuestion, rng: &mut GameRng) -> usize {
+    let proficiency = player.get_skill_proficiency(skill);
+    if question.options.is_empty() || rng.gen_bool(knows_answer_chance(proficiency)) {
+        question.correct_idx
+    } else {
+        rng.gen_range(0..question.options.len())
+    }
+}
+
+/// Most questions asked in a single interview
+pub const MAX_QUIZ_QUESTIONS: usize = 5;
+
+/// Build the quiz for a job from its mandatory requirements
+///
+/// When a job has more than five mandatory skills, a subset is picked
+/// using `rng`, so the same seed always yields the same quiz.
+pub fn generate_interview_questions(job: &Job, rng: &mut GameRng) -> Vec<QuizQuestion> {
+    let mut questions = Vec::new();
+    
+    for req in &job.requirements {
+        if req.mandatory {
+            let q = create_question_for_skill(&req.skill_name);
+            questions.push(q);
+        }
+    }
+    
+    if questions.len() > MAX_QUIZ_QUESTIONS {
+        questions.shuffle(rng);
+        questions.truncate(MAX_QUIZ_QUESTIONS);
+    }
+    
+    if questions.is_empty() {
+        questions.push(fallback_question());
+    }
+    
+    questions.iter().map(|q| q.shuffled(rng)).collect()
+}
+
+/// Question asked when a job has no mandatory skills
+pub fn fallback_question() -> QuizQuestion {
+    QuizQuestion {
+        question: "Why do you want to work here?".to_string(),
+        options: vec![
+            "I'm passionate about AI and want to learn".to_string(),
+            "For the money".to_string(),
+            "My friend works here".to_string(),
+            "I don't know".to_string(),
+        ],
+        correct_idx: 0,
+        difficulty: 1,
+        explanation: None,
+        related_skill: None,
+    }
+}
+
+/// Built-in quiz question for a skill
+pub fn create_question_for_skill(skill_name: &str) -> QuizQuestion {
+    match skill_name {
+        "Python" => QuizQuestion {
+            question: "What is the difference between a list and a tuple in Python?".to_string(),
+            options: vec![
+                "Lists are mutable, tuples are immutable".to_string(),
+                "Lists are faster than tuples".to_string(),
+                "Tuples can hold more items".to_string(),
+                "There is no difference".to_string(),
+            ],
+            correct_idx: 0,
+            difficulty: 1,
+            explanation: None,
+            related_skill: Some(skill_name.to_string()),
+        },
+        "PyTorch" | "TensorFlow" => QuizQuestion {
+            question: "What is backpropagation?".to_string(),
+            options: vec![
+                "Algorithm to compute gradients by chain rule".to_string(),
+                "A type of neural network layer".to_string(),
+                "Data preprocessing technique".to_string(),
+                "A loss function".to_string(),
+            ],
+            correct_idx: 0,
+            difficulty: 2,
+            explanation: None,
+            related_skill: Some(skill_name.to_string()),
+        },
+        "Transformers" => QuizQuestion {
+            question: "What is the key innovation in Transformer architecture?".to_string(),
+            options: vec![
+                "Self-attention mechanism".to_string(),
+                "Convolutional layers".to_string(),
+                "Recurrent connections".to_string(),
+                "Dropout regularization".to_string(),
+            ],
+            correct_idx: 0,
+            difficulty: 2,
+            explanation: None,
+            related_skill: Some(skill_name.to_string()),
+        },
+        "LLM Fine-tuning" => QuizQuestion {
+            question: "What is LoRA?".to_string(),
+            options: vec![
+                "Low-Rank Adaptation for efficient fine-tuning".to_string(),
+                "A type of language model".to_string(),
+                "A tokenization method".to_string(),
+                "A training loss function".to_string(),
+            ],
+            correct_idx: 0,
+            difficulty: 3,
+            explanation: None,
+            related_skill: Some(skill_name.to_string()),
+        },
+        "SQL" => QuizQuestion {
+            question: "Which SQL clause is used to filter results?".to_string(),
+            options: vec![
+                "WHERE".to_string(),
+                "ORDER BY".to_string(),
+                "GROUP BY".to_string(),
+                "SELECT".to_string(),
+            ],
+            correct_idx: 0,
+            difficulty: 1,
+            explanation: None,
+            related_skill: Some(skill_name.to_string()),
+        },
+        "Statistics" => QuizQuestion {
+            question: "What is the mean of [2, 4, 6, 8]?".to_string(),
+            options: vec![
+                "5".to_string(),
+                "4".to_string(),
+                "6".to_string(),
+                "4.5".to_string(),
+            ],
+            correct_idx: 0,
+            difficulty: 1,
+            explanation: None,
+            related_skill: Some(skill_name.to_string()),
+        },
+        _ => QuizQuestion {
+            question: format!("Explain your experience with {}", skill_name),
+            options: vec![
+                "I have strong practical experience".to_string(),
+                "I've studied it but need practice".to_string(),
+                "I've heard of it".to_string(),
+                "I don't know this".to_string(),
+            ],
+            correct_idx: 0,
+            difficulty: 1,
+            explanation: None,
+            related_skill: Some(skill_name.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::tests::test_job_requiring;
+    use crate::skills::Proficiency;
+
+    fn job_with_skills(id: u32, skills: &[&str]) -> Job {
+        let requirements: Vec<_> = skills.iter().map(|&s| (s, Proficiency::Basic)).collect();
+        Job { id, ..test_job_requiring(&requirements) }
+    }
+
+    const MANY_SKILLS: [&str; 8] = [
+        "Python", "PyTorch", "Transformers", "LLM Fine-tuning",
+        "SQL", "Statistics", "RAG", "MLOps",
+    ];
+
+    #[test]
+    fn test_same_seed_same_questions() {
+        let job = job_with_skills(7, &MANY_SKILLS);
+        let first = generate_interview_questions(&job, &mut GameRng::for_job_attempt(42, job.id, 0));
+        let second = generate_interview_questions(&job, &mut GameRng::for_job_attempt(42, job.id, 0));
+
+        assert_eq!(first.len(), 5);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_retries_vary_questions() {
+        let job = job_with_skills(7, &MANY_SKILLS);
+        let first = generate_interview_questions(&job, &mut GameRng::for_job_attempt(42, job.id, 0));
+        let varied = (1..10).any(|attempt| {
+            generate_interview_questions(&job, &mut GameRng::for_job_attempt(42, job.id, attempt)) != first
+        });
+        assert!(varied);
+    }
+
+    #[test]
+    fn test_shuffle_moves_the_answer_key_with_the_correct_option() {
+        let original = create_question_for_skill("SQL");
+        let correct_text = &original.options[original.correct_idx];
+
+        let mut positions = std::collections::HashSet::new();
+        for seed in 0..20 {
+            let shuffled = original.shuffled(&mut GameRng::new(seed));
+            assert_eq!(&shuffled.options[shuffled.correct_idx], correct_text);
+            let mut sorted = shuffled.options.clone();
+            sorted.sort();
+            let mut expected = original.options.clone();
+            expected.sort();
+            assert_eq!(sorted, expected);
+            positions.insert(shuffled.correct_idx);
+        }
+        assert!(positions.len() > 1, "correct option never moved");
+    }
+
+    #[test]
+    fn test_hint_removes_one_wrong_option() {
+        for seed in 0..20 {
+            let mut rng = GameRng::new(seed);
+            let mut question = create_question_for_skill("SQL");
+            question.correct_idx = seed as usize % question.options.len();
+            let mut eliminated = Vec::new();
+
+            let first = eliminate_wrong_option(&question, &eliminated, &mut rng).unwrap();
+            assert_ne!(first, question.correct_idx);
+            eliminated.push(first);
+
+            let second = eliminate_wrong_option(&question, &eliminated, &mut rng).unwrap();
+            assert_ne!(second, question.correct_idx);
+            assert_ne!(second, first);
+            eliminated.push(second);
+
+            // Down to the correct answer and one wrong option
+            assert_eq!(eliminate_wrong_option(&question, &eliminated, &mut rng), None);
+        }
+    }
+
+    fn answer(correct: bool, confidence: Confidence) -> QuizAnswer {
+        QuizAnswer { correct, confidence }
+    }
+
+    #[test]
+    fn test_confident_wrong_costs_more_than_unsure_wrong() {
+        let right = answer(true, Confidence::Sure);
+        let sure_wrong = quiz_score(&[right, right, answer(false, Confidence::Sure)]);
+        let unsure_wrong = quiz_score(&[right, right, answer(false, Confidence::Unsure)]);
+        assert!(sure_wrong < unsure_wrong);
+        assert_eq!(unsure_wrong, 2.0);
+    }
+
+    #[test]
+    fn test_negative_marking_lowers_scores_with_wrong_answers() {
+        let right = answer(true, Confidence::Sure);
+        let mixed = [right, right, right, answer(false, Confidence::Unsure), answer(false, Confidence::Sure)];
+        let plain = marked_quiz_score(&mixed, 0.0);
+        let penalized = marked_quiz_score(&mixed, DEFAULT_WRONG_ANSWER_PENALTY);
+        assert_eq!(plain, quiz_score(&mixed));
+        assert_eq!(penalized, plain - 2.0 * DEFAULT_WRONG_ANSWER_PENALTY);
+
+        let all_right = [right, answer(true, Confidence::Unsure)];
+        assert_eq!(marked_quiz_score(&all_right, DEFAULT_WRONG_ANSWER_PENALTY), quiz_score(&all_right));
+
+        let all_wrong = [answer(false, Confidence::Unsure); 3];
+        assert_eq!(marked_quiz_score(&all_wrong, DEFAULT_WRONG_ANSWER_PENALTY), 0.0);
+    }
+
+    #[test]
+    fn test_unsure_caps_upside() {
+        assert!(answer(true, Confidence::Unsure).points() < answer(true, Confidence::Sure).points());
+        assert_eq!(quiz_score(&[answer(false, Confidence::Sure)]), 0.0);
+    }
+
+    #[test]
+    fn test_expert_auto_answers_beat_novice() {
+        let question = create_question_for_skill("Python");
+        let novice = Player::new("Novice");
+        let mut expert = Player::new("Expert");
+        expert.set_skill("Python", Proficiency::Expert).unwrap();
+        let mut rng = GameRng::new(3);
+
+        let trials = 1000;
+        let correct = |player: &Player, rng: &mut GameRng| {
+            (0..trials)
+                .filter(|_| auto_answer(player, "Python", &question, rng) == question.correct_idx)
+                .count()
+        };
+        let novice_correct = correct(&novice, &mut rng);
+        let expert_correct = correct(&expert, &mut rng);
+
+        assert!(expert_correct > novice_correct * 3, "{} vs {}", expert_correct, novice_correct);
+        assert!(novice_correct > 0);
+    }
+
+    #[test]
+    fn test_no_mandatory_skills_fallback() {
+        let job = job_with_skills(1, &[]);
+        let questions = generate_interview_questions(&job, &mut GameRng::new(1));
+        assert_eq!(questions.len(), 1);
+        assert!(questions[0].question.contains("work here"));
+    }
+
+    #[test]
+    fn test_difficulty_gauge_compares_difficulty_with_proficiency() {
+        let cases = [
+            (1, Proficiency::None, DifficultyGauge::AboveLevel),
+            (1, Proficiency::Basic, DifficultyGauge::AtLevel),
+            (1, Proficiency::Advanced, DifficultyGauge::BelowLevel),
+            (3, Proficiency::Basic, DifficultyGauge::FarAboveLevel),
+            (3, Proficiency::Intermediate, DifficultyGauge::AboveLevel),
+            (4, Proficiency::Expert, DifficultyGauge::AtLevel),
+            (4, Proficiency::None, DifficultyGauge::FarAboveLevel),
+        ];
+        for (difficulty, proficiency, expected) in cases {
+            assert_eq!(difficulty_gauge(difficulty, proficiency), expected, "{} vs {:?}", difficulty, proficiency);
+        }
+        assert_eq!(DifficultyGauge::BelowLevel.label(), "You've got this");
+        assert_eq!(DifficultyGauge::AboveLevel.label(), "This is above your level");
+    }
+
+    #[test]
+    fn test_gauge_uses_the_related_skill() {
+        let player = Player::builder("Test").skill("SQL", Proficiency::Advanced).build();
+        let sql = create_question_for_skill("SQL");
+        assert_eq!(sql.gauge_for(&player), Some(DifficultyGauge::BelowLevel));
+        assert_eq!(create_question_for_skill("LLM Fine-tuning").gauge_for(&player), Some(DifficultyGauge::FarAboveLevel));
+        assert_eq!(fallback_question().gauge_for(&player), None);
+    }
+}
@@ -0,0 +1,302 @@
+//! Interview Session
+//!
+//! Runs an interview's rounds in order, whether played as quizzes in the
+//! window (`Interview::quiz_rounds`) or scored automatically with
+//! `play_round`. After each passed round the session pauses between
+//! rounds so the next one can be announced. A failed round can be retried
+//! a limited number of times per interview instead of throwing the whole
+//! interview away.
+//!
+//! How round results add up to a hire is set by `PassingPolicy`: by default
+//! every round must pass, but an interview can instead be judged on the
+//! average score across all rounds.
+
+use rand::Rng;
+use serde::Deserialize;
+
+use super::{Interview, InterviewResult, InterviewRound};
+use crate::player::Player;
+
+/// Failed rounds a player may retry within one interview
+pub const ROUND_RETRIES_PER_INTERVIEW: u32 = 1;
+/// Average score needed under `PassingPolicy::Average` unless configured
+pub const DEFAULT_AVERAGE_THRESHOLD: f32 = 0.6;
+
+/// How round results combine into a hiring decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PassingPolicy {
+    /// Every round must clear its own threshold; failed rounds may be retried
+    #[default]
+    AllRounds,
+    /// Every round is played and the average score must clear a threshold
+    Average,
+}
+
+impl PassingPolicy {
+    /// Whether a set of round results earns the job under this policy
+    pub fn passes(self, results: &[InterviewResult], average_threshold: f32) -> bool {
+        if results.is_empty() {
+            return false;
+        }
+        match self {
+            PassingPolicy::AllRounds => results.iter().all(|r| r.passed),
+            PassingPolicy::Average => average_score(results) >= average_threshold,
+        }
+    }
+}
+
+/// Mean score across round results, 0 when there are none
+pub fn average_score(results: &[InterviewResult]) -> f32 {
+    if results.is_empty() {
+        return 0.0;
+    }
+    results.iter().map(|r| r.score).sum::<f32>() / results.len() as f32
+}
+
+/// What happened after a round was scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundOutcome {
+    /// Round passed, more rounds to go
+    Passed,
+    /// Round missed its threshold but, judged on the average, the interview goes on
+    Scored,
+    /// The interview was passed under the session's policy
+    Hired,
+    /// Round failed; `retry_available` says whether `retry` may be called
+    Failed { retry_available: bool },
+}
+
+#[derive(Debug, Clone)]
+pub struct InterviewSession {
+    rounds: Vec<InterviewRound>,
+    current_round: usize,
+    /// Every round attempt, including failed ones that were retried
+    pub results: Vec<InterviewResult>,
+    retries_used: u32,
+    awaiting_retry: bool,
+    /// Paused after a passed round until `start_next_round` is called
+    between_rounds: bool,
+    policy: PassingPolicy,
+    average_threshold: f32,
+}
+
+impl InterviewSession {
+    /// Session where every round must pass
+    pub fn new(rounds: Vec<InterviewRound>) -> Self {
+        Self::with_policy(rounds, PassingPolicy::AllRounds, DEFAULT_AVERAGE_THRESHOLD)
+    }
+
+    pub fn with_policy(rounds: Vec<InterviewRound>, policy: PassingPolicy, average_threshold: f32) -> Self {
+        Self {
+            rounds,
+            current_round: 0,
+            results: Vec::new(),
+            retries_used: 0,
+            awaiting_retry: false,
+            between_rounds: false,
+            policy,
+            average_threshold,
+        }
+    }
+
+    pub fn policy(&self) -> PassingPolicy {
+        self.policy
+    }
+
+    /// Round waiting to be played, if the interview is still going
+    pub fn current_round(&self) -> Option<&InterviewRound> {
+        if self.awaiting_retry || self.between_rounds {
+            return None;
+        }
+        self.rounds.get(self.current_round)
+    }
+
+    pub fn is_between_rounds(&self) -> bool {
+        self.between_rounds
+    }
+
+    /// Announcement for the upcoming round while paused between rounds
+    pub fn next_round_announcement(&self) -> Option<String> {
+        if !self.between_rounds {
+            return None;
+        }
+        self.rounds.get(self.current_round).map(InterviewRound::announcement)
+    }
+
+    /// Leave the transition and make the next round playable
+    pub fn start_next_round(&mut self) {
+        self.between_rounds = false;
+    }
+
+    /// One line per round attempt so far, e.g. "Technical: 67%"
+    pub fn summary(&self) -> String {
+        self.results
+            .iter()
+            .map(|r| format!("{}: {:.0}%", r.round_name, r.score * 100.0))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn retries_used(&self) -> u32 {
+        self.retries_used
+    }
+
+    pub fn can_retry(&self) -> bool {
+        self.awaiting_retry && self.retries_used < ROUND_RETRIES_PER_INTERVIEW
+    }
+
+    /// Score the current round; a round played straight after the
+    /// transition ends it too
+    pub fn record(&mut self, result: InterviewResult) -> RoundOutcome {
+        self.between_rounds = false;
+        if self.policy == PassingPolicy::Average {
+            return self.record_for_average(result);
+        }
+        let passed = result.passed;
+        self.results.push(result);
+        if passed {
+            self.current_round += 1;
+            if self.current_round >= self.rounds.len() {
+                RoundOutcome::Hired
+            } else {
+                self.between_rounds = true;
+                RoundOutcome::Passed
+            }
+        } else {
+            self.awaiting_retry = true;
+            RoundOutcome::Failed { retry_available: self.can_retry() }
+        }
+    }
+
+    /// Under `Average` every round is played once and judged at the end
+    fn record_for_average(&mut self, result: InterviewResult) -> RoundOutcome {
+        let passed = result.passed;
+        self.results.push(result);
+        self.current_round += 1;
+        if self.current_round < self.rounds.len() {
+            self.between_rounds = true;
+            return if passed { RoundOutcome::Passed } else { RoundOutcome::Scored };
+        }
+        if self.policy.passes(&self.results, self.average_threshold) {
+            RoundOutcome::Hired
+        } else {
+            RoundOutcome::Failed { retry_available: false }
+        }
+    }
+
+    /// Use a retry on the round just failed; false if none are left
+    pub fn retry(&mut self) -> bool {
+        if !self.can_retry() {
+            return false;
+        }
+        self.retries_used += 1;
+        self.awaiting_retry = false;
+        true
+    }
+
+    /// Play the current round for the player and record it
+    ///
+    /// Returns `None` when no round is playable right now, including
+    /// while paused between rounds.
+    pub fn play_round(&mut self, player: &Player, rng: &mut impl Rng) -> Option<RoundOutcome> {
+        let round = self.current_round()?.clone();
+        Some(self.record(Interview::conduct_round_with(player, &round, rng)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interview::{InterviewQuestion, QuestionType};
+
+    fn round(name: &str) -> InterviewRound {
+        InterviewRound {
+            name: name.to_string(),
+            questions: Vec::new(),
+            pass_threshold: 0.6,
+        }
+    }
+
+    fn result(name: &str, score: f32) -> InterviewResult {
+        InterviewResult {
+            round_name: name.to_string(),
+            score,
+            passed: score >= 0.6,
+            feedback: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_single_retry_rescues_borderline_round() {
+        let mut session = InterviewSession::new(vec![round("Screening"), round("Technical")]);
+        assert_eq!(session.record(result("Screening", 0.7)), RoundOutcome::Passed);
+
+        assert_eq!(
+            session.record(result("Technical", 0.55)),
+            RoundOutcome::Failed { retry_available: true }
+        );
+        assert!(session.current_round().is_none());
+        assert!(session.retry());
+        assert_eq!(session.current_round().unwrap().name, "Technical");
+        assert_eq!(session.record(result("Technical", 0.65)), RoundOutcome::Hired);
+        assert_eq!(session.retries_used(), 1);
+    }
+
+    #[test]
+    fn test_transition_between_rounds() {
+        let mut design = round("System Design");
+        design.questions.push(InterviewQuestion {
+            question: "Design a feature store".to_string(),
+            question_type: QuestionType::SystemDesign,
+            related_skill: "System Design".to_string(),
+            difficulty: 3,
+        });
+        let mut session = InterviewSession::new(vec![round("Screening"), design]);
+        assert!(!session.is_between_rounds());
+
+        assert_eq!(session.record(result("Screening", 0.7)), RoundOutcome::Passed);
+        assert!(session.is_between_rounds());
+        assert!(session.current_round().is_none());
+        assert_eq!(
+            session.next_round_announcement().unwrap(),
+            "Next: System Design - you'll be asked to architect a system"
+        );
+
+        session.start_next_round();
+        assert!(session.next_round_announcement().is_none());
+        assert_eq!(session.current_round().unwrap().name, "System Design");
+    }
+
+    #[test]
+    fn test_strong_round_carries_weak_one_only_under_average() {
+        let results = vec![result("Screening", 0.9), result("Technical", 0.5)];
+        assert!(PassingPolicy::Average.passes(&results, DEFAULT_AVERAGE_THRESHOLD));
+        assert!(!PassingPolicy::AllRounds.passes(&results, DEFAULT_AVERAGE_THRESHOLD));
+
+        let rounds = vec![round("Screening"), round("Technical")];
+        let mut average = InterviewSession::with_policy(rounds.clone(), PassingPolicy::Average, DEFAULT_AVERAGE_THRESHOLD);
+        assert_eq!(average.record(result("Screening", 0.5)), RoundOutcome::Scored);
+        average.start_next_round();
+        assert_eq!(average.record(result("Technical", 0.9)), RoundOutcome::Hired);
+
+        let mut all_rounds = InterviewSession::new(rounds);
+        assert_eq!(all_rounds.record(result("Screening", 0.9)), RoundOutcome::Passed);
+        all_rounds.start_next_round();
+        assert_eq!(all_rounds.record(result("Technical", 0.5)), RoundOutcome::Failed { retry_available: true });
+    }
+
+    #[test]
+    fn test_no_second_retry() {
+        let mut session = InterviewSession::new(vec![round("Technical")]);
+        session.record(result("Technical", 0.5));
+        assert!(session.retry());
+
+        assert_eq!(
+            session.record(result("Technical", 0.5)),
+            RoundOutcome::Failed { retry_available: false }
+        );
+        assert!(!session.retry());
+        assert!(session.current_round().is_none());
+    }
+}
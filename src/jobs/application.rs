@@ -0,0 +1,140 @@
+//! Job Applications
+//!
+//! Eligibility checks and batch applications across the job board.
+
+use std::collections::HashMap;
+
+use super::{Company, Job};
+use crate::player::Player;
+
+/// A queued application awaiting its interview
+#[derive(Debug, Clone)]
+pub struct Application {
+    pub job: Job,
+    /// Match score at the time of applying (0.0 - 1.0)
+    pub match_score: f32,
+}
+
+/// A job left out of a batch application, with the reason
+#[derive(Debug, Clone)]
+pub struct SkippedJob {
+    pub job: Job,
+    pub reason: String,
+}
+
+/// Result of applying to every matching job at once
+#[derive(Debug, Clone, Default)]
+pub struct BatchApplication {
+    pub queued: Vec<Application>,
+    pub skipped: Vec<SkippedJob>,
+}
+
+impl Job {
+    /// Check whether the player may apply for this job
+    ///
+    /// Returns the reason the player is ineligible, if any.
+    pub fn check_eligibility(&self, player: &Player) -> Result<(), String> {
+        if let Some(days) = player.cooldown_remaining(self.id) {
+            return Err(format!("On cooldown for {} more day(s)", days));
+        }
+
+        for req in self.requirements.iter().filter(|r| r.mandatory) {
+            let proficiency = player.get_skill_proficiency(&req.skill_name);
+            if proficiency < req.min_proficiency {
+                return Err(format!(
+                    "Needs {} at {}",
+                    req.skill_name,
+                    req.min_proficiency.as_str()
+                ));
+            }
+        }
+
+        if player.experience_days < self.min_experience_days {
+            return Err(format!(
+                "Needs {} days of experience",
+                self.min_experience_days
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Queue applications to every job matching at least `threshold`
+///
+/// Jobs above the threshold that the player isn't eligible for are
+/// skipped with a note explaining why.
+pub fn batch_apply(
+    companies: &[Company],
+    player: &Player,
+    threshold: f32,
+    trends: Option<&HashMap<String, f32>>,
+) -> BatchApplication {
+    let mut batch = BatchApplication::default();
+
+    for job in companies.iter().flat_map(|c| &c.open_positions) {
+        let match_score = job.calculate_match_with_trends(&player.skills, trends);
+        if match_score < threshold {
+            continue;
+        }
+
+        match job.check_eligibility(player) {
+            Ok(()) => batch.queued.push(Application {
+                job: job.clone(),
+                match_score,
+            }),
+            Err(reason) => batch.skipped.push(SkippedJob {
+                job: job.clone(),
+                reason,
+            }),
+        }
+    }
+
+    batch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies::get_all_companies;
+    use crate::skills::Proficiency;
+
+    #[test]
+    fn test_batch_apply_fresh_player_skips_all() {
+        let player = Player::new("Test");
+        let companies = get_all_companies();
+
+        let batch = batch_apply(&companies, &player, 0.0, None);
+
+        assert!(batch.queued.is_empty());
+        assert_eq!(batch.skipped.len(), 4);
+        assert!(batch.skipped.iter().all(|s| s.reason.starts_with("Needs")));
+    }
+
+    #[test]
+    fn test_batch_apply_queues_eligible_jobs() {
+        let mut player = Player::new("Test");
+        player.skills.get_mut("Python").unwrap().proficiency = Proficiency::Intermediate;
+        player.skills.get_mut("PyTorch").unwrap().proficiency = Proficiency::Basic;
+        let companies = get_all_companies();
+
+        let batch = batch_apply(&companies, &player, 0.7, None);
+
+        assert_eq!(batch.queued.len(), 1);
+        assert_eq!(batch.queued[0].job.title, "Junior ML Engineer");
+    }
+
+    #[test]
+    fn test_batch_apply_respects_cooldown() {
+        let mut player = Player::new("Test");
+        player.skills.get_mut("Python").unwrap().proficiency = Proficiency::Intermediate;
+        player.skills.get_mut("PyTorch").unwrap().proficiency = Proficiency::Basic;
+        player.start_interview_cooldown(1);
+        let companies = get_all_companies();
+
+        let batch = batch_apply(&companies, &player, 0.7, None);
+
+        assert!(batch.queued.is_empty());
+        assert!(batch.skipped[0].reason.contains("cooldown"));
+    }
+}
@@ -1,7 +1,21 @@
+mod application;
+mod next_tier;
+mod offer;
+mod prep;
+
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::skills::Proficiency;
 
+pub use application::{batch_apply, Application, BatchApplication, SkippedJob};
+pub use next_tier::{current_tier, next_tier_gap, TierGap, GOOD_MATCH_THRESHOLD};
+pub use offer::{MAX_OFFER_PREMIUM, OFFER_REPUTATION_CAP};
+
+/// Most stars a job's interview difficulty can rate
+pub const MAX_DIFFICULTY_STARS: u8 = 5;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillRequirement {
     pub skill_name: String,
@@ -10,6 +24,22 @@ pub struct SkillRequirement {
     pub weight: f32,
 }
 
+/// A requirement the player doesn't meet yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillGap {
+    pub skill_name: String,
+    pub current: Proficiency,
+    pub required: Proficiency,
+    pub mandatory: bool,
+}
+
+impl SkillGap {
+    /// Proficiency levels still missing
+    pub fn levels_short(&self) -> u8 {
+        (self.required as u8).saturating_sub(self.current as u8)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Job {
     pub id: u32,
@@ -25,11 +55,25 @@ pub struct Job {
 
 impl Job {
     pub fn calculate_match(&self, player_skills: &std::collections::HashMap<String, crate::player::PlayerSkill>) -> f32 {
+        self.calculate_match_with_trends(player_skills, None)
+    }
+
+    /// Match score with requirement weights scaled by industry trends
+    pub fn calculate_match_with_trends(
+        &self,
+        player_skills: &std::collections::HashMap<String, crate::player::PlayerSkill>,
+        trends: Option<&std::collections::HashMap<String, f32>>,
+    ) -> f32 {
         let mut total_weight = 0.0;
         let mut matched_weight = 0.0;
 
         for req in &self.requirements {
-            total_weight += req.weight;
+            let trend = trends
+                .and_then(|t| t.get(&req.skill_name))
+                .copied()
+                .unwrap_or(1.0);
+            let weight = req.weight * trend;
+            total_weight += weight;
             
             let proficiency = player_skills
                 .get(&req.skill_name)
@@ -37,10 +81,10 @@ impl Job {
                 .unwrap_or(Proficiency::None);
 
             if proficiency >= req.min_proficiency {
-                matched_weight += req.weight;
+                matched_weight += weight;
             } else if proficiency != Proficiency::None {
                 let ratio = (proficiency as i32 as f32) / (req.min_proficiency as i32 as f32);
-                matched_weight += req.weight * ratio * 0.5;
+                matched_weight += weight * ratio * 0.5;
             }
         }
 
@@ -51,6 +95,46 @@ impl Job {
         }
     }
 
+    /// Requirements the player falls short on, mandatory and widest gaps first
+    pub fn skill_gaps(&self, player_skills: &std::collections::HashMap<String, crate::player::PlayerSkill>) -> Vec<SkillGap> {
+        let mut gaps: Vec<SkillGap> = self
+            .requirements
+            .iter()
+            .filter_map(|req| {
+                let current = player_skills
+                    .get(&req.skill_name)
+                    .map(|s| s.proficiency)
+                    .unwrap_or(Proficiency::None);
+                (current < req.min_proficiency).then(|| SkillGap {
+                    skill_name: req.skill_name.clone(),
+                    current,
+                    required: req.min_proficiency,
+                    mandatory: req.mandatory,
+                })
+            })
+            .collect();
+        gaps.sort_by_key(|g| (std::cmp::Reverse(g.mandatory), std::cmp::Reverse(g.levels_short())));
+        gaps
+    }
+
+    /// Final salary offered, the band midpoint scaled by the company tier
+    pub fn offer_salary(&self, tier: CompanyTier) -> u32 {
+        let midpoint = (self.salary_min + self.salary_max) / 2;
+        (midpoint as f32 * tier.salary_multiplier()).round() as u32
+    }
+
+    /// Interview difficulty as a 1-5 star rating, the job's own difficulty
+    /// plus the company tier's modifier
+    pub fn interview_difficulty(&self, tier: CompanyTier) -> u8 {
+        (self.difficulty + tier.difficulty_modifier()).clamp(1, MAX_DIFFICULTY_STARS)
+    }
+
+    /// Star rating drawn as text, e.g. "***--" for 3 of 5
+    pub fn difficulty_stars(&self, tier: CompanyTier) -> String {
+        let stars = self.interview_difficulty(tier) as usize;
+        format!("{}{}", "*".repeat(stars), "-".repeat(MAX_DIFFICULTY_STARS as usize - stars))
+    }
+
     pub fn display_salary(&self) -> String {
         format!("${} - ${}/year", self.salary_min, self.salary_max)
     }
@@ -62,9 +146,11 @@ pub struct Company {
     pub description: String,
     pub tier: CompanyTier,
     pub open_positions: Vec<Job>,
+    /// Extra interview weight by skill, giving the company's interviews their flavor
+    pub emphasis: HashMap<String, f32>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum CompanyTier {
     Startup,
     MidSize,
@@ -91,6 +177,16 @@ impl CompanyTier {
         }
     }
 
+    /// Career level at which companies of this tier show up
+    pub fn min_career_level(&self) -> u32 {
+        match self {
+            CompanyTier::Startup => 0,
+            CompanyTier::MidSize => 2,
+            CompanyTier::BigTech => 5,
+            CompanyTier::Faang => 9,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             CompanyTier::Startup => "Startup",
@@ -102,10 +198,41 @@ impl CompanyTier {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use crate::player::Player;
 
+    /// A difficulty-1 job with no requirements, for tests to customise
+    pub(crate) fn test_job() -> Job {
+        Job {
+            id: 1,
+            title: "Test Job".to_string(),
+            company: "Test Co".to_string(),
+            salary_min: 100000,
+            salary_max: 150000,
+            requirements: vec![],
+            min_experience_days: 0,
+            description: "A test job".to_string(),
+            difficulty: 1,
+        }
+    }
+
+    /// `test_job` with a mandatory, weight-1 requirement per (skill, level)
+    pub(crate) fn test_job_requiring(skills: &[(&str, Proficiency)]) -> Job {
+        Job {
+            requirements: skills
+                .iter()
+                .map(|&(skill, min_proficiency)| SkillRequirement {
+                    skill_name: skill.to_string(),
+                    min_proficiency,
+                    mandatory: true,
+                    weight: 1.0,
+                })
+                .collect(),
+            ..test_job()
+        }
+    }
+
     #[test]
     fn test_job_match_fresh_player() {
         let player = Player::new("Test");
@@ -132,6 +259,18 @@ mod tests {
         assert!(score < 0.5);
     }
 
+    #[test]
+    fn test_trend_raises_match_for_trending_skill() {
+        let mut player = Player::new("Test");
+        player.skills.get_mut("Python").unwrap().proficiency = Proficiency::Advanced;
+        let job = test_job_requiring(&[("Python", Proficiency::Advanced), ("RAG", Proficiency::Advanced)]);
+        let trends = std::collections::HashMap::from([("Python".to_string(), 1.5)]);
+
+        let base = job.calculate_match(&player.skills);
+        let trending = job.calculate_match_with_trends(&player.skills, Some(&trends));
+        assert!(trending > base);
+    }
+
     #[test]
     fn test_job_display_salary() {
         let job = Job {
@@ -149,6 +288,65 @@ mod tests {
         assert_eq!(job.display_salary(), "$100000 - $150000/year");
     }
 
+    #[test]
+    fn test_skill_gaps_mandatory_first() {
+        let mut player = Player::new("Test");
+        player.skills.get_mut("SQL").unwrap().proficiency = Proficiency::Advanced;
+        let job = Job {
+            requirements: vec![
+                SkillRequirement {
+                    skill_name: "Statistics".to_string(),
+                    min_proficiency: Proficiency::Advanced,
+                    mandatory: false,
+                    weight: 1.0,
+                },
+                SkillRequirement {
+                    skill_name: "Python".to_string(),
+                    min_proficiency: Proficiency::Basic,
+                    mandatory: true,
+                    weight: 1.0,
+                },
+                SkillRequirement {
+                    skill_name: "SQL".to_string(),
+                    min_proficiency: Proficiency::Intermediate,
+                    mandatory: true,
+                    weight: 1.0,
+                },
+            ],
+            ..test_job()
+        };
+
+        let gaps = job.skill_gaps(&player.skills);
+        assert_eq!(gaps.len(), 2);
+        assert_eq!(gaps[0].skill_name, "Python");
+        assert_eq!(gaps[1].skill_name, "Statistics");
+        assert_eq!(gaps[1].levels_short(), 3);
+    }
+
+    #[test]
+    fn test_faang_offer_exceeds_midpoint() {
+        let job = test_job();
+
+        assert!(job.offer_salary(CompanyTier::Faang) > 125000);
+        assert_eq!(job.offer_salary(CompanyTier::MidSize), 125000);
+        assert!(job.offer_salary(CompanyTier::Startup) < 125000);
+    }
+
+    #[test]
+    fn test_faang_hard_job_rates_above_startup_easy_job() {
+        let mut job = test_job();
+        let startup = job.interview_difficulty(CompanyTier::Startup);
+        job.difficulty = 4;
+        let faang = job.interview_difficulty(CompanyTier::Faang);
+
+        assert!(faang > startup);
+        assert_eq!(startup, 1);
+        assert_eq!(faang, MAX_DIFFICULTY_STARS);
+        assert_eq!(job.difficulty_stars(CompanyTier::Faang), "*****");
+        job.difficulty = 1;
+        assert_eq!(job.difficulty_stars(CompanyTier::MidSize), "**---");
+    }
+
     #[test]
     fn test_company_tier_salary_multiplier() {
         assert!((CompanyTier::Startup.salary_multiplier() - 0.8).abs() < 0.01);
@@ -0,0 +1,132 @@
+//! Next Tier Goals
+//!
+//! What it takes to move up a company tier: the skill levels needed for a
+//! good match on the easiest job one tier above the best the player
+//! currently matches well.
+
+use std::collections::HashMap;
+
+use super::{Company, CompanyTier, Job, SkillGap};
+use crate::player::PlayerSkill;
+
+/// Match score the job board labels a good match
+pub const GOOD_MATCH_THRESHOLD: f32 = 0.7;
+
+/// The gap to a good match on the easiest job of the next tier
+#[derive(Debug, Clone)]
+pub struct TierGap {
+    pub tier: CompanyTier,
+    pub job: Job,
+    /// Requirements to raise, in the order they should be closed
+    pub gaps: Vec<SkillGap>,
+}
+
+impl TierGap {
+    /// Proficiency levels still missing across all gaps
+    pub fn levels_short(&self) -> u32 {
+        self.gaps.iter().map(|g| g.levels_short() as u32).sum()
+    }
+}
+
+impl CompanyTier {
+    /// The tier above this one, if any
+    pub fn next(&self) -> Option<CompanyTier> {
+        match self {
+            CompanyTier::Startup => Some(CompanyTier::MidSize),
+            CompanyTier::MidSize => Some(CompanyTier::BigTech),
+            CompanyTier::BigTech => Some(CompanyTier::Faang),
+            CompanyTier::Faang => None,
+        }
+    }
+}
+
+/// Gaps to close, widest mandatory ones first, until the match is good
+///
+/// Closing every gap always reaches a full match, but a good match often
+/// needs only some of them.
+fn gaps_to_good_match(job: &Job, skills: &HashMap<String, PlayerSkill>) -> Vec<SkillGap> {
+    let mut improved = skills.clone();
+    let mut needed = Vec::new();
+    for gap in job.skill_gaps(skills) {
+        if job.calculate_match(&improved) >= GOOD_MATCH_THRESHOLD {
+            break;
+        }
+        if let Some(skill) = improved.get_mut(&gap.skill_name) {
+            skill.proficiency = gap.required;
+        }
+        needed.push(gap);
+    }
+    needed
+}
+
+/// Highest tier with a job the player is a good match for
+pub fn current_tier(companies: &[Company], skills: &HashMap<String, PlayerSkill>) -> Option<CompanyTier> {
+    companies
+        .iter()
+        .filter(|c| c.open_positions.iter().any(|job| job.calculate_match(skills) >= GOOD_MATCH_THRESHOLD))
+        .map(|c| c.tier)
+        .max_by_key(|tier| tier.difficulty_modifier())
+}
+
+/// What it takes to reach a good match in the next tier up
+///
+/// Starts from Startup when the player matches nothing yet. Returns `None`
+/// at the top tier, or if the next tier has no open positions.
+pub fn next_tier_gap(companies: &[Company], skills: &HashMap<String, PlayerSkill>) -> Option<TierGap> {
+    let tier = match current_tier(companies, skills) {
+        Some(tier) => tier.next()?,
+        None => CompanyTier::Startup,
+    };
+    companies
+        .iter()
+        .filter(|c| c.tier == tier)
+        .flat_map(|c| &c.open_positions)
+        .map(|job| TierGap {
+            tier,
+            job: job.clone(),
+            gaps: gaps_to_good_match(job, skills),
+        })
+        .min_by_key(|gap| (gap.levels_short(), gap.job.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies::get_all_companies;
+    use crate::player::Player;
+
+    #[test]
+    fn test_startup_ready_player_has_gaps_toward_mid_size() {
+        let companies = get_all_companies();
+        let mut player = Player::new("Test");
+        for job in companies.iter().filter(|c| c.tier == CompanyTier::Startup).flat_map(|c| &c.open_positions) {
+            for req in &job.requirements {
+                let skill = player.skills.get_mut(&req.skill_name).unwrap();
+                skill.proficiency = skill.proficiency.max(req.min_proficiency);
+            }
+        }
+        assert!(companies
+            .iter()
+            .filter(|c| c.tier == CompanyTier::Startup)
+            .flat_map(|c| &c.open_positions)
+            .all(|job| job.calculate_match(&player.skills) >= GOOD_MATCH_THRESHOLD));
+
+        let gap = next_tier_gap(&companies, &player.skills).expect("a tier above startups");
+
+        assert_eq!(gap.tier, CompanyTier::MidSize);
+        assert!(!gap.gaps.is_empty());
+        assert!(gap.levels_short() > 0);
+
+        let mut closed = player.skills.clone();
+        for g in &gap.gaps {
+            closed.get_mut(&g.skill_name).unwrap().proficiency = g.required;
+        }
+        assert!(gap.job.calculate_match(&closed) >= GOOD_MATCH_THRESHOLD);
+    }
+
+    #[test]
+    fn test_fresh_player_aims_at_startups() {
+        let gap = next_tier_gap(&get_all_companies(), &Player::new("Test").skills).unwrap();
+        assert_eq!(gap.tier, CompanyTier::Startup);
+    }
+}
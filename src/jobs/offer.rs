@@ -0,0 +1,92 @@
+//! Salary Offers
+//!
+//! How much a company offers a particular candidate. Match quality, skill
+//! depth and reputation place the offer within the job's salary band.
+//! Exceptional candidates can negotiate past the top of the band, up to a
+//! bounded premium.
+
+use super::{CompanyTier, Job};
+use crate::player::Player;
+use crate::skills::Proficiency;
+
+/// Most an offer can exceed `salary_max`, as a share of it
+pub const MAX_OFFER_PREMIUM: f32 = 0.15;
+/// Reputation at which it counts fully toward the offer
+pub const OFFER_REPUTATION_CAP: u32 = 50;
+
+/// Weight of the job match in the candidate score
+const MATCH_WEIGHT: f32 = 0.5;
+/// Weight of skill depth on the job's requirements
+const SKILL_WEIGHT: f32 = 0.3;
+/// Weight of reputation
+const REPUTATION_WEIGHT: f32 = 0.2;
+
+impl Job {
+    /// How strong the player is as a candidate for this job, from 0 to 1
+    pub fn candidate_score(&self, player: &Player) -> f32 {
+        let match_quality = self.calculate_match(&player.skills);
+        let skill_depth = if self.requirements.is_empty() {
+            0.0
+        } else {
+            let levels: u32 = self
+                .requirements
+                .iter()
+                .map(|req| player.get_skill_proficiency(&req.skill_name) as u32)
+                .sum();
+            levels as f32 / (self.requirements.len() as u32 * Proficiency::Expert as u32) as f32
+        };
+        let reputation = player.reputation.min(OFFER_REPUTATION_CAP) as f32 / OFFER_REPUTATION_CAP as f32;
+        (MATCH_WEIGHT * match_quality + SKILL_WEIGHT * skill_depth + REPUTATION_WEIGHT * reputation).clamp(0.0, 1.0)
+    }
+
+    /// Salary offered to this player, scaled by the company tier
+    ///
+    /// Runs from `salary_min` for the weakest candidate to `salary_max`
+    /// plus `MAX_OFFER_PREMIUM` for a maxed-out, well-known one. The score
+    /// is squared, so only strong candidates get past the band's midpoint.
+    pub fn offer_for(&self, player: &Player, tier: CompanyTier) -> u32 {
+        let score = self.candidate_score(player);
+        let ceiling = self.salary_max as f32 * (1.0 + MAX_OFFER_PREMIUM);
+        let base = self.salary_min as f32 + (ceiling - self.salary_min as f32) * score * score;
+        (base * tier.salary_multiplier()).round() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jobs::tests::test_job_requiring;
+
+    fn job() -> Job {
+        test_job_requiring(&[("Python", Proficiency::Intermediate), ("PyTorch", Proficiency::Intermediate)])
+    }
+
+    #[test]
+    fn test_star_candidate_beats_the_midpoint_and_can_exceed_the_band() {
+        let job = job();
+        let mut star = Player::builder("Star")
+            .skill("Python", Proficiency::Expert)
+            .skill("PyTorch", Proficiency::Expert)
+            .build();
+        star.reputation = OFFER_REPUTATION_CAP * 2;
+
+        let offer = job.offer_for(&star, CompanyTier::MidSize);
+        assert!(offer > job.offer_salary(CompanyTier::MidSize));
+        assert!(offer > job.salary_max);
+        assert!(offer <= (job.salary_max as f32 * (1.0 + MAX_OFFER_PREMIUM)).round() as u32);
+    }
+
+    #[test]
+    fn test_borderline_candidate_lands_below_the_midpoint() {
+        let job = job();
+        let borderline = Player::builder("Borderline")
+            .skill("Python", Proficiency::Intermediate)
+            .skill("PyTorch", Proficiency::Basic)
+            .build();
+
+        let offer = job.offer_for(&borderline, CompanyTier::MidSize);
+        assert!(offer < job.offer_salary(CompanyTier::MidSize));
+        assert!(offer >= job.salary_min);
+        assert!(job.offer_for(&borderline, CompanyTier::Faang) > offer);
+    }
+}
@@ -0,0 +1,92 @@
+//! Preparation Estimate
+//!
+//! Rough number of study days a player needs before they qualify for a
+//! job: the XP to lift every mandatory requirement to its level, turned
+//! into library sessions and packed into days by the energy each session
+//! costs. Buffs, venues and study groups are left out, so the estimate
+//! errs on the long side.
+
+use super::Job;
+use crate::player::{xp_to_next_level, Player, PlayerSkill, STUDY_SESSION_HOURS, STUDY_XP_PER_HOUR};
+use crate::skills::Proficiency;
+
+/// XP one library study session earns without any bonuses
+const SESSION_XP: u32 = STUDY_SESSION_HOURS * STUDY_XP_PER_HOUR;
+
+/// XP still needed to bring a skill up to `target`
+fn xp_to_reach(skill: &PlayerSkill, target: Proficiency) -> u32 {
+    let mut xp = 0;
+    let mut level = skill.proficiency;
+    while level < target {
+        xp += xp_to_next_level(skill.skill.difficulty, level);
+        match level.next() {
+            Some(next) => level = next,
+            None => break,
+        }
+    }
+    xp.saturating_sub(skill.experience_points)
+}
+
+impl Job {
+    /// Study sessions and their total energy to close every mandatory gap
+    fn prep_sessions_and_energy(&self, player: &Player) -> (u32, u32) {
+        self.skill_gaps(&player.skills)
+            .iter()
+            .filter(|gap| gap.mandatory)
+            .filter_map(|gap| player.skills.get(&gap.skill_name).map(|skill| (skill, gap.required)))
+            .map(|(skill, required)| {
+                let sessions = xp_to_reach(skill, required).div_ceil(SESSION_XP);
+                (sessions, sessions * skill.study_energy_cost(STUDY_SESSION_HOURS))
+            })
+            .fold((0, 0), |(sessions, energy), (s, e)| (sessions + s, energy + e))
+    }
+
+    /// Library study sessions to close every mandatory gap
+    pub fn estimated_prep_sessions(&self, player: &Player) -> u32 {
+        self.prep_sessions_and_energy(player).0
+    }
+
+    /// Days of study to close every mandatory gap, starting each day at
+    /// full energy; 0 once the player qualifies
+    pub fn estimated_prep_days(&self, player: &Player) -> u32 {
+        let (_, energy) = self.prep_sessions_and_energy(player);
+        energy.div_ceil(player.max_energy.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies::get_all_companies;
+    use crate::jobs::CompanyTier;
+
+    #[test]
+    fn test_fresh_player_needs_longer_for_faang_than_a_startup() {
+        let companies = get_all_companies();
+        let player = Player::new("Test");
+        let prep_days = |tier: CompanyTier| {
+            companies
+                .iter()
+                .filter(|c| c.tier == tier)
+                .flat_map(|c| &c.open_positions)
+                .map(|job| job.estimated_prep_days(&player))
+                .min()
+                .unwrap()
+        };
+
+        let startup = prep_days(CompanyTier::Startup);
+        assert!(startup > 0);
+        assert!(prep_days(CompanyTier::Faang) > startup);
+    }
+
+    #[test]
+    fn test_qualified_player_needs_no_prep() {
+        let job = &get_all_companies()[0].open_positions[0];
+        let mut player = Player::new("Test");
+        for req in &job.requirements {
+            player.set_skill(&req.skill_name, req.min_proficiency).unwrap();
+        }
+        assert_eq!(job.estimated_prep_sessions(&player), 0);
+        assert_eq!(job.estimated_prep_days(&player), 0);
+    }
+}
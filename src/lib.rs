@@ -1,12 +1,25 @@
+//! AI Engineer Career RPG
+//!
+//! The simulation modules (`player`, `skills`, `jobs`, `companies`,
+//! `interview`, `engine`, `llm`, `game`) have no window dependency.
+//! Rendering and input live in `world`, `graphics` and `ui`, which are
+//! only built with the default `gui` feature. The `server` feature adds a
+//! socket server for driving the simulation from other programs.
+
 pub mod companies;
 pub mod engine;
 pub mod game;
+#[cfg(feature = "gui")]
 pub mod graphics;
 pub mod interview;
 pub mod jobs;
 pub mod llm;
 pub mod player;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod skills;
 pub mod testing;
+#[cfg(feature = "gui")]
 pub mod ui;
+#[cfg(feature = "gui")]
 pub mod world;
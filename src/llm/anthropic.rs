@@ -9,7 +9,7 @@
 //! - `ANTHROPIC_BASE_URL`: API endpoint (e.g., https://api.z.ai/api/anthropic)
 //!
 //! # Example
-//! ```rust
+//! ```rust,ignore
 //! use crate::llm::{LlmProvider, LlmMessage};
 //! use crate::llm::anthropic::AnthropicProvider;
 //!
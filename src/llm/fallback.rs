@@ -0,0 +1,116 @@
+//! Fallback Provider Chain
+//!
+//! Wraps several providers and tries them in order, returning the first
+//! successful completion. Lets the config put a local model first and a
+//! cloud model (or the mock) behind it.
+//!
+//! # Example
+//! ```rust,ignore
+//! use crate::llm::{FallbackProvider, MockProvider, Provider};
+//!
+//! let chain = FallbackProvider::new(vec![
+//!     Provider::Mock(MockProvider::failing("local", "offline")),
+//!     Provider::Mock(MockProvider::new("Hello from the backup")),
+//! ]);
+//! let response = chain.complete("system", vec![LlmMessage::user("Hi")]).await?;
+//! assert_eq!(response, "Hello from the backup");
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+
+use anyhow::{anyhow, Result};
+
+use super::provider::{LlmMessage, LlmProvider, Provider};
+
+/// Provider that falls through an ordered list until one succeeds
+#[derive(Clone)]
+pub struct FallbackProvider {
+    name: String,
+    providers: Vec<Provider>,
+    /// Providers left out of the chain, each with why it couldn't be built
+    skipped: Vec<String>,
+}
+
+impl FallbackProvider {
+    pub fn new(providers: Vec<Provider>) -> Self {
+        let names: Vec<&str> = providers.iter().map(|p| p.name()).collect();
+        Self {
+            name: format!("fallback({})", names.join(" -> ")),
+            providers,
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Note providers that were configured but couldn't be built
+    pub fn with_skipped(mut self, skipped: Vec<String>) -> Self {
+        self.skipped = skipped;
+        self
+    }
+
+    pub fn providers(&self) -> &[Provider] {
+        &self.providers
+    }
+
+    pub fn skipped(&self) -> &[String] {
+        &self.skipped
+    }
+}
+
+impl LlmProvider for FallbackProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete<'a>(
+        &'a self,
+        system: &'a str,
+        messages: Vec<LlmMessage>,
+    ) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut failures = Vec::new();
+            for provider in &self.providers {
+                match provider.complete(system, messages.clone()).await {
+                    Ok(response) => return Ok(response),
+                    Err(e) => failures.push(format!("{}: {:#}", provider.name(), e)),
+                }
+            }
+            if failures.is_empty() {
+                return Err(anyhow!("No providers configured"));
+            }
+            Err(anyhow!("All providers failed in {}: {}", self.name, failures.join("; ")))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::MockProvider;
+
+    #[tokio::test]
+    async fn test_falls_through_to_second_provider() {
+        let local = MockProvider::failing("local", "connection refused");
+        let backup = MockProvider::with_name("backup", "Backup reply");
+        let chain = FallbackProvider::new(vec![Provider::Mock(local.clone()), Provider::Mock(backup.clone())]);
+
+        let response = chain.complete("system", vec![LlmMessage::user("hi")]).await.unwrap();
+
+        assert_eq!(response, "Backup reply");
+        assert_eq!(local.get_requests().len(), 1);
+        assert_eq!(backup.get_requests().len(), 1);
+        assert_eq!(chain.name(), "fallback(local -> backup)");
+    }
+
+    #[tokio::test]
+    async fn test_all_failing_returns_error() {
+        let chain = FallbackProvider::new(vec![
+            Provider::Mock(MockProvider::failing("a", "down")),
+            Provider::Mock(MockProvider::failing("b", "also down")),
+        ]);
+        let err = chain.complete("system", vec![LlmMessage::user("hi")]).await.unwrap_err();
+        let message = format!("{:#}", err);
+        assert!(message.contains("a: down"));
+        assert!(message.contains("b: also down"));
+    }
+}
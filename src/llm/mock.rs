@@ -4,7 +4,7 @@
 //! Useful for unit testing without making real API calls.
 //!
 //! # Example
-//! ```rust
+//! ```rust,ignore
 //! use crate::llm::mock::MockProvider;
 //! use crate::llm::{LlmProvider, LlmMessage};
 //!
@@ -16,10 +16,13 @@
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 use super::provider::LlmMessage;
 
+/// A recorded request: (system prompt, messages)
+type RecordedRequest = (String, Vec<LlmMessage>);
+
 /// Mock provider that returns predefined responses
 ///
 /// Thread-safe for use in async tests.
@@ -30,17 +33,15 @@ pub struct MockProvider {
     /// Response to return for any completion
     response: Arc<Mutex<String>>,
     /// Optional: track all requests made (for assertions)
-    requests: Arc<Mutex<Vec<(String, Vec<LlmMessage>)>>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    /// When set, every completion fails with this message
+    error: Arc<Mutex<Option<String>>>,
 }
 
 impl MockProvider {
     /// Create a mock that returns a fixed response
     pub fn new(response: impl Into<String>) -> Self {
-        Self {
-            name: "mock".to_string(),
-            response: Arc::new(Mutex::new(response.into())),
-            requests: Arc::new(Mutex::new(Vec::new())),
-        }
+        Self::with_name("mock", response)
     }
     
     /// Create a mock with a custom name
@@ -49,8 +50,21 @@ impl MockProvider {
             name: name.into(),
             response: Arc::new(Mutex::new(response.into())),
             requests: Arc::new(Mutex::new(Vec::new())),
+            error: Arc::new(Mutex::new(None)),
         }
     }
+
+    /// Create a mock whose completions always fail (for testing error paths)
+    pub fn failing(name: impl Into<String>, error: impl Into<String>) -> Self {
+        let mock = Self::with_name(name, "");
+        mock.set_error(Some(error.into()));
+        mock
+    }
+
+    /// Make completions fail with `error`, or succeed again with `None`
+    pub fn set_error(&self, error: Option<String>) {
+        *self.error.lock().unwrap() = error;
+    }
     
     /// Update the response (for testing different scenarios)
     pub fn set_response(&self, response: impl Into<String>) {
@@ -58,7 +72,7 @@ impl MockProvider {
     }
     
     /// Get all requests made to this mock (for assertions)
-    pub fn get_requests(&self) -> Vec<(String, Vec<LlmMessage>)> {
+    pub fn get_requests(&self) -> Vec<RecordedRequest> {
         self.requests.lock().unwrap().clone()
     }
     
@@ -79,6 +93,10 @@ impl super::provider::LlmProvider for MockProvider {
         Box::pin(async move {
             // Track the request
             self.requests.lock().unwrap().push((system.to_string(), messages));
+
+            if let Some(error) = self.error.lock().unwrap().clone() {
+                return Err(anyhow!(error));
+            }
             
             // Return the predefined response
             Ok(self.response.lock().unwrap().clone())
@@ -109,6 +127,14 @@ mod tests {
         assert_eq!(requests[0].1.len(), 1);
     }
     
+    #[tokio::test]
+    async fn test_failing_mock_errors_but_records_request() {
+        let mock = MockProvider::failing("down", "connection refused");
+        let err = mock.complete("", vec![LlmMessage::user("test")]).await.unwrap_err();
+        assert_eq!(err.to_string(), "connection refused");
+        assert_eq!(mock.get_requests().len(), 1);
+    }
+
     #[tokio::test]
     async fn test_mock_can_update_response() {
         let mock = MockProvider::new("first");
@@ -20,17 +20,21 @@
 //!           (test) (future)
 //! ```
 //!
+//! `FallbackProvider` chains several providers and returns the first
+//! successful completion (see `LlmConfig::fallbacks`).
+//!
 //! # Testing
 //! - **Unit tests**: Use `MockProvider` (no API calls)
 //! - **Integration tests**: Use `AnthropicProvider` with real API
 //!
 //! # Example
-//! ```rust
+//! ```rust,ignore
 //! use crate::llm::{LlmProvider, LlmMessage, create_provider, LlmConfig};
 //!
 //! let config = LlmConfig {
 //!     provider: "anthropic".into(),
 //!     model: "glm-4.7".into(),
+//!     fallbacks: vec!["mock".into()],
 //! };
 //! let provider = create_provider(&config)?;
 //! let response = provider.complete(
@@ -42,10 +46,12 @@
 pub mod provider;
 pub mod anthropic;
 pub mod mock;
+pub mod fallback;
 
-pub use provider::{LlmProvider, LlmMessage, LlmConfig, Provider, create_provider};
+pub use provider::{LlmProvider, LlmMessage, LlmConfig, Provider, create_provider, SUPPORTED_PROVIDERS};
 pub use anthropic::AnthropicProvider;
 pub use mock::MockProvider;
+pub use fallback::FallbackProvider;
 
 #[cfg(test)]
 mod tests {
@@ -77,9 +83,25 @@ mod tests {
         let config = LlmConfig {
             provider: "mock".into(),
             model: "test".into(),
+            fallbacks: Vec::new(),
         };
         let provider = create_provider(&config).unwrap();
         let result = provider.complete("system", vec![LlmMessage::user("test")]).await.unwrap();
         assert_eq!(result, "Mock response");
     }
+
+    #[tokio::test]
+    async fn test_create_provider_builds_fallback_chain() {
+        let config = LlmConfig {
+            provider: "mock".into(),
+            model: "test".into(),
+            fallbacks: vec!["mock".into()],
+        };
+        let provider = create_provider(&config).unwrap();
+        assert!(matches!(provider, Provider::Fallback(_)));
+        assert_eq!(provider.name(), "fallback(mock -> mock)");
+
+        let bad = LlmConfig { fallbacks: vec!["ollama".into()], ..config };
+        assert!(create_provider(&bad).is_err());
+    }
 }
@@ -90,6 +90,8 @@ pub enum Provider {
     Anthropic(crate::llm::anthropic::AnthropicProvider),
     /// Mock provider for testing
     Mock(crate::llm::mock::MockProvider),
+    /// Several providers tried in order
+    Fallback(crate::llm::fallback::FallbackProvider),
 }
 
 impl LlmProvider for Provider {
@@ -97,6 +99,7 @@ impl LlmProvider for Provider {
         match self {
             Self::Anthropic(p) => p.name(),
             Self::Mock(p) => p.name(),
+            Self::Fallback(p) => p.name(),
         }
     }
 
@@ -108,6 +111,7 @@ impl LlmProvider for Provider {
         match self {
             Self::Anthropic(p) => p.complete(system, messages),
             Self::Mock(p) => p.complete(system, messages),
+            Self::Fallback(p) => p.complete(system, messages),
         }
     }
 }
@@ -119,29 +123,69 @@ pub struct LlmConfig {
     pub provider: String,
     /// Model identifier (provider-specific)
     pub model: String,
+    /// Providers to try, in order, when `provider` fails
+    pub fallbacks: Vec<String>,
 }
 
+/// Provider names accepted by `create_provider`
+pub const SUPPORTED_PROVIDERS: &[&str] = &["anthropic", "mock"];
+
 /// Create an LLM provider based on configuration
 ///
+/// With `fallbacks` set, builds a `FallbackProvider` over `provider`
+/// followed by each fallback. Providers in the chain that can't be built
+/// (e.g. missing credentials) are skipped as long as one remains, and
+/// listed in `FallbackProvider::skipped`.
+///
 /// # Currently Supported Providers
 /// - `"anthropic"`: Anthropic/Z.ai API
 /// - `"mock"`: Mock provider for testing
 ///
 /// # Errors
-/// Returns an error if the provider name is unknown
+/// Returns an error if any provider name is unknown, or if no provider
+/// in the chain could be built
 pub fn create_provider(config: &LlmConfig) -> Result<Provider> {
-    match config.provider.as_str() {
+    if config.fallbacks.is_empty() {
+        return create_single(&config.provider, &config.model);
+    }
+
+    let names: Vec<&String> = std::iter::once(&config.provider).chain(&config.fallbacks).collect();
+    if let Some(unknown) = names.iter().find(|n| !SUPPORTED_PROVIDERS.contains(&n.as_str())) {
+        return Err(unknown_provider(unknown));
+    }
+    let mut providers = Vec::new();
+    let mut skipped = Vec::new();
+    for name in names {
+        match create_single(name, &config.model) {
+            Ok(provider) => providers.push(provider),
+            Err(e) => skipped.push(format!("{}: {:#}", name, e)),
+        }
+    }
+    if providers.is_empty() {
+        return Err(anyhow!("No provider in the fallback chain could be created ({})", skipped.join("; ")));
+    }
+    let chain = crate::llm::fallback::FallbackProvider::new(providers).with_skipped(skipped);
+    Ok(Provider::Fallback(chain))
+}
+
+fn unknown_provider(name: &str) -> anyhow::Error {
+    anyhow!(
+        "Unknown LLM provider: {}. Supported: {}",
+        name,
+        SUPPORTED_PROVIDERS.join(", ")
+    )
+}
+
+fn create_single(name: &str, model: &str) -> Result<Provider> {
+    match name {
         "anthropic" => {
-            let provider = crate::llm::anthropic::AnthropicProvider::new(&config.model)?;
+            let provider = crate::llm::anthropic::AnthropicProvider::new(model)?;
             Ok(Provider::Anthropic(provider))
         }
         "mock" => {
             let provider = crate::llm::mock::MockProvider::new("Mock response");
             Ok(Provider::Mock(provider))
         }
-        _ => Err(anyhow!(
-            "Unknown LLM provider: {}. Supported: anthropic, mock",
-            config.provider
-        )),
+        _ => Err(unknown_provider(name)),
     }
 }
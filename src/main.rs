@@ -1,22 +1,50 @@
-mod companies;
-mod engine;
-mod game;
-mod graphics;
-mod interview;
-mod jobs;
-mod llm;
-mod player;
-mod skills;
-mod ui;
-mod world;
+use std::collections::VecDeque;
+use std::path::Path;
 
+use ai_career_rpg::{companies, graphics, skills, world};
 use macroquad::prelude::*;
-use macroquad::rand::ChooseRandom;
-use game::{GameScreen, GameState};
-use world::{WorldPlayer, Camera, GameMap, BuildingType, Npc, get_npcs};
-use ui::{draw_hud, draw_interaction_hint, draw_controls_hint};
-use jobs::Job;
-use graphics::{init_fonts, draw_text_crisp, use_custom_font, is_custom_font_enabled};
+use ai_career_rpg::game::{
+    self as game, ActionResult, AutoStudy, AutoStudyStep, DayLog, DiskSink, GameScreen, GameState, InterviewOutcome, SaveFormat, SaveHeader,
+    ShutdownFlush, ShutdownHook, TimeCategory, AUTO_STUDY_SESSIONS, HOME_REST_HOURS, SAVE_DIR, SAVE_SLOTS,
+};
+use world::{WorldPlayer, Camera, FixedTimestep, GameMap, BuildingType, Npc, NpcType, npcs_for_map, COMMUTE_HOURS};
+use ai_career_rpg::ui::{
+    debug_overlay_lines, draw_building_labels, draw_controls_hint, draw_debug_overlay, draw_hud, draw_interaction_hint,
+    apply_skill_profile, draw_line_chart, draw_map_legend, DebugInfo, HudSettings, Panel, RepeatKey, Typewriter, DEBUG_SKILL_PROFILES,
+    DIALOG_CHARS_PER_SEC, MacroquadInput, clamp_selection, step_selection, visible_lines, wrap_text_crisp,
+};
+use ai_career_rpg::engine::{AdviceEngine, AssistConfig, ExplainEngine, GameConfig, InterviewConfig};
+use ai_career_rpg::game::GameRng;
+use ai_career_rpg::testing::{InputSnapshot, InputSource};
+use ai_career_rpg::interview::adaptive::AdaptiveQuiz;
+use ai_career_rpg::interview::questions::InterviewQuestionDb;
+use ai_career_rpg::interview::session::{InterviewSession, RoundOutcome};
+use ai_career_rpg::interview::Interview;
+use ai_career_rpg::interview::quiz::{
+    eliminate_wrong_option, marked_quiz_score, Confidence, DifficultyGauge, QuizAnswer, QuizQuestion, FREE_HINTS_PER_INTERVIEW,
+    HINT_REPUTATION_COST,
+};
+use ai_career_rpg::jobs::{self, Application, Company, CompanyTier, Job};
+use ai_career_rpg::player::{
+    CoffeeMenu, NameInput, PartTimeJob, Player, PlayerSkill, PlayerBuilder, StudyVenue, CERT_EXAM_FEE, CERT_EXAM_HOURS,
+    DEBUG_START_ENV, INTERVIEW_LENGTH_HOURS, RESEARCH_HOURS, RESPEC_COST,
+    RESPEC_REFUND_FRACTION, RESPEC_REPUTATION_PENALTY, STUDY_GROUP_COST, STUDY_SESSION_HOURS, WARM_UP_ENERGY,
+    WARM_UP_HOURS};
+use graphics::{init_fonts, draw_text_crisp, use_custom_font, is_custom_font_enabled, AnimClock};
+
+const RESUME_PATH: &str = "resume.json";
+/// Real seconds between auto-study sessions, so the run is visible and cancellable
+const AUTO_STUDY_STEP_SECS: f32 = 0.5;
+/// Spacing between wrapped lines of dialog text
+const DIALOG_LINE_HEIGHT: f32 = 24.0;
+/// Events visible at once on the event log screen
+const EVENT_LOG_ROWS: usize = 14;
+/// Minimum match score for "apply to all matching jobs"
+const BATCH_APPLY_THRESHOLD: f32 = 0.7;
+/// Dialog choice that takes the offer just made
+const ACCEPT_OFFER_CHOICE: &str = "Accept offer";
+/// Only choice on the dialog between interview rounds
+const NEXT_ROUND_CHOICE: &str = "Next round";
 
 fn window_conf() -> Conf {
     Conf {
@@ -36,96 +64,284 @@ pub struct Dialog {
     pub choices: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
-struct QuizQuestion {
-    question: String,
-    options: Vec<String>,
-    correct_idx: usize,
-}
-
 struct InterviewState {
     job: Job,
+    /// Hiring company, whose emphasis shapes the technical quiz
+    company: Option<Company>,
+    tier: CompanyTier,
+    /// Rounds, retries and the hiring decision
+    session: InterviewSession,
+    /// Picks each next question of the current round based on how the previous ones went
+    quiz: AdaptiveQuiz,
+    rng: GameRng,
+    /// Questions asked so far; the last one is the current question
     questions: Vec<QuizQuestion>,
     current_question: usize,
-    score: u32,
+    /// Answers given so far, with the confidence chosen for each
+    answers: Vec<QuizAnswer>,
+    /// Confidence that will be attached to the next answer
+    confidence: Confidence,
     selected_answer: usize,
+    /// Hints taken so far; only the first few are free
+    hints_used: u32,
+    /// Options hidden by hints on the current question
+    eliminated: Vec<usize>,
+    /// Why the last hint couldn't be used
+    hint_error: Option<String>,
+    /// Question just answered wrong, which the player may ask to have explained
+    last_missed: Option<QuizQuestion>,
+    /// Explanation requested for `last_missed`
+    explanation: Option<String>,
+}
+
+/// Save slot changes that need confirming first
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SlotAction {
+    Overwrite,
+    Delete,
+}
+
+/// Choice that carries out a pending confirmation
+const CONFIRM_YES: &str = "Yes";
+/// Choice that drops a pending confirmation
+const CONFIRM_NO: &str = "No";
+
+/// Significant or destructive actions that wait on the player's "Yes"
+#[derive(Debug, Clone, PartialEq)]
+enum ConfirmAction {
+    Slot(SlotAction, usize),
+    Respec(String),
+    /// Rest at home, then study the skill again
+    RestAndRetry(String),
+    QuitPartTimeJob,
+    /// Turn down the pending offer for this job id
+    DeclineOffer(u32),
+    QuitGame,
+}
+
+/// A yes/no question on screen and the action a "Yes" carries out
+#[derive(Debug, Clone, PartialEq)]
+struct PendingConfirmation {
+    prompt: String,
+    action: ConfirmAction,
+}
+
+impl PendingConfirmation {
+    /// The action to carry out for the chosen answer, `None` if declined
+    fn resolve(self, choice: &str) -> Option<ConfirmAction> {
+        (choice == CONFIRM_YES).then_some(self.action)
+    }
 }
 
 struct Game {
     state: GameState,
     world_player: WorldPlayer,
+    /// Fixed-rate clock the world simulation steps on, independent of frame rate
+    world_tick: FixedTimestep,
     camera: Camera,
     map: GameMap,
     npcs: Vec<Npc>,
     current_dialog: Option<Dialog>,
+    /// Character-by-character reveal of the current dialog's text
+    typewriter: Typewriter,
     current_npc: Option<usize>,
     selected_choice: usize,
-    player_name_input: String,
+    player_name_input: NameInput,
     input_active: bool,
     interview: Option<InterviewState>,
-    scroll_offset: usize,
+    question_db: InterviewQuestionDb,
+    /// Interview settings from `[interview]`, e.g. negative marking
+    interview_config: InterviewConfig,
+    /// Opt-in assists from `[assist]`
+    assist: AssistConfig,
+    application_queue: VecDeque<Application>,
+    debug: bool,
+    /// Next entry of `DEBUG_SKILL_PROFILES` that F4 applies
+    debug_profile: usize,
+    anim_clock: AnimClock,
+    /// Whether the study screen was opened through the Student's study group
+    study_group: bool,
+    /// Where the study screen's sessions take place and what they cost
+    study_venue: StudyVenue,
+    coffee_menu: CoffeeMenu,
+    /// Auto-study run in progress, advanced one session at a time
+    auto_study: Option<AutoStudy>,
+    auto_study_timer: f32,
+    /// Job board shows only bookmarked jobs
+    bookmarks_only: bool,
+    /// Map legend and building name tags, off by default
+    show_legend: bool,
+    /// HUD elements the player chose to show, toggled from the menu
+    hud_settings: HudSettings,
+    /// Save slot headers, refreshed when the save screen opens
+    save_slots: Vec<Result<Option<SaveHeader>, String>>,
+    /// Action waiting on the yes/no dialog currently shown
+    pending_confirmation: Option<PendingConfirmation>,
+    /// Set once the player confirms quitting or closes the window; the main loop exits
+    quit_requested: bool,
+    /// Autosave on exit, run once
+    shutdown: ShutdownHook,
+    /// Result of the last save, load or delete
+    save_message: Option<String>,
+    /// First event shown on the event log screen, counting from the newest
+    event_log_scroll: usize,
+    /// Hold-to-repeat state for list navigation
+    nav_up: RepeatKey,
+    nav_down: RepeatKey,
+    /// Problems hit while setting up, printed with the config problems
+    startup_problems: Vec<String>,
 }
 
 impl Game {
     fn new() -> Self {
+        let config = GameConfig::load().ok();
+        let map_seed = config.as_ref().map_or(world::HAND_AUTHORED_SEED, |config| config.game.map_seed);
+        let mut startup_problems = PlayerBuilder::env_override_problems();
+        startup_problems.extend(SaveFormat::from_env().err());
+        let map = GameMap::generate(map_seed).unwrap_or_else(|e| {
+            startup_problems.push(format!("{:#}; using the built-in map", e));
+            GameMap::new()
+        });
+        let (spawn_x, spawn_y) = world::SPAWN_TILE;
         Self {
             state: GameState::new(""),
-            world_player: WorldPlayer::new(5.0 * 32.0, (world::MAP_HEIGHT as f32 - 5.0) * 32.0),
+            world_player: WorldPlayer::new(spawn_x as f32 * world::TILE_SIZE, spawn_y as f32 * world::TILE_SIZE),
+            world_tick: FixedTimestep::default(),
             camera: Camera::new(),
-            map: GameMap::new(),
-            npcs: get_npcs(),
+            npcs: npcs_for_map(&map, map_seed)
+                .into_iter()
+                .map(|npc| match &config {
+                    Some(config) => {
+                        let variants = config.get_npc_dialog_variants(npc.npc_type.class_name());
+                        npc.with_dialog_variants(variants)
+                    }
+                    None => npc,
+                })
+                .collect(),
+            map,
             current_dialog: None,
+            typewriter: Typewriter::new(DIALOG_CHARS_PER_SEC),
             current_npc: None,
             selected_choice: 0,
-            player_name_input: String::new(),
+            player_name_input: NameInput::new(),
             input_active: true,
             interview: None,
-            scroll_offset: 0,
+            question_db: InterviewQuestionDb::load(),
+            interview_config: config.as_ref().map(|config| config.interview.clone()).unwrap_or_default(),
+            assist: config.as_ref().map(|config| config.assist).unwrap_or_default(),
+            application_queue: VecDeque::new(),
+            debug: false,
+            debug_profile: 0,
+            anim_clock: AnimClock::new(),
+            study_group: false,
+            study_venue: StudyVenue::LIBRARY,
+            coffee_menu: CoffeeMenu::load(),
+            auto_study: None,
+            auto_study_timer: 0.0,
+            bookmarks_only: false,
+            show_legend: false,
+            hud_settings: HudSettings {
+                warnings: config.map(|config| config.hud).unwrap_or_default(),
+                ..HudSettings::default()
+            },
+            save_slots: Vec::new(),
+            pending_confirmation: None,
+            quit_requested: false,
+            shutdown: ShutdownHook::default(),
+            save_message: None,
+            event_log_scroll: 0,
+            nav_up: RepeatKey::new(),
+            nav_down: RepeatKey::new(),
+            startup_problems,
         }
     }
 
-    async fn update(&mut self) {
-        let dt = get_frame_time();
+    /// Advance one frame, reacting to this frame's input
+    fn update(&mut self, input: &InputSnapshot, dt: f32) {
+
+        if input.is_key_pressed("f3") {
+            self.debug = !self.debug;
+        }
+        if self.debug && input.is_key_pressed("f4") {
+            self.apply_next_skill_profile();
+        }
+
+        self.anim_clock.set_paused(self.state.paused);
+        self.anim_clock.tick(dt);
+
+        let nav_up = self.nav_up.update(input.is_key_down("w") || input.is_key_down("up"), dt);
+        let nav_down = self.nav_down.update(input.is_key_down("s") || input.is_key_down("down"), dt);
 
         match self.state.screen {
             GameScreen::Title => {
                 if self.input_active {
-                    if is_key_pressed(KeyCode::Enter) && !self.player_name_input.is_empty() {
-                        self.state = GameState::new(&self.player_name_input);
-                        self.state.screen = GameScreen::World;
+                    if input.is_key_pressed("enter") && !self.player_name_input.is_empty() {
+                        self.state = GameState::new(self.player_name_input.name());
+                        if std::env::var_os(DEBUG_START_ENV).is_some() {
+                            self.state.player = PlayerBuilder::debug_start(self.player_name_input.name()).env_overrides().build();
+                        }
+                        self.state.reset_screen(GameScreen::World);
                         self.input_active = false;
                     }
                     
-                    while let Some(c) = get_char_pressed() {
-                        if c.is_alphanumeric() || c == ' ' {
-                            if self.player_name_input.len() < 20 {
-                                self.player_name_input.push(c);
-                            }
-                        }
+                    for &c in &input.chars_typed {
+                        self.player_name_input.push(c);
                     }
-                    if is_key_pressed(KeyCode::Backspace) && !self.player_name_input.is_empty() {
-                        self.player_name_input.pop();
+                    if input.is_key_pressed("backspace") {
+                        self.player_name_input.backspace();
+                    }
+                    if input.is_key_pressed("tab") {
+                        self.open_save_slots();
                     }
                 }
             }
             GameScreen::World => {
-                self.world_player.update(dt, &self.map);
-
-                self.camera.follow(self.world_player.x, self.world_player.y);
+                if !self.state.missed_interviews.is_empty() {
+                    self.report_missed_interviews();
+                    return;
+                }
+                if !self.state.expired_offers.is_empty() {
+                    self.report_expired_offers();
+                    return;
+                }
+                for _ in 0..self.world_tick.advance(dt) {
+                    self.world_player.update(input, self.world_tick.step_secs(), &self.map);
+                }
 
-                if is_key_pressed(KeyCode::E) {
+                if input.is_key_pressed("e") {
                     let mut interacted = false;
+                    let now = self.state.time_of_day;
 
-                    for (i, npc) in self.npcs.iter().enumerate() {
-                        if npc.distance_to(self.world_player.x, self.world_player.y) < 50.0 {
+                    for (i, npc) in self.npcs.iter_mut().enumerate() {
+                        if npc.distance_to(self.world_player.x, self.world_player.y) < 50.0 && npc.is_available(now) {
                             self.current_npc = Some(i);
-                            let (name, text) = npc.get_dialog();
+                            if npc.current_dialog == 0 {
+                                let talks = self.state.player.relationship(&npc.name).map_or(0, |r| r.times_talked);
+                                npc.start_conversation(&mut GameRng::for_conversation(self.state.seed, npc.npc_type_id(), talks));
+                            }
+                            let (name, line) = npc.get_dialog(&self.state.player.skills);
+                            let greeting = self.state.player.relationship(name)
+                                .and_then(|r| r.greeting(&self.state.player.name));
+                            let text = match greeting {
+                                Some(greeting) => format!("{} {}", greeting, line),
+                                None => line.to_string(),
+                            };
+                            self.state.player.record_talk(name, line);
+                            let choices = if matches!(npc.npc_type, NpcType::Student) {
+                                vec![
+                                    format!("Study together (${})", STUDY_GROUP_COST),
+                                    "Chat".to_string(),
+                                    "Leave".to_string(),
+                                ]
+                            } else {
+                                vec![]
+                            };
                             self.current_dialog = Some(Dialog {
                                 speaker: name.to_string(),
-                                text: text.to_string(),
-                                choices: vec![],
+                                text,
+                                choices,
                             });
-                            self.state.screen = GameScreen::Dialog;
+                            self.state.push_screen(GameScreen::Dialog);
                             interacted = true;
                             break;
                         }
@@ -135,36 +351,49 @@ impl Game {
                         if let Some(building) = self.map.get_building_at(self.world_player.x, self.world_player.y) {
                             let building = building.clone();
                             self.interact_with_building(&building);
+                        } else if let Some(npc) = self.absent_npc_nearby() {
+                            let (name, note) = (npc.name.clone(), npc.absence_note());
+                            self.show_action_result(&name, ActionResult::Blocked(note));
                         }
                     }
                 }
 
-                if is_key_pressed(KeyCode::I) {
-                    self.state.screen = GameScreen::Skills;
+                if input.is_key_pressed("i") {
+                    self.state.push_screen(GameScreen::Skills);
                 }
 
-                if is_key_pressed(KeyCode::J) {
-                    self.state.screen = GameScreen::JobBoard;
+                if input.is_key_pressed("j") {
+                    self.state.push_screen(GameScreen::JobBoard);
                 }
 
-                if is_key_pressed(KeyCode::Escape) {
-                    self.state.screen = GameScreen::Menu;
+                if input.is_key_pressed("escape") {
+                    self.state.paused = true;
+                    self.state.push_screen(GameScreen::Menu);
                 }
 
-                if is_key_pressed(KeyCode::F) {
+                if input.is_key_pressed("f") {
                     use_custom_font(!is_custom_font_enabled());
                 }
+
+                if input.is_key_pressed("l") {
+                    self.show_legend = !self.show_legend;
+                }
             }
             GameScreen::Dialog => {
                 if let Some(dialog) = &self.current_dialog {
-                    if dialog.choices.is_empty() {
-                        if is_key_pressed(KeyCode::E) || is_key_pressed(KeyCode::Enter) {
+                    self.typewriter.update(&dialog.text, dt);
+                    if !self.typewriter.is_done() {
+                        if input.is_key_pressed("e") || input.is_key_pressed("enter") {
+                            self.typewriter.skip();
+                        }
+                    } else if dialog.choices.is_empty() {
+                        if input.is_key_pressed("e") || input.is_key_pressed("enter") {
                             if let Some(npc_idx) = self.current_npc {
                                 if !self.npcs[npc_idx].advance_dialog() {
                                     self.npcs[npc_idx].reset_dialog();
                                     self.current_npc = None;
                                 } else {
-                                    let (name, text) = self.npcs[npc_idx].get_dialog();
+                                    let (name, text) = self.npcs[npc_idx].get_dialog(&self.state.player.skills);
                                     self.current_dialog = Some(Dialog {
                                         speaker: name.to_string(),
                                         text: text.to_string(),
@@ -173,95 +402,383 @@ impl Game {
                                     return;
                                 }
                             }
-                            self.current_dialog = None;
-                            self.state.screen = GameScreen::World;
+                            self.close_dialog();
                         }
                     } else {
-                        if is_key_pressed(KeyCode::W) || is_key_pressed(KeyCode::Up) {
-                            if self.selected_choice > 0 {
-                                self.selected_choice -= 1;
-                            }
-                        }
-                        if is_key_pressed(KeyCode::S) || is_key_pressed(KeyCode::Down) {
-                            if self.selected_choice < dialog.choices.len() - 1 {
-                                self.selected_choice += 1;
-                            }
-                        }
-                        if is_key_pressed(KeyCode::E) || is_key_pressed(KeyCode::Enter) {
+                        self.selected_choice = step_selection(self.selected_choice, dialog.choices.len(), nav_up, nav_down);
+                        if input.is_key_pressed("e") || input.is_key_pressed("enter") {
                             self.handle_dialog_choice();
                         }
                     }
+                } else {
+                    self.back();
+                }
+                if input.is_key_pressed("escape") && self.current_dialog.is_some() {
+                    if self.interview.is_some() {
+                        // Backing out of a retry offer or a round transition counts as giving up
+                        self.fail_interview();
+                    } else {
+                        self.current_npc = None;
+                        self.close_dialog();
+                    }
                 }
             }
             GameScreen::Skills => {
-                if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::I) {
-                    self.state.screen = GameScreen::World;
+                if input.is_key_pressed("escape") || input.is_key_pressed("i") {
+                    self.back();
                 }
             }
             GameScreen::Study => {
-                if is_key_pressed(KeyCode::Escape) {
-                    self.state.screen = GameScreen::World;
+                if self.auto_study.is_some() {
+                    self.update_auto_study(input, dt);
+                    return;
                 }
-                if is_key_pressed(KeyCode::W) || is_key_pressed(KeyCode::Up) {
-                    if self.selected_choice > 0 {
-                        self.selected_choice -= 1;
-                    }
+                if input.is_key_pressed("r") && self.can_auto_study() {
+                    self.start_auto_study();
                 }
-                if is_key_pressed(KeyCode::S) || is_key_pressed(KeyCode::Down) {
-                    if self.selected_choice < self.state.player.skills.len() - 1 {
-                        self.selected_choice += 1;
-                    }
+                if input.is_key_pressed("x") {
+                    self.confirm_respec();
+                }
+                if input.is_key_pressed("p") {
+                    self.allocate_pool_xp();
+                }
+                if input.is_key_pressed("f") {
+                    self.toggle_focus_skill();
+                }
+                if input.is_key_pressed("c") {
+                    self.take_certification_exam();
+                }
+                if input.is_key_pressed("escape") {
+                    self.study_group = false;
+                    self.back();
                 }
-                if is_key_pressed(KeyCode::E) || is_key_pressed(KeyCode::Enter) {
+                self.selected_choice = step_selection(self.selected_choice, self.state.player.skills.len(), nav_up, nav_down);
+                if input.is_key_pressed("e") || input.is_key_pressed("enter") {
                     self.handle_study();
                 }
             }
             GameScreen::JobBoard => {
-                if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::J) {
-                    self.state.screen = GameScreen::World;
+                if input.is_key_pressed("escape") || input.is_key_pressed("j") {
+                    self.back();
                 }
-                if is_key_pressed(KeyCode::W) || is_key_pressed(KeyCode::Up) {
-                    if self.selected_choice > 0 {
-                        self.selected_choice -= 1;
-                    }
+                if nav_up || nav_down {
+                    self.selected_choice = step_selection(self.selected_choice, self.board_job_count(), nav_up, nav_down);
                 }
-                if is_key_pressed(KeyCode::S) || is_key_pressed(KeyCode::Down) {
-                    let total_jobs: usize = companies::get_all_companies().iter().map(|c| c.open_positions.len()).sum();
-                    if self.selected_choice < total_jobs - 1 {
-                        self.selected_choice += 1;
+                if input.is_key_pressed("b") {
+                    if let Some(job) = self.selected_board_job() {
+                        self.state.player.toggle_bookmark(job.id);
+                        if self.bookmarks_only {
+                            self.selected_choice = clamp_selection(self.selected_choice, self.board_job_count());
+                        }
                     }
                 }
-                if is_key_pressed(KeyCode::E) || is_key_pressed(KeyCode::Enter) {
+                if input.is_key_pressed("f") {
+                    self.bookmarks_only = !self.bookmarks_only;
+                    self.selected_choice = 0;
+                }
+                if input.is_key_pressed("r") {
+                    self.research_selected_job();
+                }
+                if input.is_key_pressed("p") {
+                    self.warm_up_for_selected_job();
+                }
+                if input.is_key_pressed("e") || input.is_key_pressed("enter") {
                     self.start_interview();
                 }
+                if input.is_key_pressed("a") {
+                    self.batch_apply();
+                }
             }
             GameScreen::Interview => {
-                if let Some(ref interview) = self.interview {
-                    if is_key_pressed(KeyCode::W) || is_key_pressed(KeyCode::Up) {
-                        if self.selected_choice > 0 {
-                            self.selected_choice -= 1;
+                if let Some(interview) = &self.interview {
+                    // Step over options hidden by a hint
+                    let options = interview.questions.get(interview.current_question).map_or(0, |q| q.options.len());
+                    let visible: Vec<usize> = (0..options).filter(|i| !interview.eliminated.contains(i)).collect();
+                    if nav_up {
+                        if let Some(&prev) = visible.iter().rev().find(|&&i| i < self.selected_choice) {
+                            self.selected_choice = prev;
                         }
                     }
-                    if is_key_pressed(KeyCode::S) || is_key_pressed(KeyCode::Down) {
-                        if self.selected_choice < 3 {
-                            self.selected_choice += 1;
+                    if nav_down {
+                        if let Some(&next) = visible.iter().find(|&&i| i > self.selected_choice) {
+                            self.selected_choice = next;
                         }
                     }
-                    if is_key_pressed(KeyCode::E) || is_key_pressed(KeyCode::Enter) {
+                    if input.is_key_pressed("h") {
+                        self.use_interview_hint();
+                    }
+                    if input.is_key_pressed("x") {
+                        if let Some(interview) = &mut self.interview {
+                            interview.explanation = interview.last_missed.as_ref().map(ExplainEngine::rule_explanation);
+                        }
+                    }
+                    if input.is_key_pressed("tab") {
+                        if let Some(interview) = &mut self.interview {
+                            interview.confidence = interview.confidence.toggle();
+                        }
+                    }
+                    if input.is_key_pressed("e") || input.is_key_pressed("enter") {
                         self.answer_interview_question();
                     }
                 }
             }
             GameScreen::Menu => {
-                if is_key_pressed(KeyCode::Escape) {
-                    self.state.screen = GameScreen::World;
+                if input.is_key_pressed("escape") {
+                    self.state.paused = false;
+                    self.back();
+                }
+                if input.is_key_pressed("r") {
+                    self.export_resume();
+                }
+                if input.is_key_pressed("l") {
+                    self.open_save_slots();
+                }
+                if input.is_key_pressed("h") {
+                    self.event_log_scroll = 0;
+                    self.state.push_screen(GameScreen::EventLog);
+                }
+                if input.is_key_pressed("v") {
+                    let result = self.state.found_startup();
+                    self.show_action_result("Startup", result);
+                }
+                if input.is_key_pressed("q") {
+                    self.confirm("Quit", "Quit the game? Your progress will be autosaved.".to_string(), ConfirmAction::QuitGame);
+                }
+                if input.is_key_pressed("key1") {
+                    self.hud_settings.show_time = !self.hud_settings.show_time;
+                }
+                if input.is_key_pressed("key2") {
+                    self.hud_settings.show_energy = !self.hud_settings.show_energy;
+                }
+                if input.is_key_pressed("key3") {
+                    self.hud_settings.show_money = !self.hud_settings.show_money;
+                }
+                if input.is_key_pressed("o") {
+                    self.selected_choice = 0;
+                    self.state.push_screen(GameScreen::Offers);
+                }
+            }
+            GameScreen::SaveSlots => {
+                self.selected_choice = step_selection(self.selected_choice, SAVE_SLOTS, nav_up, nav_down);
+                if input.is_key_pressed("e") || input.is_key_pressed("enter") {
+                    self.save_to_slot(self.selected_choice);
+                }
+                if input.is_key_pressed("l") {
+                    self.load_from_slot(self.selected_choice);
+                }
+                if input.is_key_pressed("a") {
+                    let loaded = game::load_autosave(Path::new(SAVE_DIR));
+                    self.resume(loaded, "the autosave");
+                }
+                if input.is_key_pressed("x") || input.is_key_pressed("delete") {
+                    self.confirm_slot_action(SlotAction::Delete, self.selected_choice);
+                }
+                if input.is_key_pressed("escape") {
+                    self.back();
+                }
+            }
+            GameScreen::Offers => {
+                let offers = self.state.player.pending_offers.len();
+                if nav_up && self.selected_choice > 0 {
+                    self.selected_choice -= 1;
+                }
+                if nav_down && self.selected_choice + 1 < offers {
+                    self.selected_choice += 1;
+                }
+                let selected = self.state.player.pending_offers.get(self.selected_choice).map(|offer| offer.job.id);
+                if let Some(job_id) = selected {
+                    if input.is_key_pressed("e") || input.is_key_pressed("enter") {
+                        self.accept_offer(job_id);
+                    } else if input.is_key_pressed("x") {
+                        if let Some(offer) = self.state.player.pending_offer(job_id) {
+                            let prompt = format!("Turn down the {} offer from {}? It can't be taken back.",
+                                offer.job.title, offer.job.company);
+                            self.confirm("Offers", prompt, ConfirmAction::DeclineOffer(job_id));
+                        }
+                    }
+                }
+                if input.is_key_pressed("escape") || input.is_key_pressed("o") {
+                    self.back();
+                }
+            }
+            GameScreen::EventLog => {
+                let last_page = self.state.event_log.len().saturating_sub(EVENT_LOG_ROWS);
+                if nav_up && self.event_log_scroll > 0 {
+                    self.event_log_scroll -= 1;
+                }
+                if nav_down && self.event_log_scroll < last_page {
+                    self.event_log_scroll += 1;
+                }
+                if input.is_key_pressed("escape") || input.is_key_pressed("h") {
+                    self.back();
+                }
+            }
+        }
+    }
+
+    /// Debug shortcut: jump to the next preset skill profile
+    fn apply_next_skill_profile(&mut self) {
+        let profile = &DEBUG_SKILL_PROFILES[self.debug_profile % DEBUG_SKILL_PROFILES.len()];
+        self.debug_profile += 1;
+        let text = match apply_skill_profile(&mut self.state.player, profile) {
+            Ok(()) => format!(
+                "Applied '{}' skill profile. Career level is now {}.",
+                profile.name,
+                self.state.player.career_level()
+            ),
+            Err(e) => format!("Could not apply '{}': {}", profile.name, e),
+        };
+        self.current_dialog = Some(Dialog {
+            speaker: "Debug".to_string(),
+            text,
+            choices: vec![],
+        });
+        self.selected_choice = 0;
+        self.state.push_screen(GameScreen::Dialog);
+    }
+
+    fn open_save_slots(&mut self) {
+        self.refresh_save_slots();
+        self.save_message = None;
+        self.selected_choice = 0;
+        self.state.push_screen(GameScreen::SaveSlots);
+    }
+
+    fn refresh_save_slots(&mut self) {
+        self.save_slots = game::list_slots(Path::new(SAVE_DIR))
+            .into_iter()
+            .map(|slot| slot.map_err(|e| e.to_string()))
+            .collect();
+    }
+
+    /// Save, asking first if the slot already holds a game
+    fn save_to_slot(&mut self, slot: usize) {
+        if self.input_active {
+            self.save_message = Some("Start a game before saving".to_string());
+            return;
+        }
+        if matches!(self.save_slots.get(slot), Some(Ok(None))) {
+            self.apply_slot_action(SlotAction::Overwrite, slot);
+        } else {
+            self.confirm_slot_action(SlotAction::Overwrite, slot);
+        }
+    }
+
+    fn confirm_slot_action(&mut self, action: SlotAction, slot: usize) {
+        if action == SlotAction::Delete && matches!(self.save_slots.get(slot), Some(Ok(None))) {
+            self.save_message = Some(format!("Slot {} is already empty", slot + 1));
+            return;
+        }
+        let prompt = match action {
+            SlotAction::Overwrite => format!("Overwrite the save in slot {}?", slot + 1),
+            SlotAction::Delete => format!("Delete the save in slot {}? This can't be undone.", slot + 1),
+        };
+        self.confirm("Save Slots", prompt, ConfirmAction::Slot(action, slot));
+    }
+
+    /// Ask a yes/no question and carry out `action` if the player says yes
+    fn confirm(&mut self, speaker: &str, prompt: String, action: ConfirmAction) {
+        self.current_dialog = Some(Dialog {
+            speaker: speaker.to_string(),
+            text: prompt.clone(),
+            choices: vec![CONFIRM_YES.to_string(), CONFIRM_NO.to_string()],
+        });
+        self.pending_confirmation = Some(PendingConfirmation { prompt, action });
+        self.selected_choice = 0;
+        self.state.push_screen(GameScreen::Dialog);
+    }
+
+    fn run_confirmed(&mut self, action: ConfirmAction) {
+        match action {
+            ConfirmAction::Slot(action, slot) => self.apply_slot_action(action, slot),
+            ConfirmAction::Respec(skill) => {
+                let result = match self.state.player.respec_skill(&skill) {
+                    Ok(refund) => ActionResult::Done(format!(
+                        "{} reset. {} XP added to your pool - press P on a skill to use it", skill, refund)),
+                    Err(e) => ActionResult::Blocked(e),
+                };
+                self.show_action_result("Respec", result);
+            }
+            ConfirmAction::RestAndRetry(skill) => {
+                let study = Self::study_action(self.study_venue, self.study_group, &skill);
+                let (result, rested) = self.state.rest_and_retry(study);
+                self.finish_study(result, rested);
+            }
+            ConfirmAction::QuitPartTimeJob => {
+                if let Some(job) = self.state.player.quit_part_time_job() {
+                    let result = ActionResult::Done(format!("You quit your job as a {}.", job.title()));
+                    self.show_action_result("Job Center", result);
                 }
             }
-            _ => {}
+            ConfirmAction::DeclineOffer(job_id) => {
+                let result = self.state.decline_offer(job_id);
+                self.show_action_result("Offers", result);
+            }
+            ConfirmAction::QuitGame => self.quit_requested = true,
+        }
+    }
+
+    fn apply_slot_action(&mut self, action: SlotAction, slot: usize) {
+        let dir = Path::new(SAVE_DIR);
+        let result = match action {
+            SlotAction::Overwrite => game::save_game_as(dir, slot, &self.state, SaveFormat::from_env().unwrap_or_default()).map(|_| "Saved to"),
+            SlotAction::Delete => game::delete_save(dir, slot).map(|_| "Deleted"),
+        };
+        self.save_message = Some(match result {
+            Ok(done) => format!("{} slot {}", done, slot + 1),
+            Err(e) => format!("Slot {} failed: {}", slot + 1, e),
+        });
+        self.refresh_save_slots();
+    }
+
+    fn load_from_slot(&mut self, slot: usize) {
+        let loaded = game::load_game(Path::new(SAVE_DIR), slot);
+        self.resume(loaded, &format!("slot {}", slot + 1));
+    }
+
+    /// Switch to a loaded game, or say why `source` couldn't be loaded
+    fn resume(&mut self, loaded: anyhow::Result<GameState>, source: &str) {
+        match loaded {
+            Ok(state) => {
+                self.state = state;
+                self.player_name_input = NameInput::new();
+                self.input_active = false;
+                self.interview = None;
+                self.application_queue.clear();
+                self.auto_study = None;
+                self.current_dialog = None;
+                self.current_npc = None;
+                self.pending_confirmation = None;
+                self.selected_choice = 0;
+                let (spawn_x, spawn_y) = world::SPAWN_TILE;
+                self.world_player = WorldPlayer::new(spawn_x as f32 * world::TILE_SIZE, spawn_y as f32 * world::TILE_SIZE);
+                self.world_tick = FixedTimestep::default();
+            }
+            Err(e) => self.save_message = Some(format!("Could not load {}: {}", source, e)),
+        }
+    }
+
+    /// Autosave the game in progress before exiting; later calls do nothing
+    fn shut_down(&mut self) {
+        let flush = ShutdownFlush {
+            state: (!self.input_active).then_some(&self.state),
+            save_dir: Path::new(SAVE_DIR),
+            format: SaveFormat::from_env().unwrap_or_default(),
+            // The window only runs rule engines, so there's no response cache to keep
+            cache: None,
+        };
+        if let Err(e) = self.shutdown.run(&flush, &mut DiskSink) {
+            eprintln!("Could not autosave on exit: {:#}", e);
         }
     }
 
     fn interact_with_building(&mut self, building: &world::Building) {
+        // Midnight can pass on the way in; the player walks in again after the summary
+        let finished = self.state.advance_time(COMMUTE_HOURS, TimeCategory::Commuting);
+        if finished.is_some() {
+            self.show_day_summary(finished);
+            return;
+        }
         match building.building_type {
             BuildingType::Apartment => {
                 self.current_dialog = Some(Dialog {
@@ -270,19 +787,29 @@ impl Game {
                     choices: vec!["Rest (restore energy)".to_string(), "Leave".to_string()],
                 });
                 self.selected_choice = 0;
-                self.state.screen = GameScreen::Dialog;
+                self.state.push_screen(GameScreen::Dialog);
             }
             BuildingType::Library => {
-                self.state.screen = GameScreen::Study;
+                self.study_group = false;
+                self.study_venue = StudyVenue::LIBRARY;
+                self.state.push_screen(GameScreen::Study);
+            }
+            BuildingType::University => {
+                self.study_group = false;
+                self.study_venue = StudyVenue::UNIVERSITY;
+                self.state.push_screen(GameScreen::Study);
             }
             BuildingType::CoffeeShop => {
                 self.current_dialog = Some(Dialog {
                     speaker: "Barista".to_string(),
                     text: "Welcome! Care for some coffee? Great for networking!".to_string(),
-                    choices: vec!["Buy coffee ($5)".to_string(), "Network with people".to_string(), "Leave".to_string()],
+                    choices: self.coffee_menu.items.iter()
+                        .map(|item| item.label())
+                        .chain(["Network with people".to_string(), "Leave".to_string()])
+                        .collect(),
                 });
                 self.selected_choice = 0;
-                self.state.screen = GameScreen::Dialog;
+                self.state.push_screen(GameScreen::Dialog);
             }
             BuildingType::Company { tier: _ } => {
                 self.current_dialog = Some(Dialog {
@@ -291,10 +818,22 @@ impl Game {
                     choices: vec!["View open positions".to_string(), "Talk to recruiter".to_string(), "Leave".to_string()],
                 });
                 self.selected_choice = 0;
-                self.state.screen = GameScreen::Dialog;
+                self.state.push_screen(GameScreen::Dialog);
             }
             BuildingType::JobCenter => {
-                self.state.screen = GameScreen::JobBoard;
+                let mut choices = vec!["View open positions".to_string()];
+                choices.extend(PartTimeJob::ALL.iter().map(|job| job.label()));
+                if self.state.player.part_time.is_some() {
+                    choices.push("Quit part-time job".to_string());
+                }
+                choices.push("Leave".to_string());
+                self.current_dialog = Some(Dialog {
+                    speaker: "Job Center".to_string(),
+                    text: "Browse full-time openings, or pick up part-time work to pay the bills while you study.".to_string(),
+                    choices,
+                });
+                self.selected_choice = 0;
+                self.state.push_screen(GameScreen::Dialog);
             }
             BuildingType::Park => {
                 self.current_dialog = Some(Dialog {
@@ -303,7 +842,7 @@ impl Game {
                     choices: vec!["Relax (+energy)".to_string(), "Leave".to_string()],
                 });
                 self.selected_choice = 0;
-                self.state.screen = GameScreen::Dialog;
+                self.state.push_screen(GameScreen::Dialog);
             }
         }
     }
@@ -313,190 +852,547 @@ impl Game {
             let choice_idx = self.selected_choice;
             let choice = dialog.choices.get(choice_idx).cloned().unwrap_or_default();
 
+            if let Some(pending) = self.pending_confirmation.take() {
+                self.close_dialog();
+                if let Some(action) = pending.resolve(&choice) {
+                    self.run_confirmed(action);
+                }
+                return;
+            }
             if choice.contains("Rest") || choice.contains("Relax") {
-                self.state.player.energy = self.state.player.max_energy;
-                self.state.advance_time(8.0);
-                self.state.screen = GameScreen::World;
-                self.current_dialog = None;
+                let finished = self.state.rest_at_home();
+                self.close_dialog();
+                self.show_day_summary(finished);
+                return;
+            }
+            if let Some(item) = self.coffee_menu.find_by_label(&choice).cloned() {
+                let result = game::buy_drink(&mut self.state.player, &item);
+                self.show_action_result("Barista", result);
                 return;
             }
-            if choice.contains("Buy coffee") {
-                if self.state.player.money >= 5 {
-                    self.state.player.money -= 5;
-                    self.state.player.energy = (self.state.player.energy + 20).min(self.state.player.max_energy);
+            if let Some(&job) = PartTimeJob::ALL.iter().find(|job| job.label() == choice) {
+                let result = self.state.take_part_time_job(job);
+                self.show_action_result("Job Center", result);
+                return;
+            }
+            if choice == "Quit part-time job" {
+                if let Some(job) = self.state.player.part_time {
+                    let prompt = format!("Quit your job as a {}? The pay stops today.", job.title());
+                    self.confirm("Job Center", prompt, ConfirmAction::QuitPartTimeJob);
                 }
-                self.state.screen = GameScreen::World;
-                self.current_dialog = None;
                 return;
             }
             if choice.contains("View open positions") || choice == "Network with people" {
-                self.state.screen = GameScreen::JobBoard;
+                // Keep the dialog so backing out of the board returns to it
+                self.state.push_screen(GameScreen::JobBoard);
+                return;
+            }
+            if choice.starts_with("Study together") {
+                self.current_npc = None;
                 self.current_dialog = None;
+                self.study_group = true;
+                self.study_venue = StudyVenue::LIBRARY;
+                self.state.replace_screen(GameScreen::Study);
+                return;
+            }
+            if choice == "Chat" {
+                if let Some(npc_idx) = self.current_npc {
+                    let (name, text) = self.npcs[npc_idx].get_dialog(&self.state.player.skills);
+                    self.current_dialog = Some(Dialog {
+                        speaker: name.to_string(),
+                        text: text.to_string(),
+                        choices: vec![],
+                    });
+                }
                 return;
             }
             if choice.contains("Leave") {
-                self.state.screen = GameScreen::World;
-                self.current_dialog = None;
+                self.current_npc = None;
+                self.close_dialog();
                 return;
             }
-            if choice.contains("Awesome!") || choice.contains("OK") {
-                self.state.screen = GameScreen::World;
-                self.current_dialog = None;
+            if choice == ACCEPT_OFFER_CHOICE {
+                // The dialog is about the offer that just came in
+                if let Some(job_id) = self.state.player.pending_offers.last().map(|offer| offer.job.id) {
+                    self.close_dialog();
+                    self.accept_offer(job_id);
+                }
+                return;
+            }
+            if choice == "Decide later" {
+                self.close_dialog();
+                return;
+            }
+            if choice == "Retry round" {
+                self.retry_interview_round();
+                return;
+            }
+            if choice == NEXT_ROUND_CHOICE {
+                self.start_next_interview_round();
+                return;
+            }
+            if choice == "Give up" {
+                self.fail_interview();
+                return;
+            }
+            if choice == "Cancel" {
+                self.application_queue.clear();
+                self.close_dialog();
+                return;
+            }
+            if choice == "Start interviews" || choice.contains("Awesome!") || choice.contains("OK") {
+                self.close_dialog();
+                self.start_next_application();
                 return;
             }
         }
+        self.close_dialog();
+    }
+
+    /// Take a pending offer and say how it went
+    fn accept_offer(&mut self, job_id: u32) {
+        let result = self.state.accept_offer(job_id);
+        self.show_action_result("Offers", result);
+    }
+
+    fn show_offer_message(&mut self, text: String) {
+        self.current_dialog = Some(Dialog {
+            speaker: "Offers".to_string(),
+            text,
+            choices: vec![],
+        });
+        self.selected_choice = 0;
+        self.state.push_screen(GameScreen::Dialog);
+    }
+
+    /// Tell the player about offers they let lapse
+    fn report_expired_offers(&mut self) {
+        let expired = std::mem::take(&mut self.state.expired_offers);
+        let lines: Vec<String> = expired.iter()
+            .map(|offer| format!("Your offer from {} expired", offer.job.company))
+            .collect();
+        self.show_offer_message(lines.join("\n"));
+    }
+
+    /// Dismiss the current dialog and go back to the screen underneath
+    fn close_dialog(&mut self) {
         self.current_dialog = None;
-        self.state.screen = GameScreen::World;
+        self.back();
+    }
+
+    /// Return to the previous screen, skipping dialogs that were already dismissed
+    ///
+    /// An unanswered confirmation is dropped on the way out.
+    fn back(&mut self) {
+        self.selected_choice = 0;
+        self.pending_confirmation = None;
+        self.state.pop_screen();
+        while self.state.screen == GameScreen::Dialog && self.current_dialog.is_none() {
+            self.state.pop_screen();
+        }
+    }
+
+    /// Tell the player what an action did, or why it didn't happen
+    fn show_action_result(&mut self, speaker: &str, result: ActionResult) {
+        self.current_dialog = Some(Dialog {
+            speaker: speaker.to_string(),
+            text: result.message().to_string(),
+            choices: vec![],
+        });
+        self.selected_choice = 0;
+        if self.state.screen != GameScreen::Dialog {
+            self.state.push_screen(GameScreen::Dialog);
+        }
+    }
+
+    /// Show how yesterday's hours were spent once the day rolls over
+    fn show_day_summary(&mut self, finished: Option<DayLog>) {
+        let Some(log) = finished else { return };
+        self.current_dialog = Some(Dialog {
+            speaker: "Daily Summary".to_string(),
+            text: log.summary(self.state.day - 1),
+            choices: vec![],
+        });
+        self.selected_choice = 0;
+        self.state.push_screen(GameScreen::Dialog);
+    }
+
+    fn export_resume(&mut self) {
+        let text = match self.state.player.to_resume().write_to(RESUME_PATH) {
+            Ok(()) => format!("Your resume was saved to {}", RESUME_PATH),
+            Err(e) => format!("Could not save resume: {}", e),
+        };
+        self.current_dialog = Some(Dialog {
+            speaker: "Resume".to_string(),
+            text,
+            choices: vec!["OK".to_string()],
+        });
+        self.selected_choice = 0;
+        self.state.push_screen(GameScreen::Dialog);
     }
 
     fn handle_study(&mut self) {
-        let skills: Vec<_> = self.state.player.skills.iter().collect();
-        if self.selected_choice < skills.len() {
-            let skill_name = skills[self.selected_choice].0.clone();
-            let energy_cost = 30;
-            
-            if self.state.player.energy >= energy_cost {
-                if let Some(skill) = self.state.player.skills.get_mut(&skill_name) {
-                    self.state.player.energy -= energy_cost;
-                    let xp_gained = 50;
-                    skill.add_experience(xp_gained);
-                    self.state.advance_time(2.0);
-                }
-            }
+        if let Some(skill_name) = self.state.player.skills.keys().nth(self.selected_choice).cloned() {
+            self.study_skill(&skill_name);
+        }
+    }
+
+    /// One study session on a skill at the current venue
+    ///
+    /// Running short of energy near home offers the auto-rest assist when it's on.
+    fn study_skill(&mut self, skill_name: &str) {
+        let result = Self::study_action(self.study_venue, self.study_group, skill_name)(&mut self.state);
+        if self.state.offers_auto_rest(self.assist.auto_rest, self.is_near_home(), &result) {
+            let prompt = format!("{}\nHead home, rest {}h and try again?", result.message(), HOME_REST_HOURS);
+            self.confirm(self.study_venue.name, prompt, ConfirmAction::RestAndRetry(skill_name.to_string()));
+        } else {
+            self.finish_study(result, None);
+        }
+    }
+
+    /// A study session on a skill, as an action the auto-rest assist can retry
+    fn study_action(venue: StudyVenue, group: bool, skill_name: &str) -> impl FnMut(&mut GameState) -> ActionResult + '_ {
+        move |state| {
+            let time_of_day = state.time_of_day;
+            state.track_progress(|player| {
+                game::study_session(player, &venue, skill_name, STUDY_SESSION_HOURS, time_of_day, group)
+            })
+        }
+    }
+
+    /// Pass the session's hours on a success, or say why it didn't happen
+    ///
+    /// `rested` is the log of a day a rest before the session finished.
+    fn finish_study(&mut self, result: ActionResult, rested: Option<DayLog>) {
+        if result.succeeded() {
+            let finished = self.state.advance_time(STUDY_SESSION_HOURS as f32, TimeCategory::Studying);
+            self.show_day_summary(finished.or(rested));
+        } else {
+            self.show_action_result(self.study_venue.name, result);
+        }
+    }
+
+    /// Whether the player is within `[assist] home_range` tiles of the apartment
+    fn is_near_home(&self) -> bool {
+        let range = self.assist.home_range * world::TILE_SIZE;
+        self.map
+            .buildings
+            .iter()
+            .filter(|b| matches!(b.building_type, BuildingType::Apartment))
+            .any(|home| home.is_within(self.world_player.x, self.world_player.y, range))
+    }
+
+    /// Ask before resetting the selected skill
+    fn confirm_respec(&mut self) {
+        let Some(skill) = self.state.player.skills.keys().nth(self.selected_choice).cloned() else { return };
+        let prompt = format!(
+            "Reset {} to None for ${} and {} reputation? You get back {:.0}% of its XP to spend elsewhere.",
+            skill, RESPEC_COST, RESPEC_REPUTATION_PENALTY, RESPEC_REFUND_FRACTION * 100.0
+        );
+        self.confirm("Respec", prompt, ConfirmAction::Respec(skill));
+    }
+
+    /// Pour the refunded XP pool into the selected skill
+    fn allocate_pool_xp(&mut self) {
+        let Some(skill) = self.state.player.skills.keys().nth(self.selected_choice).cloned() else { return };
+        let result = match self.state.track_progress(|player| player.allocate_pool_xp(&skill)) {
+            Ok(message) => ActionResult::Done(message),
+            Err(e) => ActionResult::Blocked(e),
+        };
+        self.show_action_result("Respec", result);
+    }
+
+    /// Make the selected skill the focus skill, or clear it if it already is
+    fn toggle_focus_skill(&mut self) {
+        let Some(skill) = self.state.player.skills.keys().nth(self.selected_choice).cloned() else { return };
+        let player = &mut self.state.player;
+        let result = if player.focus_skill.as_deref() == Some(skill.as_str()) {
+            player.set_focus_skill(None).map(|_| format!("{} is no longer your focus skill", skill))
+        } else {
+            player.set_focus_skill(Some(&skill)).map(|_| {
+                format!("{} is now your focus skill: +{} XP/day from self-study", skill, player.focus_daily_xp())
+            })
+        };
+        let result = match result {
+            Ok(message) => ActionResult::Done(message),
+            Err(e) => ActionResult::Blocked(e),
+        };
+        self.show_action_result("Focus", result);
+    }
+
+    /// Sit the certification exam for the selected skill
+    fn take_certification_exam(&mut self) {
+        let Some(skill) = self.state.player.skills.keys().nth(self.selected_choice).cloned() else { return };
+        let result = self.state.take_certification_exam(&skill);
+        // Done covers a failed exam too: the time was spent either way
+        let sat = result.succeeded();
+        self.show_action_result("Certification", result);
+        if sat {
+            let finished = self.state.advance_time(CERT_EXAM_HOURS as f32, TimeCategory::Studying);
+            self.show_day_summary(finished);
+        }
+    }
+
+    /// Auto-study runs free sessions only, so not in a group or at a paid venue
+    fn can_auto_study(&self) -> bool {
+        !self.study_group && self.study_venue.cost == 0
+    }
+
+    fn start_auto_study(&mut self) {
+        if let Some(skill_name) = self.state.player.skills.keys().nth(self.selected_choice) {
+            self.auto_study = Some(AutoStudy::new(skill_name, AUTO_STUDY_SESSIONS));
+            self.auto_study_timer = 0.0;
         }
     }
 
+    /// Run the next auto-study session once the step delay has passed; Esc stops the run
+    fn update_auto_study(&mut self, input: &InputSnapshot, dt: f32) {
+        let Some(auto) = &mut self.auto_study else { return };
+        let cancelled = input.is_key_pressed("escape");
+        if cancelled {
+            auto.cancel();
+        }
+        self.auto_study_timer += dt;
+        if self.auto_study_timer < AUTO_STUDY_STEP_SECS && !cancelled {
+            return;
+        }
+        self.auto_study_timer = 0.0;
+
+        if let AutoStudyStep::Finished(summary) = auto.step(&mut self.state) {
+            self.auto_study = None;
+            self.current_dialog = Some(Dialog {
+                speaker: "Auto Study".to_string(),
+                text: summary.message(),
+                choices: vec![],
+            });
+            self.selected_choice = 0;
+            self.state.push_screen(GameScreen::Dialog);
+        }
+    }
+
+    /// Spend time with the recruiter to learn the selected job's interview rounds
+    fn research_selected_job(&mut self) {
+        let Some(job) = self.selected_board_job() else { return };
+        let result = game::research_job(&mut self.state.player, job.id);
+        if !result.succeeded() {
+            self.show_action_result(&job.company, result);
+            return;
+        }
+        let finished = self.state.advance_time(RESEARCH_HOURS as f32, TimeCategory::Networking);
+        self.show_day_summary(finished);
+    }
+
+    /// Run through the selected job's interview topics, sharpening its first question
+    fn warm_up_for_selected_job(&mut self) {
+        let Some(job) = self.selected_board_job() else { return };
+        let result = game::warm_up(&mut self.state.player, &job);
+        let succeeded = result.succeeded();
+        self.show_action_result(&job.company, result);
+        if succeeded {
+            let finished = self.state.advance_time(WARM_UP_HOURS as f32, TimeCategory::Studying);
+            self.show_day_summary(finished);
+        }
+    }
+
+    /// Book an interview for the selected job, or go to it if already booked
     fn start_interview(&mut self) {
-        let mut idx = 0;
-        let mut target_job: Option<Job> = None;
-        
-        'outer: for company in companies::get_all_companies() {
-            for job in &company.open_positions {
-                if idx == self.selected_choice {
-                    target_job = Some(job.clone());
-                    break 'outer;
+        let Some(job) = self.selected_board_job() else { return };
+        if self.state.player.scheduled_interview(job.id).is_some() {
+            match self.state.attend_interview(job.id) {
+                Ok((_, waited)) => {
+                    let finished = self.start_interview_for(job);
+                    self.show_day_summary(waited.or(finished));
                 }
-                idx += 1;
+                Err(e) => self.show_action_result(&job.company, ActionResult::Blocked(e)),
             }
+            return;
         }
-        
-        if let Some(job) = target_job {
-            let questions = self.generate_interview_questions(&job);
-            self.interview = Some(InterviewState {
-                job,
-                questions,
-                current_question: 0,
-                score: 0,
-                selected_answer: 0,
-            });
+        if let Err(e) = game::check_interview_cooldown(&self.state.player, job.id) {
+            self.show_action_result(&job.company, ActionResult::Blocked(e));
+            return;
+        }
+        let result = match self.state.player.schedule_interview(&job, self.state.day) {
+            Ok(slot) => ActionResult::Done(format!(
+                "Your {} interview is booked for {}. Press E on the job again to attend - don't sleep through it!",
+                job.title, slot.slot_label())),
+            Err(e) => ActionResult::Blocked(e),
+        };
+        self.show_action_result(&job.company, result);
+    }
+
+    /// Tell the player about booked interviews they let pass
+    fn report_missed_interviews(&mut self) {
+        let missed = std::mem::take(&mut self.state.missed_interviews);
+        let lines: Vec<String> = missed.iter()
+            .map(|slot| format!("You missed your {} interview at {} ({})", slot.title, slot.company, slot.slot_label()))
+            .collect();
+        self.show_action_result("Calendar", ActionResult::Blocked(lines.join("\n")));
+    }
+
+    /// Sit an interview for a job. Returns the finished day's log if it ran past midnight.
+    fn start_interview_for(&mut self, job: Job) -> Option<DayLog> {
+        let attempt = self.state.player.record_interview_attempt(job.id);
+        let mut rng = GameRng::for_job_attempt(self.state.seed, job.id, attempt);
+        let company = companies::find(&job.company);
+        let tier = company.as_ref().map_or(CompanyTier::MidSize, |c| c.tier);
+        let session = self.interview_config.new_session(Interview::quiz_rounds(&job, tier));
+        let round = session.current_round().expect("an interview has at least one round");
+        let mut quiz = AdaptiveQuiz::for_round(&job, round, company.as_ref(), &self.question_db);
+        let questions: Vec<QuizQuestion> = quiz.next_question(&mut rng).into_iter().collect();
+        // A warm-up rules out one wrong option on the first question
+        let eliminated = match questions.first() {
+            Some(first) if self.state.player.consume_warm_up(job.id) => {
+                eliminate_wrong_option(first, &[], &mut rng).into_iter().collect()
+            }
+            _ => Vec::new(),
+        };
+        self.interview = Some(InterviewState {
+            job,
+            company,
+            tier,
+            session,
+            quiz,
+            rng,
+            questions,
+            current_question: 0,
+            answers: Vec::new(),
+            confidence: Confidence::default(),
+            selected_answer: 0,
+            hints_used: 0,
+            eliminated,
+            hint_error: None,
+            last_missed: None,
+            explanation: None,
+        });
+        self.selected_choice = 0;
+        self.state.push_screen(GameScreen::Interview);
+        self.state.advance_time(INTERVIEW_LENGTH_HOURS as f32, TimeCategory::Interviewing)
+    }
+
+    /// Re-run the failed round with fresh questions, using up the retry
+    fn retry_interview_round(&mut self) {
+        if self.interview.as_mut().is_some_and(|interview| interview.session.retry()) {
+            self.start_interview_round();
+        }
+    }
+
+    /// Leave the transition between rounds and start the next one
+    fn start_next_interview_round(&mut self) {
+        if let Some(interview) = &mut self.interview {
+            interview.session.start_next_round();
+            self.start_interview_round();
+        }
+    }
+
+    /// Start the session's current round with a fresh quiz
+    fn start_interview_round(&mut self) {
+        if let Some(interview) = &mut self.interview {
+            let Some(round) = interview.session.current_round() else { return };
+            interview.quiz = AdaptiveQuiz::for_round(&interview.job, round, interview.company.as_ref(), &self.question_db);
+            interview.questions = interview.quiz.next_question(&mut interview.rng).into_iter().collect();
+            interview.current_question = 0;
+            interview.answers.clear();
+            interview.confidence = Confidence::default();
+            interview.selected_answer = 0;
+            interview.eliminated.clear();
+            interview.hint_error = None;
+            interview.last_missed = None;
+            interview.explanation = None;
+            self.current_dialog = None;
             self.selected_choice = 0;
-            self.state.screen = GameScreen::Interview;
+            self.state.replace_screen(GameScreen::Interview);
         }
     }
 
-    fn generate_interview_questions(&self, job: &Job) -> Vec<QuizQuestion> {
-        let mut questions = Vec::new();
-        
-        for req in &job.requirements {
-            if req.mandatory {
-                let q = self.create_question_for_skill(&req.skill_name);
-                questions.push(q);
+    /// End the interview as a failure: start the cooldown and show mentor feedback
+    fn fail_interview(&mut self) {
+        let Some(interview) = self.interview.take() else { return };
+        let outcome = Self::interview_outcome(&interview, &self.state.player, self.interview_config.marking_penalty())
+            .settle(false, &self.state.player);
+        self.state.apply_interview_outcome(&outcome);
+        self.current_dialog = Some(Dialog {
+            speaker: "Interview Complete".to_string(),
+            text: format!("Unfortunately, you didn't pass. {}\nMentor: {}", 
+                Self::interview_score_text(&interview, &outcome), AdviceEngine::rule_advice(&outcome.job, &self.state.player)),
+            choices: vec!["OK".to_string()],
+        });
+        self.selected_choice = 0;
+        self.state.replace_screen(GameScreen::Dialog);
+    }
+
+    /// Score the current round as it stands
+    fn interview_outcome(interview: &InterviewState, player: &Player, wrong_penalty: f32) -> InterviewOutcome {
+        InterviewOutcome::from_quiz(interview.job.clone(), interview.tier, player, &interview.questions, &interview.answers, wrong_penalty)
+    }
+
+    /// Per-round scores once any round is done, otherwise the current round's score
+    fn interview_score_text(interview: &InterviewState, outcome: &InterviewOutcome) -> String {
+        if interview.session.results.is_empty() {
+            format!("Score: {}", outcome.score_text())
+        } else {
+            format!("\n{}", interview.session.summary())
+        }
+    }
+
+    /// Start the next queued interview, if any
+    fn start_next_application(&mut self) -> bool {
+        match self.application_queue.pop_front() {
+            Some(application) => {
+                let finished = self.start_interview_for(application.job);
+                self.show_day_summary(finished);
+                true
             }
+            None => false,
         }
-        
-        if questions.len() > 5 {
-            questions.shuffle();
-            questions.truncate(5);
+    }
+
+    fn batch_apply(&mut self) {
+        let batch = jobs::batch_apply(
+            &self.visible_companies(),
+            &self.state.player,
+            BATCH_APPLY_THRESHOLD,
+            Some(&self.state.trends),
+        );
+
+        let mut text = format!("Queued {} interview(s).", batch.queued.len());
+        for skipped in &batch.skipped {
+            text.push_str(&format!("\nSkipped {}: {}", skipped.job.title, skipped.reason));
         }
-        
-        if questions.is_empty() {
-            questions.push(QuizQuestion {
-                question: "Why do you want to work here?".to_string(),
-                options: vec![
-                    "I'm passionate about AI and want to learn".to_string(),
-                    "For the money".to_string(),
-                    "My friend works here".to_string(),
-                    "I don't know".to_string(),
-                ],
-                correct_idx: 0,
-            });
+        let choices = if batch.queued.is_empty() {
+            vec!["OK".to_string()]
+        } else {
+            vec!["Start interviews".to_string(), "Cancel".to_string()]
+        };
+
+        self.application_queue = batch.queued.into();
+        self.current_dialog = Some(Dialog {
+            speaker: "Apply to All".to_string(),
+            text,
+            choices,
+        });
+        self.selected_choice = 0;
+        self.state.push_screen(GameScreen::Dialog);
+    }
+
+    /// Hide one wrong option on the current question
+    fn use_interview_hint(&mut self) {
+        let Some(interview) = &mut self.interview else { return };
+        let Some(question) = interview.questions.get(interview.current_question) else { return };
+        let Some(option) = eliminate_wrong_option(question, &interview.eliminated, &mut interview.rng) else {
+            interview.hint_error = Some("No more options to eliminate".to_string());
+            return;
+        };
+        if interview.hints_used >= FREE_HINTS_PER_INTERVIEW {
+            if let Err(e) = self.state.player.spend_reputation(HINT_REPUTATION_COST) {
+                interview.hint_error = Some(e);
+                return;
+            }
         }
-        
-        questions
-    }
-
-    fn create_question_for_skill(&self, skill_name: &str) -> QuizQuestion {
-        match skill_name {
-            "Python" => QuizQuestion {
-                question: "What is the difference between a list and a tuple in Python?".to_string(),
-                options: vec![
-                    "Lists are mutable, tuples are immutable".to_string(),
-                    "Lists are faster than tuples".to_string(),
-                    "Tuples can hold more items".to_string(),
-                    "There is no difference".to_string(),
-                ],
-                correct_idx: 0,
-            },
-            "PyTorch" | "TensorFlow" => QuizQuestion {
-                question: "What is backpropagation?".to_string(),
-                options: vec![
-                    "Algorithm to compute gradients by chain rule".to_string(),
-                    "A type of neural network layer".to_string(),
-                    "Data preprocessing technique".to_string(),
-                    "A loss function".to_string(),
-                ],
-                correct_idx: 0,
-            },
-            "Transformers" => QuizQuestion {
-                question: "What is the key innovation in Transformer architecture?".to_string(),
-                options: vec![
-                    "Self-attention mechanism".to_string(),
-                    "Convolutional layers".to_string(),
-                    "Recurrent connections".to_string(),
-                    "Dropout regularization".to_string(),
-                ],
-                correct_idx: 0,
-            },
-            "LLM Fine-tuning" => QuizQuestion {
-                question: "What is LoRA?".to_string(),
-                options: vec![
-                    "Low-Rank Adaptation for efficient fine-tuning".to_string(),
-                    "A type of language model".to_string(),
-                    "A tokenization method".to_string(),
-                    "A training loss function".to_string(),
-                ],
-                correct_idx: 0,
-            },
-            "SQL" => QuizQuestion {
-                question: "Which SQL clause is used to filter results?".to_string(),
-                options: vec![
-                    "WHERE".to_string(),
-                    "ORDER BY".to_string(),
-                    "GROUP BY".to_string(),
-                    "SELECT".to_string(),
-                ],
-                correct_idx: 0,
-            },
-            "Statistics" => QuizQuestion {
-                question: "What is the mean of [2, 4, 6, 8]?".to_string(),
-                options: vec![
-                    "5".to_string(),
-                    "4".to_string(),
-                    "6".to_string(),
-                    "4.5".to_string(),
-                ],
-                correct_idx: 0,
-            },
-            _ => QuizQuestion {
-                question: format!("Explain your experience with {}", skill_name),
-                options: vec![
-                    "I have strong practical experience".to_string(),
-                    "I've studied it but need practice".to_string(),
-                    "I've heard of it".to_string(),
-                    "I don't know this".to_string(),
-                ],
-                correct_idx: 0,
-            },
+        interview.hint_error = None;
+        interview.hints_used += 1;
+        interview.eliminated.push(option);
+        if interview.eliminated.contains(&self.selected_choice) {
+            self.selected_choice = (0..question.options.len()).find(|i| !interview.eliminated.contains(i)).unwrap_or(0);
         }
     }
 
@@ -504,38 +1400,69 @@ impl Game {
         if let Some(ref mut interview) = self.interview {
             let current = interview.current_question;
             if current < interview.questions.len() {
-                if interview.selected_answer == interview.questions[current].correct_idx {
-                    interview.score += 1;
-                }
+                interview.selected_answer = self.selected_choice;
+                let correct = interview.selected_answer == interview.questions[current].correct_idx;
+                interview.answers.push(QuizAnswer { correct, confidence: interview.confidence });
+                interview.confidence = Confidence::default();
+                interview.eliminated.clear();
+                interview.hint_error = None;
+                interview.last_missed = (!correct).then(|| interview.questions[current].clone());
+                interview.explanation = None;
                 interview.current_question += 1;
                 interview.selected_answer = 0;
+                self.selected_choice = 0;
+
+                interview.quiz.record_answer(correct);
+                if let Some(next) = interview.quiz.next_question(&mut interview.rng) {
+                    interview.questions.push(next);
+                }
                 
                 if interview.current_question >= interview.questions.len() {
-                    let total = interview.questions.len() as u32;
-                    let score = interview.score;
-                    let job = interview.job.clone();
+                    let outcome = Self::interview_outcome(interview, &self.state.player, self.interview_config.marking_penalty());
+                    let Some(round) = interview.session.current_round() else { return };
+                    let result = outcome.round_result(round);
+                    let round_name = result.round_name.clone();
                     
-                    if score >= total / 2 {
-                        let salary = (job.salary_min + job.salary_max) / 2;
-                        self.state.player.employed = true;
-                        self.state.player.current_salary = salary;
-                        self.current_dialog = Some(Dialog {
-                            speaker: "Interview Complete".to_string(),
-                            text: format!("Congratulations! You got the job!\nPosition: {} at {}\nSalary: ${}/year", 
-                                job.title, job.company, salary),
-                            choices: vec!["Awesome!".to_string()],
-                        });
-                    } else {
-                        self.current_dialog = Some(Dialog {
-                            speaker: "Interview Complete".to_string(),
-                            text: format!("Unfortunately, you didn't pass. Score: {}/{}\nKeep studying and try again!", 
-                                score, total),
-                            choices: vec!["OK".to_string()],
-                        });
+                    match interview.session.record(result) {
+                        RoundOutcome::Hired => {
+                            let outcome = outcome.settle(true, &self.state.player);
+                            self.state.apply_interview_outcome(&outcome);
+                            self.application_queue.clear();
+                            let job = &outcome.job;
+                            let days = self.state.player.pending_offer(job.id).map_or(0, |offer| offer.days_remaining(self.state.day));
+                            self.current_dialog = Some(Dialog {
+                                speaker: "Interview Complete".to_string(),
+                                text: format!("Congratulations! You got an offer!\nPosition: {} at {} ({})\nSalary: ${}/year\n{}\nIt stays open for {} days on the Offers screen.", 
+                                    job.title, job.company, outcome.tier.as_str(), outcome.offered_salary.unwrap_or_default(),
+                                    interview.session.summary(), days),
+                                choices: vec![ACCEPT_OFFER_CHOICE.to_string(), "Decide later".to_string()],
+                            });
+                            self.interview = None;
+                            self.state.replace_screen(GameScreen::Dialog);
+                        }
+                        RoundOutcome::Passed | RoundOutcome::Scored => {
+                            // Pause on the next round's announcement until the player is ready
+                            self.current_dialog = Some(Dialog {
+                                speaker: outcome.job.company.clone(),
+                                text: format!("{} round done. Score: {}\n{}", 
+                                    round_name, outcome.score_text(), interview.session.next_round_announcement().unwrap_or_default()),
+                                choices: vec![NEXT_ROUND_CHOICE.to_string()],
+                            });
+                            self.selected_choice = 0;
+                            self.state.replace_screen(GameScreen::Dialog);
+                        }
+                        RoundOutcome::Failed { retry_available: true } => {
+                            // Keep the interview around so the round can be re-run
+                            self.current_dialog = Some(Dialog {
+                                speaker: outcome.job.company.clone(),
+                                text: format!("That round didn't go well. Score: {}\nWe can let you retry it once.", 
+                                    outcome.score_text()),
+                                choices: vec!["Retry round".to_string(), "Give up".to_string()],
+                            });
+                            self.state.replace_screen(GameScreen::Dialog);
+                        }
+                        RoundOutcome::Failed { retry_available: false } => self.fail_interview(),
                     }
-                    
-                    self.interview = None;
-                    self.state.screen = GameScreen::Dialog;
                 }
             }
         }
@@ -571,8 +1498,85 @@ impl Game {
                 self.draw_world();
                 self.draw_menu();
             }
-            _ => {}
+            GameScreen::SaveSlots => {
+                if self.input_active {
+                    self.draw_title_screen();
+                } else {
+                    self.draw_world();
+                }
+                self.draw_save_slots();
+            }
+            GameScreen::Offers => {
+                self.draw_world();
+                self.draw_offers();
+            }
+            GameScreen::EventLog => {
+                self.draw_world();
+                self.draw_event_log();
+            }
         }
+
+        if self.debug {
+            self.draw_debug();
+        }
+    }
+
+    /// Companies unlocked at the player's current career level
+    fn visible_companies(&self) -> Vec<jobs::Company> {
+        companies::get_visible_companies(self.state.player.career_level())
+    }
+
+    /// Companies listed on the job board, narrowed to bookmarks when that filter is on
+    fn board_companies(&self) -> Vec<jobs::Company> {
+        let mut companies = self.visible_companies();
+        if self.bookmarks_only {
+            for company in &mut companies {
+                company.open_positions.retain(|job| self.state.player.is_bookmarked(job.id));
+            }
+            companies.retain(|company| !company.open_positions.is_empty());
+        }
+        companies
+    }
+
+    fn board_job_count(&self) -> usize {
+        self.board_companies().iter().map(|c| c.open_positions.len()).sum()
+    }
+
+    fn selected_board_job(&self) -> Option<Job> {
+        self.board_companies()
+            .into_iter()
+            .flat_map(|company| company.open_positions)
+            .nth(self.selected_choice)
+    }
+
+    /// An NPC whose spot the player is standing at while they're away
+    fn absent_npc_nearby(&self) -> Option<&Npc> {
+        let (px, py) = (self.world_player.x, self.world_player.y);
+        self.npcs
+            .iter()
+            .find(|npc| npc.distance_to(px, py) < 50.0 && !npc.is_available(self.state.time_of_day))
+    }
+
+    /// Name of the NPC or building the player could interact with right now
+    fn nearest_interactable(&self) -> Option<String> {
+        let (px, py) = (self.world_player.x, self.world_player.y);
+        let now = self.state.time_of_day;
+        if let Some(npc) = self.npcs.iter().find(|npc| npc.distance_to(px, py) < 50.0 && npc.is_available(now)) {
+            return Some(format!("NPC {}", npc.name));
+        }
+        self.map
+            .get_building_at(px, py)
+            .map(|building| format!("Building {}", building.name))
+    }
+
+    fn draw_debug(&self) {
+        let info = DebugInfo {
+            fps: get_fps(),
+            player_pos: (self.world_player.x, self.world_player.y),
+            camera_pos: (self.camera.x, self.camera.y),
+            nearest_interactable: self.nearest_interactable(),
+        };
+        draw_debug_overlay(&debug_overlay_lines(&info, &self.state));
     }
 
     fn draw_title_screen(&mut self) {
@@ -589,19 +1593,22 @@ impl Game {
         draw_rectangle(input_box_x, screen_height() / 2.0 + 10.0, input_box_width, 35.0, Color::from_rgba(50, 50, 70, 255));
         draw_rectangle(input_box_x + 2.0, screen_height() / 2.0 + 12.0, input_box_width - 4.0, 31.0, Color::from_rgba(30, 30, 50, 255));
 
-        let cursor = if (get_time() * 2.0) as i32 % 2 == 0 { "|" } else { "" };
-        let display_text = format!("{}{}", self.player_name_input, cursor);
+        let cursor = if self.anim_clock.blink(2.0) { "|" } else { "" };
+        let display_text = format!("{}{}", self.player_name_input.as_str(), cursor);
         draw_text_crisp(&display_text, input_box_x + 10.0, screen_height() / 2.0 + 35.0, 24.0, WHITE);
 
         if !self.player_name_input.is_empty() {
             draw_text_crisp("Press ENTER to start", screen_width() / 2.0 - 100.0, screen_height() / 2.0 + 100.0, 20.0, Color::from_rgba(150, 255, 150, 255));
         }
+        draw_text_crisp("Press TAB to load a saved game", screen_width() / 2.0 - 130.0, screen_height() / 2.0 + 130.0, 18.0, Color::from_rgba(150, 150, 150, 255));
 
         draw_text_crisp("WASD to move | E to interact | I for skills | J for jobs", 
             screen_width() / 2.0 - 230.0, screen_height() - 50.0, 18.0, Color::from_rgba(150, 150, 150, 255));
     }
 
     fn draw_world(&mut self) {
+        let player_pos = self.world_player.render_position(self.world_tick.alpha());
+        self.camera.follow(player_pos.x, player_pos.y);
         let sw = screen_width();
         let sh = screen_height();
         
@@ -610,14 +1617,14 @@ impl Game {
         
         self.map.draw(cam_x, cam_y);
         
-        for npc in &self.npcs {
+        for npc in self.npcs.iter().filter(|npc| npc.is_available(self.state.time_of_day)) {
             let (sx, sy) = self.camera.world_to_screen(npc.x, npc.y);
             if sx > -50.0 && sx < sw + 50.0 && sy > -50.0 && sy < sh + 50.0 {
                 graphics::draw_npc(sx, sy, npc.npc_type_id());
             }
         }
         
-        let (px, py) = self.camera.world_to_screen(self.world_player.x, self.world_player.y);
+        let (px, py) = self.camera.world_to_screen(player_pos.x, player_pos.y);
         graphics::draw_player(
             px,
             py,
@@ -626,12 +1633,17 @@ impl Game {
             self.world_player.anim_timer,
         );
 
-        draw_hud(&self.state);
+        if self.show_legend {
+            draw_building_labels(&self.map.buildings, &self.camera);
+            draw_map_legend(&self.map.buildings);
+        }
+
+        draw_hud(&self.state, &self.hud_settings, &self.anim_clock);
         draw_controls_hint();
 
         let mut hint_shown = false;
 
-        for npc in &self.npcs {
+        for npc in self.npcs.iter().filter(|npc| npc.is_available(self.state.time_of_day)) {
             if npc.distance_to(self.world_player.x, self.world_player.y) < 50.0 {
                 draw_interaction_hint(&format!("Press E to talk to {}", npc.name));
                 hint_shown = true;
@@ -642,43 +1654,65 @@ impl Game {
         if !hint_shown {
             if let Some(building) = self.map.get_building_at(self.world_player.x, self.world_player.y) {
                 draw_interaction_hint(&format!("Press E to enter {}", building.name));
+            } else if let Some(npc) = self.absent_npc_nearby() {
+                draw_interaction_hint(&format!("{} isn't here right now", npc.name));
             }
         }
     }
 
+    /// Highlight color for the selected row, gently pulsing over time
+    fn selection_color(&self) -> Color {
+        let glow = self.anim_clock.pulse(1.2);
+        Color::new(1.0, 1.0, 0.3 + 0.3 * glow, 1.0)
+    }
+
     fn draw_dialog(&mut self) {
         if let Some(dialog) = &self.current_dialog {
-            let box_height = 180.0;
-            let box_y = screen_height() - box_height - 20.0;
             let box_margin = 50.0;
+            let box_width = screen_width() - box_margin * 2.0;
+            let lines = wrap_text_crisp(&dialog.text, 20.0, box_width - 30.0);
+            // Grow with the text up to 60% of the screen, then keep the newest lines in view
+            let max_lines = ((screen_height() * 0.6 - 70.0 - dialog.choices.len() as f32 * 28.0) / DIALOG_LINE_HEIGHT).max(1.0) as usize;
+            let line_slots = lines.len().clamp(1, max_lines);
+            let box_height = (70.0 + line_slots as f32 * DIALOG_LINE_HEIGHT + dialog.choices.len() as f32 * 28.0).max(180.0);
+            let box_y = screen_height() - box_height - 20.0;
 
-            draw_rectangle(box_margin, box_y, screen_width() - box_margin * 2.0, box_height, Color::from_rgba(0, 0, 0, 220));
-            draw_rectangle_lines(box_margin, box_y, screen_width() - box_margin * 2.0, box_height, 2.0, WHITE);
+            draw_rectangle(box_margin, box_y, box_width, box_height, Color::from_rgba(0, 0, 0, 220));
+            draw_rectangle_lines(box_margin, box_y, box_width, box_height, 2.0, WHITE);
 
             draw_text_crisp(&dialog.speaker, box_margin + 15.0, box_y + 25.0, 22.0, Color::from_rgba(255, 215, 0, 255));
 
-            draw_text_crisp(&dialog.text, box_margin + 15.0, box_y + 55.0, 20.0, WHITE);
+            // Catch dialogs replaced since this frame's update
+            self.typewriter.update(&dialog.text, 0.0);
+            let shown = visible_lines(&lines, self.typewriter.visible().chars().count());
+            let first = shown.len().saturating_sub(line_slots);
+            for (i, line) in shown[first..].iter().enumerate() {
+                draw_text_crisp(line, box_margin + 15.0, box_y + 55.0 + i as f32 * DIALOG_LINE_HEIGHT, 20.0, WHITE);
+            }
+            if !self.typewriter.is_done() {
+                return;
+            }
 
+            let choices_y = box_y + 55.0 + line_slots as f32 * DIALOG_LINE_HEIGHT + 6.0;
             for (i, choice) in dialog.choices.iter().enumerate() {
-                let choice_y = box_y + 85.0 + (i as f32 * 28.0);
+                let choice_y = choices_y + (i as f32 * 28.0);
                 let prefix = if i == self.selected_choice { "> " } else { "  " };
-                let color = if i == self.selected_choice { Color::from_rgba(255, 255, 100, 255) } else { WHITE };
+                let color = if i == self.selected_choice { self.selection_color() } else { WHITE };
                 draw_text_crisp(&format!("{}{}", prefix, choice), box_margin + 15.0, choice_y, 18.0, color);
             }
         }
     }
 
     fn draw_skills_screen(&mut self) {
-        let panel_width = 600.0;
-        let panel_height = 500.0;
-        let panel_x = (screen_width() - panel_width) / 2.0;
-        let panel_y = (screen_height() - panel_height) / 2.0;
-
-        draw_rectangle(panel_x, panel_y, panel_width, panel_height, Color::from_rgba(0, 0, 0, 240));
-        draw_rectangle_lines(panel_x, panel_y, panel_width, panel_height, 2.0, WHITE);
-
-        draw_text_crisp("YOUR SKILLS", panel_x + 20.0, panel_y + 30.0, 24.0, Color::from_rgba(255, 215, 0, 255));
-        draw_text_crisp("Press ESC or I to close", panel_x + 20.0, panel_y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+        let panel = Panel::centered(880.0, 500.0)
+            .title(&format!("YOUR SKILLS - Career Level {}", self.state.player.career_level()))
+            .draw();
+        draw_text_crisp("Press ESC or I to close", panel.x + 20.0, panel.y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+        let focus = match &self.state.player.focus_skill {
+            Some(skill) => format!("Focus skill: {} (+{} XP/day) - set it from the study screen", skill, self.state.player.focus_daily_xp()),
+            None => "No focus skill - press F on the study screen to pick one".to_string(),
+        };
+        draw_text_crisp(&focus, panel.x + 20.0, panel.y + 72.0, 14.0, Color::from_rgba(100, 255, 100, 255));
 
         let by_category = self.state.player.get_skills_by_category();
         let categories: [&skills::SkillCategory; 6] = [
@@ -690,88 +1724,160 @@ impl Game {
             &skills::SkillCategory::DomainKnowledge,
         ];
 
-        let mut y = panel_y + 85.0;
+        let mut y = panel.y + 95.0;
         for category in &categories {
             if let Some(skills_list) = by_category.get(*category) {
-                draw_text_crisp(&format!("{:?}", category), panel_x + 20.0, y, 16.0, Color::from_rgba(100, 200, 255, 255));
+                draw_text_crisp(&format!("{:?} ({} pts)", category, self.state.player.category_proficiency(**category)),
+                    panel.x + 20.0, y, 16.0, Color::from_rgba(100, 200, 255, 255));
                 y += 22.0;
                 
                 for (name, skill) in skills_list {
-                    let xp_bar = self.skill_xp_bar(skill.experience_points, skill.points_to_next_level());
-                    draw_text_crisp(&format!("{}: {} {}", name, skill.proficiency.as_str(), xp_bar), 
-                        panel_x + 40.0, y, 14.0, WHITE);
+                    let xp_bar = self.skill_xp_bar(skill);
+                    let cert = if self.state.player.is_certified(name) { " [CERT]" } else { "" };
+                    draw_text_crisp(&format!("{}: {} {}{}", name, skill.proficiency.as_str(), xp_bar, cert), 
+                        panel.x + 40.0, y, 14.0, WHITE);
+                    draw_text_crisp(&format!("last practiced: {}", skill.last_practiced_label(self.state.day)),
+                        panel.x + 400.0, y, 12.0, Color::from_rgba(150, 150, 150, 255));
                     y += 18.0;
                 }
                 y += 10.0;
             }
         }
+
+        self.draw_next_tier_goals(panel.x + 600.0, panel.y + 95.0);
+        self.draw_money_history(panel.x + 600.0, panel.y + 340.0);
     }
 
-    fn draw_study_screen(&mut self) {
-        let panel_width = 600.0;
-        let panel_height = 550.0;
-        let panel_x = (screen_width() - panel_width) / 2.0;
-        let panel_y = (screen_height() - panel_height) / 2.0;
+    /// Money at the start of each recent day
+    fn draw_money_history(&self, x: f32, y: f32) {
+        let history = &self.state.money_history;
+        draw_text_crisp(&format!("MONEY (last {} days)", history.len()), x, y, 16.0, Color::from_rgba(100, 200, 255, 255));
+        let values: Vec<f32> = history.iter().map(|s| s.money as f32).collect();
+        draw_line_chart(&values, x, y + 12.0, 200.0, 110.0, GOLD, |v| format!("${:.0}", v));
+    }
 
-        draw_rectangle(panel_x, panel_y, panel_width, panel_height, Color::from_rgba(0, 0, 0, 240));
-        draw_rectangle_lines(panel_x, panel_y, panel_width, panel_height, 2.0, WHITE);
+    /// Checklist of skill levels needed for a good match one company tier up
+    fn draw_next_tier_goals(&self, x: f32, y: f32) {
+        let Some(goal) = jobs::next_tier_gap(&companies::get_all_companies(), &self.state.player.skills) else {
+            draw_text_crisp("Top tier reached!", x, y, 16.0, Color::from_rgba(100, 200, 255, 255));
+            return;
+        };
+        draw_text_crisp(&format!("NEXT TIER: {}", goal.tier.as_str()), x, y, 16.0, Color::from_rgba(100, 200, 255, 255));
+        draw_text_crisp(&goal.job.title, x, y + 20.0, 14.0, WHITE);
+        draw_text_crisp(&format!("at {}", goal.job.company), x, y + 36.0, 12.0, Color::from_rgba(150, 150, 150, 255));
+        draw_text_crisp(&format!("{} level(s) to a good match:", goal.levels_short()), x, y + 60.0, 14.0, WHITE);
+        for (i, gap) in goal.gaps.iter().enumerate() {
+            draw_text_crisp(&format!("[ ] {}: {} -> {}", gap.skill_name, gap.current.as_str(), gap.required.as_str()),
+                x, y + 80.0 + i as f32 * 18.0, 12.0, Color::from_rgba(255, 255, 100, 255));
+        }
+    }
 
-        draw_text_crisp("LIBRARY - Study Skills", panel_x + 20.0, panel_y + 30.0, 24.0, Color::from_rgba(255, 215, 0, 255));
-        draw_text_crisp(&format!("Energy: {}/100 (30 per study session)", self.state.player.energy), 
-            panel_x + 20.0, panel_y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
-        draw_text_crisp("Press ESC to leave | WS/Arrows to select | E to study", 
-            panel_x + 20.0, panel_y + 75.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+    fn draw_study_screen(&mut self) {
+        let title = if self.study_group {
+            format!("STUDY GROUP with Sam - x{:.2} XP, ${}/session",
+                self.state.player.study_group_multiplier(), STUDY_GROUP_COST)
+        } else {
+            format!("{} - Study Skills", self.study_venue.label())
+        };
+        let panel = Panel::centered(600.0, 550.0).title(&title).draw();
+        draw_text_crisp(&format!("Energy: {}/{} ({}h sessions, harder skills cost more)", 
+            self.state.player.energy, self.state.player.max_energy, STUDY_SESSION_HOURS), 
+            panel.x + 20.0, panel.y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+        if let Some(auto) = &self.auto_study {
+            draw_text_crisp(&format!("Auto-studying {} ({}/{} sessions) - ESC to stop", 
+                auto.skill(), auto.sessions_done(), AUTO_STUDY_SESSIONS), 
+                panel.x + 20.0, panel.y + 75.0, 14.0, Color::from_rgba(100, 255, 100, 255));
+        } else if !self.can_auto_study() {
+            draw_text_crisp("Press ESC to leave | WS/Arrows to select | E to study", 
+                panel.x + 20.0, panel.y + 75.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+        } else {
+            draw_text_crisp(&format!("Press ESC to leave | WS/Arrows to select | E to study | R to auto-study x{}", AUTO_STUDY_SESSIONS), 
+                panel.x + 20.0, panel.y + 75.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+        }
+        let respec_hint = match self.state.player.xp_pool {
+            0 => format!("F to set focus skill | X to respec skill (${}) | C to get certified (${})", RESPEC_COST, CERT_EXAM_FEE),
+            pool => format!("F to set focus skill | X to respec skill (${}) | C to get certified (${}) | P to spend {} refunded XP",
+                RESPEC_COST, CERT_EXAM_FEE, pool),
+        };
+        draw_text_crisp(&respec_hint, panel.x + 20.0, panel.y + panel.height - 35.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+        if self.state.is_tired() {
+            draw_text_crisp(&format!("You're tired - usable energy capped at {}", self.state.effective_energy_cap()),
+                panel.x + 20.0, panel.y + panel.height - 15.0, 14.0, Color::from_rgba(255, 120, 120, 255));
+        }
 
         let skills: Vec<_> = self.state.player.skills.iter().collect();
-        let mut y = panel_y + 100.0;
+        let mut y = panel.y + 100.0;
+        if skills.is_empty() {
+            draw_text_crisp("No skills to study.", panel.x + 30.0, y, 16.0, Color::from_rgba(150, 150, 150, 255));
+        }
 
         for (i, (name, skill)) in skills.iter().enumerate() {
             let selected = i == self.selected_choice;
             let prefix = if selected { "> " } else { "  " };
-            let color = if selected { Color::from_rgba(255, 255, 100, 255) } else { WHITE };
-            let xp_bar = self.skill_xp_bar(skill.experience_points, skill.points_to_next_level());
+            let color = if selected {
+                self.selection_color()
+            } else if skill.is_maxed() {
+                Color::from_rgba(255, 215, 0, 255)
+            } else {
+                WHITE
+            };
+            let xp_bar = self.skill_xp_bar(skill);
+            let focus = if self.state.player.focus_skill.as_deref() == Some(name.as_str()) { " [FOCUS]" } else { "" };
+            let cert = if self.state.player.is_certified(name) { " [CERT]" } else { "" };
             
-            draw_text_crisp(&format!("{}{}: {} {}", prefix, name, skill.proficiency.as_str(), xp_bar), 
-                panel_x + 30.0, y, 16.0, color);
+            draw_text_crisp(&format!("{}{}: {} {}{}{}", prefix, name, skill.proficiency.as_str(), xp_bar, focus, cert), 
+                panel.x + 30.0, y, 16.0, color);
             
             if selected {
-                draw_text_crisp(&format!("Difficulty: {} | XP to next: {}", 
-                    skill.skill.difficulty, skill.points_to_next_level() - skill.experience_points),
-                    panel_x + 50.0, y + 18.0, 12.0, Color::from_rgba(150, 150, 150, 255));
+                let details = if skill.is_maxed() {
+                    "Mastered - studying this won't teach you anything new".to_string()
+                } else {
+                    format!("Difficulty: {} | Energy cost: {} | XP to {}: {} ({}/{})", 
+                        skill.skill.difficulty, self.state.player.focused_cost(skill.study_energy_cost(STUDY_SESSION_HOURS)),
+                        skill.proficiency.next().map(|p| p.as_str()).unwrap_or("next"),
+                        skill.points_to_next_level().saturating_sub(skill.experience_points),
+                        skill.experience_points, skill.points_to_next_level())
+                };
+                draw_text_crisp(&details, panel.x + 50.0, y + 18.0, 12.0, Color::from_rgba(150, 150, 150, 255));
                 y += 20.0;
             }
             y += 25.0;
         }
     }
 
-    fn skill_xp_bar(&self, current: u32, max: u32) -> String {
-        if max == 0 { return String::new(); }
-        let filled = (current as f32 / max as f32 * 10.0) as usize;
-        format!("[{}{}]", "=".repeat(filled), " ".repeat(10 - filled))
+    fn skill_xp_bar(&self, skill: &PlayerSkill) -> String {
+        let filled = match skill.progress_percent() {
+            Some(percent) => (percent / 10) as usize,
+            None => 10,
+        };
+        format!("[{}{}] {}", "=".repeat(filled), " ".repeat(10 - filled), skill.progress_label())
     }
 
     fn draw_job_board(&mut self) {
-        let panel_width = 700.0;
-        let panel_height = 550.0;
-        let panel_x = (screen_width() - panel_width) / 2.0;
-        let panel_y = (screen_height() - panel_height) / 2.0;
-
-        draw_rectangle(panel_x, panel_y, panel_width, panel_height, Color::from_rgba(0, 0, 0, 240));
-        draw_rectangle_lines(panel_x, panel_y, panel_width, panel_height, 2.0, WHITE);
+        let title = if self.bookmarks_only { "JOB BOARD (Bookmarked) - E: book / attend interview" } else { "JOB BOARD - E: book / attend interview" };
+        let panel = Panel::centered(700.0, 550.0).title(title).draw();
+        draw_text_crisp("WASD to navigate | A: apply to all good matches | B: bookmark | F: bookmarks only | ESC or J to close", panel.x + 20.0, panel.y + 50.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+        draw_text_crisp(&format!("R: research rounds ({}h) | P: warm up on the topics ({}h, {} energy)", RESEARCH_HOURS, WARM_UP_HOURS, WARM_UP_ENERGY), panel.x + 20.0, panel.y + 68.0, 14.0, Color::from_rgba(150, 150, 150, 255));
 
-        draw_text_crisp("JOB BOARD - Press E to Apply", panel_x + 20.0, panel_y + 30.0, 24.0, Color::from_rgba(255, 215, 0, 255));
-        draw_text_crisp("WASD to navigate | ESC or J to close", panel_x + 20.0, panel_y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
-
-        let mut y = panel_y + 90.0;
+        let mut y = panel.y + 90.0;
         let mut idx = 0;
-        for company in companies::get_all_companies() {
+        let companies = self.board_companies();
+        if companies.is_empty() {
+            let message = if self.bookmarks_only {
+                "No bookmarked jobs yet. Press F to show all and B to bookmark."
+            } else {
+                "No jobs available right now. Check back after leveling up."
+            };
+            draw_text_crisp(message, panel.x + 20.0, y, 16.0, Color::from_rgba(150, 150, 150, 255));
+        }
+        for company in companies {
             draw_text_crisp(&format!("{} ({})", company.name, company.tier.as_str()), 
-                panel_x + 20.0, y, 18.0, Color::from_rgba(100, 200, 255, 255));
+                panel.x + 20.0, y, 18.0, Color::from_rgba(100, 200, 255, 255));
             y += 22.0;
 
             for job in &company.open_positions {
                 let selected = idx == self.selected_choice;
-                let match_score = job.calculate_match(&self.state.player.skills) * 100.0;
+                let match_score = job.calculate_match_with_trends(&self.state.player.skills, Some(&self.state.trends)) * 100.0;
                 let match_indicator = if match_score >= 70.0 { "[GOOD MATCH]" } 
                     else if match_score >= 40.0 { "[PARTIAL]" } 
                     else { "[SKILLS NEEDED]" };
@@ -780,12 +1886,44 @@ impl Game {
                     else { Color::from_rgba(255, 100, 100, 255) };
 
                 let prefix = if selected { "> " } else { "  " };
-                let text_color = if selected { Color::from_rgba(255, 255, 100, 255) } else { WHITE };
+                let text_color = if selected { self.selection_color() } else { WHITE };
                 
-                draw_text_crisp(&format!("{}{} - {}", prefix, job.title, job.display_salary()), 
-                    panel_x + 30.0, y, 14.0, text_color);
-                draw_text_crisp(match_indicator, panel_x + 450.0, y, 14.0, match_color);
+                let star = if self.state.player.is_bookmarked(job.id) { "* " } else { "" };
+                let mut booked = self.state.player.scheduled_interview(job.id)
+                    .map(|slot| format!(" [Booked {}]", slot.slot_label()))
+                    .unwrap_or_default();
+                if self.state.player.is_warmed_up_for(job.id) {
+                    booked.push_str(" [Warmed up]");
+                }
+                draw_text_crisp(&format!("{}{}{} - {}{}", prefix, star, job.title, job.display_salary(), booked), 
+                    panel.x + 30.0, y, 14.0, text_color);
+                draw_text_crisp(match_indicator, panel.x + 450.0, y, 14.0, match_color);
+                draw_text_crisp(&job.difficulty_stars(company.tier), panel.x + 600.0, y, 14.0, Color::from_rgba(255, 200, 80, 255));
                 y += 20.0;
+                if selected {
+                    let prep = match job.estimated_prep_days(&self.state.player) {
+                        0 => "Ready to apply".to_string(),
+                        days => format!("~{} day{} to qualify ({} study sessions)", days,
+                            if days == 1 { "" } else { "s" }, job.estimated_prep_sessions(&self.state.player)),
+                    };
+                    draw_text_crisp(&prep, panel.x + 50.0, y, 12.0, Color::from_rgba(200, 200, 150, 255));
+                    y += 16.0;
+                }
+                if selected && self.state.player.has_researched(job.id) {
+                    let preview = Interview::preview(job, company.tier);
+                    let rounds: Vec<&str> = preview.iter().map(|r| r.name.as_str()).collect();
+                    let mut tested: Vec<&str> = Vec::new();
+                    for skill in preview.iter().flat_map(|r| &r.skills) {
+                        if !tested.contains(&skill.as_str()) {
+                            tested.push(skill);
+                        }
+                    }
+                    draw_text_crisp(&format!("Rounds: {}", rounds.join(" > ")),
+                        panel.x + 50.0, y, 12.0, Color::from_rgba(150, 200, 255, 255));
+                    draw_text_crisp(&format!("Tests: {}", tested.join(", ")),
+                        panel.x + 50.0, y + 16.0, 12.0, Color::from_rgba(150, 200, 255, 255));
+                    y += 34.0;
+                }
                 idx += 1;
             }
             y += 15.0;
@@ -794,68 +1932,410 @@ impl Game {
 
     fn draw_interview_screen(&mut self) {
         if let Some(ref interview) = self.interview {
-            let panel_width = 700.0;
-            let panel_height = 450.0;
-            let panel_x = (screen_width() - panel_width) / 2.0;
-            let panel_y = (screen_height() - panel_height) / 2.0;
-
-            draw_rectangle(panel_x, panel_y, panel_width, panel_height, Color::from_rgba(0, 0, 0, 240));
-            draw_rectangle_lines(panel_x, panel_y, panel_width, panel_height, 2.0, WHITE);
-
-            draw_text_crisp(&format!("INTERVIEW: {} at {}", interview.job.title, interview.job.company), 
-                panel_x + 20.0, panel_y + 30.0, 22.0, Color::from_rgba(255, 215, 0, 255));
+            let panel = Panel::centered(700.0, 450.0)
+                .title(&format!("INTERVIEW: {} at {}", interview.job.title, interview.job.company))
+                .title_size(22.0)
+                .draw();
             
-            draw_text_crisp(&format!("Question {}/{} | Score: {}", 
-                interview.current_question + 1, interview.questions.len(), interview.score), 
-                panel_x + 20.0, panel_y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
-
+            let round = interview.session.current_round().map_or("", |round| round.name.as_str());
+            draw_text_crisp(&format!("{} - Question {}/{} | Score: {:.1}", 
+                round, interview.current_question + 1, interview.quiz.len(),
+                marked_quiz_score(&interview.answers, self.interview_config.marking_penalty())), 
+                panel.x + 20.0, panel.y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+
+            if interview.questions.is_empty() {
+                draw_text_crisp("No questions for this interview.",
+                    panel.x + 20.0, panel.y + 100.0, 16.0, Color::from_rgba(150, 150, 150, 255));
+            }
             if interview.current_question < interview.questions.len() {
                 let q = &interview.questions[interview.current_question];
                 
-                draw_text_crisp(&q.question, panel_x + 20.0, panel_y + 100.0, 18.0, WHITE);
+                draw_text_crisp(&q.question, panel.x + 20.0, panel.y + 100.0, 18.0, WHITE);
+                if let Some(gauge) = q.gauge_for(&self.state.player) {
+                    let gauge_color = match gauge {
+                        DifficultyGauge::BelowLevel => Color::from_rgba(100, 255, 100, 255),
+                        DifficultyGauge::AtLevel => Color::from_rgba(150, 200, 255, 255),
+                        DifficultyGauge::AboveLevel => Color::from_rgba(255, 200, 100, 255),
+                        DifficultyGauge::FarAboveLevel => Color::from_rgba(255, 100, 100, 255),
+                    };
+                    draw_text_crisp(gauge.label(), panel.x + 20.0, panel.y + 125.0, 14.0, gauge_color);
+                }
 
-                let mut y = panel_y + 150.0;
+                let mut y = panel.y + 150.0;
                 for (i, option) in q.options.iter().enumerate() {
+                    if interview.eliminated.contains(&i) {
+                        draw_text_crisp(&format!("{}.   ---", (i + 65) as u8 as char),
+                            panel.x + 30.0, y, 16.0, Color::from_rgba(90, 90, 90, 255));
+                        y += 30.0;
+                        continue;
+                    }
                     let selected = i == self.selected_choice;
                     let prefix = if selected { "> " } else { "  " };
-                    let color = if selected { Color::from_rgba(255, 255, 100, 255) } else { WHITE };
+                    let color = if selected { self.selection_color() } else { WHITE };
                     draw_text_crisp(&format!("{}. {}{}", (i + 65) as u8 as char, prefix, option), 
-                        panel_x + 30.0, y, 16.0, color);
+                        panel.x + 30.0, y, 16.0, color);
                     y += 30.0;
                 }
                 
-                draw_text_crisp("WASD to select | E to answer", 
-                    panel_x + 20.0, panel_y + panel_height - 30.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+                if let Some(explanation) = &interview.explanation {
+                    draw_text_crisp(&format!("Last answer: {}", explanation), panel.x + 20.0, y + 20.0, 14.0,
+                        Color::from_rgba(150, 200, 255, 255));
+                } else if interview.last_missed.is_some() {
+                    draw_text_crisp("That last answer was wrong. Press X for an explanation", panel.x + 20.0, y + 20.0, 14.0,
+                        Color::from_rgba(255, 150, 100, 255));
+                }
+
+                if let Some(error) = &interview.hint_error {
+                    draw_text_crisp(error, panel.x + 20.0, panel.y + panel.height - 80.0, 14.0,
+                        Color::from_rgba(255, 100, 100, 255));
+                }
+
+                let confidence_color = match interview.confidence {
+                    Confidence::Sure => Color::from_rgba(100, 255, 100, 255),
+                    Confidence::Unsure => Color::from_rgba(255, 200, 100, 255),
+                };
+                draw_text_crisp(&format!("Confidence: {} (sure: full credit, wrong costs points)", interview.confidence.as_str()),
+                    panel.x + 20.0, panel.y + panel.height - 55.0, 14.0, confidence_color);
+                let hint_text = if interview.hints_used < FREE_HINTS_PER_INTERVIEW {
+                    "H hint (free)".to_string()
+                } else {
+                    format!("H hint ({} rep)", HINT_REPUTATION_COST)
+                };
+                draw_text_crisp(&format!("WASD to select | TAB to toggle confidence | {} | E to answer", hint_text), 
+                    panel.x + 20.0, panel.y + panel.height - 30.0, 14.0, Color::from_rgba(150, 150, 150, 255));
             }
         }
     }
 
     fn draw_menu(&mut self) {
-        let panel_width = 300.0;
-        let panel_height = 200.0;
-        let panel_x = (screen_width() - panel_width) / 2.0;
-        let panel_y = (screen_height() - panel_height) / 2.0;
+        let panel = Panel::centered(300.0, 450.0).title("MENU").title_color(WHITE).draw();
+
+        let options = ["Resume", "View Skills (I)", "Job Board (J)", "Export Resume (R)", "Save / Load (L)", "Offers (O)", "Event Log (H)",
+            "Found a Startup (V)", "Save & Quit (Q)"];
+        for (i, option) in options.iter().enumerate() {
+            draw_text_crisp(option, panel.x + 30.0, panel.y + 70.0 + (i as f32 * 30.0), 18.0, WHITE);
+        }
 
-        draw_rectangle(panel_x, panel_y, panel_width, panel_height, Color::from_rgba(0, 0, 0, 240));
-        draw_rectangle_lines(panel_x, panel_y, panel_width, panel_height, 2.0, WHITE);
+        let on_off = |shown: bool| if shown { "ON" } else { "OFF" };
+        let toggles = [
+            format!("HUD time (1): {}", on_off(self.hud_settings.show_time)),
+            format!("HUD energy (2): {}", on_off(self.hud_settings.show_energy)),
+            format!("HUD money (3): {}", on_off(self.hud_settings.show_money)),
+        ];
+        for (i, toggle) in toggles.iter().enumerate() {
+            draw_text_crisp(toggle, panel.x + 30.0, panel.y + 350.0 + (i as f32 * 25.0), 16.0, LIGHTGRAY);
+        }
+    }
 
-        draw_text_crisp("MENU", panel_x + 20.0, panel_y + 30.0, 24.0, WHITE);
+    fn draw_offers(&mut self) {
+        let panel = Panel::centered(600.0, 350.0).title("OFFERS").draw();
+        draw_text_crisp("W/S to select | E accept | X decline | ESC or O to close",
+            panel.x + 20.0, panel.y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
 
-        let options = ["Resume", "View Skills (I)", "Job Board (J)", "Quit"];
-        for (i, option) in options.iter().enumerate() {
-            draw_text_crisp(option, panel_x + 30.0, panel_y + 70.0 + (i as f32 * 30.0), 18.0, WHITE);
+        if self.state.player.pending_offers.is_empty() {
+            draw_text_crisp("No open offers. Pass an interview to get one!",
+                panel.x + 20.0, panel.y + 90.0, 16.0, Color::from_rgba(150, 150, 150, 255));
+            return;
+        }
+        for (i, offer) in self.state.player.pending_offers.iter().enumerate() {
+            let selected = i == self.selected_choice;
+            let days = offer.days_remaining(self.state.day);
+            let text = format!("{}{} at {} | ${}/yr | {} day{} left",
+                if selected { "> " } else { "  " }, offer.job.title, offer.job.company, offer.salary,
+                days, if days == 1 { "" } else { "s" });
+            let color = if selected { self.selection_color() } else if days == 1 { ORANGE } else { WHITE };
+            draw_text_crisp(&text, panel.x + 20.0, panel.y + 90.0 + i as f32 * 30.0, 16.0, color);
         }
     }
+
+    fn draw_event_log(&mut self) {
+        let panel = Panel::centered(600.0, 450.0).title("EVENT LOG").draw();
+        draw_text_crisp("Newest first | W/S to scroll | ESC or H to close",
+            panel.x + 20.0, panel.y + 55.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+
+        if self.state.event_log.is_empty() {
+            draw_text_crisp("Nothing has happened yet. Go study, interview, get hired!",
+                panel.x + 20.0, panel.y + 90.0, 16.0, Color::from_rgba(150, 150, 150, 255));
+            return;
+        }
+        let events = self.state.recent_events().skip(self.event_log_scroll).take(EVENT_LOG_ROWS);
+        for (i, event) in events.enumerate() {
+            draw_text_crisp(&game::format_event(event), panel.x + 20.0, panel.y + 90.0 + i as f32 * 22.0, 16.0, WHITE);
+        }
+        let shown_to = (self.event_log_scroll + EVENT_LOG_ROWS).min(self.state.event_log.len());
+        draw_text_crisp(&format!("{}-{} of {}", self.event_log_scroll + 1, shown_to, self.state.event_log.len()),
+            panel.x + 20.0, panel.y + panel.height - 20.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+    }
+
+    fn draw_save_slots(&mut self) {
+        let panel = Panel::centered(560.0, 300.0).title("SAVE / LOAD").draw();
+        let now = game::unix_now();
+
+        for (i, slot) in self.save_slots.iter().enumerate() {
+            let y = panel.y + 80.0 + i as f32 * 40.0;
+            let selected = i == self.selected_choice;
+            let prefix = if selected { "> " } else { "  " };
+            let (text, color) = match slot {
+                Ok(Some(header)) => (
+                    format!("{}Slot {}: {} | Day {} | ${}/yr | {}",
+                        prefix, i + 1, header.player_name, header.day, header.salary, header.saved_ago(now)),
+                    WHITE,
+                ),
+                Ok(None) => (format!("{}Slot {}: (empty)", prefix, i + 1), GRAY),
+                Err(_) => (format!("{}Slot {}: unreadable save", prefix, i + 1), RED),
+            };
+            let color = if selected { self.selection_color() } else { color };
+            draw_text_crisp(&text, panel.x + 20.0, y, 16.0, color);
+        }
+
+        if let Some(message) = &self.save_message {
+            draw_text_crisp(message, panel.x + 20.0, panel.y + panel.height - 55.0, 14.0, Color::from_rgba(150, 255, 150, 255));
+        }
+        draw_text_crisp("WASD to select | E save | L load | A load autosave | X delete | ESC back",
+            panel.x + 20.0, panel.y + panel.height - 30.0, 14.0, Color::from_rgba(150, 150, 150, 255));
+    }
+}
+
+/// Print every problem in the bundled config files at once
+///
+/// The game still starts: engines fall back to rule for bad settings.
+fn report_config_problems(game: &Game) {
+    let mut problems = match GameConfig::load() {
+        Ok(config) => config.validate().err().unwrap_or_default(),
+        Err(e) => vec![format!("{:#}", e)],
+    };
+    problems.extend(game.question_db.validate().err().unwrap_or_default());
+    problems.extend(game.startup_problems.iter().cloned());
+    if problems.is_empty() {
+        return;
+    }
+    eprintln!("Found {} config problem(s):", problems.len());
+    for problem in &problems {
+        eprintln!("  - {}", problem);
+    }
 }
 
 #[macroquad::main(window_conf)]
 async fn main() {
-    init_fonts();
+    if let Err(e) = init_fonts() {
+        eprintln!("Warning: {}", e);
+    }
     let mut game = Game::new();
-
-    loop {
-        game.update().await;
+    let mut input = MacroquadInput;
+    report_config_problems(&game);
+
+    // Closing the window sets is_quit_requested instead of exiting, so the
+    // autosave below still runs
+    prevent_quit();
+    while !game.quit_requested {
+        if is_quit_requested() {
+            game.quit_requested = true;
+        }
+        game.update(&input.snapshot(), get_frame_time());
         game.draw().await;
         next_frame().await
     }
+    game.shut_down();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ai_career_rpg::interview::session::PassingPolicy;
+    use ai_career_rpg::testing::ScriptedInputSource;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    #[test]
+    fn test_scripted_input_starts_the_game_and_opens_the_menu() {
+        let mut game = Game::new();
+        let mut input = ScriptedInputSource::new(vec![
+            InputSnapshot::new().with_text("Ada"),
+            InputSnapshot::new().with_key_pressed("enter"),
+            InputSnapshot::new().with_key_pressed("escape"),
+        ]);
+
+        game.update(&input.snapshot(), DT);
+        assert_eq!(game.state.screen, GameScreen::Title);
+        game.update(&input.snapshot(), DT);
+        assert_eq!(game.state.screen, GameScreen::World);
+        assert_eq!(game.state.player.name, "Ada");
+
+        game.update(&input.snapshot(), DT);
+        assert_eq!(game.state.screen, GameScreen::Menu);
+        assert!(game.state.paused);
+    }
+
+    #[test]
+    fn test_walking_ends_in_the_same_place_at_any_frame_rate() {
+        let walk = |fps: usize| {
+            let mut game = Game::new();
+            game.state.reset_screen(GameScreen::World);
+            let held = InputSnapshot::new().with_key_down("d");
+            for _ in 0..fps {
+                game.update(&held, 1.0 / fps as f32);
+            }
+            (game.world_player.position(), game.world_tick.total_ticks())
+        };
+
+        let (at_60, ticks) = walk(60);
+        assert_eq!(ticks, world::WORLD_TICK_HZ as u64);
+        assert_eq!(walk(144), (at_60, ticks));
+        assert_eq!(walk(20), (at_60, ticks));
+    }
+
+    #[test]
+    fn test_confirmation_runs_its_action_on_yes_and_drops_it_on_no() {
+        let pending = PendingConfirmation { prompt: "Sure?".to_string(), action: ConfirmAction::QuitGame };
+        assert_eq!(pending.clone().resolve(CONFIRM_YES), Some(ConfirmAction::QuitGame));
+        assert_eq!(pending.resolve(CONFIRM_NO), None);
+
+        let mut game = Game::new();
+        game.state.reset_screen(GameScreen::World);
+        game.state.player.part_time = Some(PartTimeJob::DataLabeler);
+        let quit_job = |game: &mut Game, choice: usize| {
+            game.confirm("Job Center", "Quit?".to_string(), ConfirmAction::QuitPartTimeJob);
+            game.selected_choice = choice;
+            game.handle_dialog_choice();
+        };
+
+        quit_job(&mut game, 1);
+        assert!(game.pending_confirmation.is_none());
+        assert_eq!(game.state.player.part_time, Some(PartTimeJob::DataLabeler));
+        assert_eq!(game.state.screen, GameScreen::World);
+
+        quit_job(&mut game, 0);
+        assert!(game.pending_confirmation.is_none());
+        assert_eq!(game.state.player.part_time, None);
+    }
+
+    #[test]
+    fn test_loading_a_save_puts_the_player_back_at_spawn() {
+        let mut game = Game::new();
+        game.state.reset_screen(GameScreen::World);
+        let spawn = game.world_player.position();
+        for _ in 0..30 {
+            game.update(&InputSnapshot::new().with_key_down("d"), DT);
+        }
+        assert_ne!(game.world_player.position(), spawn);
+
+        game.resume(Ok(GameState::new("Loaded")), "a test save");
+        assert_eq!(game.world_player.position(), spawn);
+        assert_eq!(game.world_tick.total_ticks(), 0);
+    }
+
+    #[test]
+    fn test_backing_out_of_a_confirmation_drops_it() {
+        let mut game = Game::new();
+        game.state.reset_screen(GameScreen::SaveSlots);
+        game.confirm("Save Slots", "Delete?".to_string(), ConfirmAction::QuitGame);
+        game.back();
+        assert!(game.pending_confirmation.is_none());
+        assert_eq!(game.state.screen, GameScreen::SaveSlots);
+    }
+
+    /// Answer every question of the current interview round, all right or all wrong
+    fn answer_round(game: &mut Game, correct: bool) {
+        while game.state.screen == GameScreen::Interview {
+            let interview = game.interview.as_ref().expect("interview in progress");
+            let question = &interview.questions[interview.current_question];
+            game.selected_choice = if correct {
+                question.correct_idx
+            } else {
+                (question.correct_idx + 1) % question.options.len()
+            };
+            game.answer_interview_question();
+        }
+    }
+
+    #[test]
+    fn test_a_failed_round_can_be_retried_once() {
+        let mut game = Game::new();
+        game.state.reset_screen(GameScreen::World);
+        let job = companies::get_all_companies()[0].open_positions[0].clone();
+        game.start_interview_for(job.clone());
+
+        answer_round(&mut game, false);
+        assert_eq!(game.current_dialog.as_ref().unwrap().choices, ["Retry round", "Give up"]);
+        game.selected_choice = 0;
+        game.handle_dialog_choice();
+        assert_eq!(game.state.screen, GameScreen::Interview);
+        assert_eq!(game.interview.as_ref().unwrap().session.retries_used(), 1);
+
+        answer_round(&mut game, false);
+        assert!(game.interview.is_none());
+        assert!(game.state.player.cooldown_remaining(job.id).is_some());
+    }
+
+    #[test]
+    fn test_interviews_and_entering_buildings_take_time() {
+        let mut game = Game::new();
+        game.state.reset_screen(GameScreen::World);
+        game.start_interview_for(companies::get_all_companies()[0].open_positions[0].clone());
+        assert_eq!(game.state.day_log.hours(TimeCategory::Interviewing), INTERVIEW_LENGTH_HOURS as f32);
+
+        game.interview = None;
+        game.state.reset_screen(GameScreen::World);
+        let library = game.map.buildings.iter().find(|b| b.building_type == BuildingType::Library).unwrap().clone();
+        game.interact_with_building(&library);
+        assert_eq!(game.state.day_log.hours(TimeCategory::Commuting), COMMUTE_HOURS);
+        assert_eq!(game.state.screen, GameScreen::Study);
+    }
+
+    /// A job whose interview has more than one round
+    fn multi_round_job() -> Job {
+        companies::get_all_companies()
+            .into_iter()
+            .flat_map(|c| c.open_positions)
+            .find(|job| Interview::quiz_rounds(job, companies::tier_of(&job.company).unwrap()).len() > 1)
+            .expect("a job with more than one round")
+    }
+
+    #[test]
+    fn test_average_policy_plays_on_after_a_weak_round() {
+        let mut game = Game::new();
+        game.state.reset_screen(GameScreen::World);
+        game.interview_config.passing_policy = PassingPolicy::Average;
+        game.start_interview_for(multi_round_job());
+
+        answer_round(&mut game, false);
+        assert_eq!(game.current_dialog.as_ref().unwrap().choices, [NEXT_ROUND_CHOICE]);
+        assert_eq!(game.interview.as_ref().unwrap().session.policy(), PassingPolicy::Average);
+    }
+
+    #[test]
+    fn test_passing_a_round_pauses_before_the_next_one() {
+        let mut game = Game::new();
+        game.state.reset_screen(GameScreen::World);
+        game.start_interview_for(multi_round_job());
+
+        answer_round(&mut game, true);
+        let interview = game.interview.as_ref().unwrap();
+        assert!(interview.session.is_between_rounds());
+        assert_eq!(game.state.screen, GameScreen::Dialog);
+        let dialog = game.current_dialog.as_ref().unwrap();
+        assert_eq!(dialog.choices, [NEXT_ROUND_CHOICE]);
+        assert!(dialog.text.contains("Next: System Design"));
+
+        game.handle_dialog_choice();
+        let interview = game.interview.as_ref().unwrap();
+        assert_eq!(game.state.screen, GameScreen::Interview);
+        assert_eq!(interview.session.current_round().unwrap().name, "System Design");
+        assert_eq!(interview.current_question, 0);
+    }
+
+    #[test]
+    fn test_quitting_from_the_menu_asks_first() {
+        let mut game = Game::new();
+        game.state.reset_screen(GameScreen::World);
+        game.update(&InputSnapshot::new().with_key_pressed("escape"), DT);
+        game.update(&InputSnapshot::new().with_key_pressed("q"), DT);
+        assert_eq!(game.state.screen, GameScreen::Dialog);
+        assert!(!game.quit_requested);
+
+        // The first Enter finishes the typewriter, the second picks "Yes"
+        game.update(&InputSnapshot::new().with_key_pressed("enter"), DT);
+        game.update(&InputSnapshot::new().with_key_pressed("enter"), DT);
+        assert!(game.quit_requested);
+    }
 }
@@ -0,0 +1,122 @@
+//! Timed Buffs
+//!
+//! Temporary effects (mostly from the coffee shop) that count down in
+//! game-hours as time advances.
+
+use serde::{Deserialize, Serialize};
+
+use super::Player;
+
+/// What a buff does while it's active
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BuffKind {
+    /// Multiplies study XP by `1 + magnitude`
+    StudyXp,
+    /// Reduces study energy cost by `magnitude` (a fraction)
+    Focus,
+}
+
+impl BuffKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BuffKind::StudyXp => "Study XP",
+            BuffKind::Focus => "Focus",
+        }
+    }
+}
+
+/// A buff with its remaining duration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Buff {
+    pub kind: BuffKind,
+    pub magnitude: f32,
+    /// Game-hours left before the buff wears off
+    pub hours: f32,
+}
+
+impl Buff {
+    pub fn label(&self) -> String {
+        let sign = match self.kind {
+            BuffKind::StudyXp => "+",
+            BuffKind::Focus => "-",
+        };
+        format!(
+            "{} {}{:.0}% ({:.1}h)",
+            self.kind.as_str(),
+            sign,
+            self.magnitude * 100.0,
+            self.hours
+        )
+    }
+}
+
+impl Player {
+    /// Add a buff, refreshing an existing one of the same kind if it's stronger or longer
+    pub fn add_buff(&mut self, buff: Buff) {
+        if let Some(existing) = self.buffs.iter_mut().find(|b| b.kind == buff.kind) {
+            existing.magnitude = existing.magnitude.max(buff.magnitude);
+            existing.hours = existing.hours.max(buff.hours);
+        } else {
+            self.buffs.push(buff);
+        }
+    }
+
+    /// Count buffs down and drop the ones that ran out
+    pub fn tick_buffs(&mut self, hours: f32) {
+        for buff in &mut self.buffs {
+            buff.hours -= hours;
+        }
+        self.buffs.retain(|b| b.hours > 0.0);
+    }
+
+    fn buff_magnitude(&self, kind: BuffKind) -> f32 {
+        self.buffs
+            .iter()
+            .filter(|b| b.kind == kind)
+            .map(|b| b.magnitude)
+            .sum()
+    }
+
+    /// XP multiplier from active study buffs
+    pub fn study_xp_buff(&self) -> f32 {
+        1.0 + self.buff_magnitude(BuffKind::StudyXp)
+    }
+
+    /// Energy cost multiplier from active focus buffs
+    pub fn focus_cost_multiplier(&self) -> f32 {
+        (1.0 - self.buff_magnitude(BuffKind::Focus)).max(0.0)
+    }
+
+    /// Apply active focus buffs to a base study energy cost
+    pub fn focused_cost(&self, base_cost: u32) -> u32 {
+        (base_cost as f32 * self.focus_cost_multiplier()).round() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buff_expires_after_duration() {
+        let mut player = Player::new("Test");
+        player.add_buff(Buff { kind: BuffKind::StudyXp, magnitude: 0.25, hours: 4.0 });
+        assert_eq!(player.study_xp_buff(), 1.25);
+
+        player.tick_buffs(2.0);
+        assert_eq!(player.buffs[0].hours, 2.0);
+        player.tick_buffs(2.0);
+        assert!(player.buffs.is_empty());
+        assert_eq!(player.study_xp_buff(), 1.0);
+    }
+
+    #[test]
+    fn test_same_kind_buff_refreshes() {
+        let mut player = Player::new("Test");
+        player.add_buff(Buff { kind: BuffKind::Focus, magnitude: 0.2, hours: 1.0 });
+        player.add_buff(Buff { kind: BuffKind::Focus, magnitude: 0.1, hours: 3.0 });
+        assert_eq!(player.buffs.len(), 1);
+        assert_eq!(player.buffs[0].magnitude, 0.2);
+        assert_eq!(player.buffs[0].hours, 3.0);
+    }
+}
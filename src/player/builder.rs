@@ -0,0 +1,215 @@
+//! Player Builder
+//!
+//! Configurable starting conditions for tests, speedruns and QA debug starts.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::skills::{get_all_skills, Proficiency};
+use super::{Burnout, Player, PlayerSkill};
+
+/// Environment variable that makes new games use `PlayerBuilder::debug_start`
+pub const DEBUG_START_ENV: &str = "AI_CAREER_DEBUG_START";
+/// Environment variable overriding the starting money of new players
+pub const START_MONEY_ENV: &str = "RPG_START_MONEY";
+/// Environment variable overriding the starting energy of new players
+pub const START_ENERGY_ENV: &str = "RPG_START_ENERGY";
+
+/// Builds a `Player` with custom starting conditions
+#[derive(Debug, Clone)]
+pub struct PlayerBuilder {
+    name: String,
+    money: u32,
+    energy: u32,
+    max_energy: u32,
+    day: u32,
+    salary: Option<u32>,
+    skills: HashMap<String, Proficiency>,
+}
+
+impl PlayerBuilder {
+    /// Builder with the normal new-game defaults
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            money: 1000,
+            energy: 100,
+            max_energy: 100,
+            day: 1,
+            salary: None,
+            skills: HashMap::new(),
+        }
+    }
+
+    /// Well-funded, moderately skilled start for QA
+    pub fn debug_start(name: &str) -> Self {
+        let mut builder = Self::new(name).money(50000);
+        for skill in get_all_skills() {
+            builder = builder.skill(&skill.name, Proficiency::Intermediate);
+        }
+        builder
+    }
+
+    /// Apply `RPG_START_MONEY` / `RPG_START_ENERGY` if they are set
+    pub fn env_overrides(self) -> Self {
+        self.overrides_from(|key| std::env::var(key).ok(), &mut Vec::new())
+    }
+
+    /// Malformed `RPG_START_MONEY` / `RPG_START_ENERGY` values, which
+    /// `env_overrides` skips
+    pub fn env_override_problems() -> Vec<String> {
+        let mut problems = Vec::new();
+        Self::new("").overrides_from(|key| std::env::var(key).ok(), &mut problems);
+        problems
+    }
+
+    /// Apply start overrides from `lookup`; malformed values are skipped and noted in `problems`
+    fn overrides_from(mut self, lookup: impl Fn(&str) -> Option<String>, problems: &mut Vec<String>) -> Self {
+        let mut parse = |key: &str| {
+            let value = lookup(key)?;
+            let parsed = value.trim().parse::<u32>().ok();
+            if parsed.is_none() {
+                problems.push(format!("Ignoring {}={:?}: not a whole number", key, value));
+            }
+            parsed
+        };
+        if let Some(money) = parse(START_MONEY_ENV) {
+            self.money = money;
+        }
+        if let Some(energy) = parse(START_ENERGY_ENV) {
+            self.energy = energy;
+        }
+        self
+    }
+
+    pub fn money(mut self, money: u32) -> Self {
+        self.money = money;
+        self
+    }
+
+    /// Starting energy, capped at max energy when built
+    pub fn energy(mut self, energy: u32) -> Self {
+        self.energy = energy;
+        self
+    }
+
+    pub fn max_energy(mut self, max_energy: u32) -> Self {
+        self.max_energy = max_energy;
+        self
+    }
+
+    pub fn day(mut self, day: u32) -> Self {
+        self.day = day;
+        self
+    }
+
+    /// Start already employed at the given salary
+    pub fn employed(mut self, salary: u32) -> Self {
+        self.salary = Some(salary);
+        self
+    }
+
+    /// Preset a skill's proficiency; unknown skill names are ignored
+    pub fn skill(mut self, name: &str, proficiency: Proficiency) -> Self {
+        self.skills.insert(name.to_string(), proficiency);
+        self
+    }
+
+    pub fn build(self) -> Player {
+        let mut skills = HashMap::new();
+        for skill in get_all_skills() {
+            let mut player_skill = PlayerSkill::new(skill);
+            if let Some(proficiency) = self.skills.get(&player_skill.skill.name) {
+                player_skill.proficiency = *proficiency;
+            }
+            skills.insert(player_skill.skill.name.clone(), player_skill);
+        }
+
+        Player {
+            name: self.name,
+            skills,
+            money: self.money,
+            energy: self.energy.min(self.max_energy),
+            max_energy: self.max_energy,
+            day: self.day,
+            employed: self.salary.is_some(),
+            current_salary: self.salary.unwrap_or(0),
+            reputation: 0,
+            experience_days: 0,
+            job_history: Vec::new(),
+            interview_attempts: HashMap::new(),
+            interview_cooldowns: HashMap::new(),
+            pending_offers: Vec::new(),
+            student_friendship: 0,
+            buffs: Vec::new(),
+            scheduled_crashes: Vec::new(),
+            bookmarked_jobs: HashSet::new(),
+            researched_jobs: HashSet::new(),
+            xp_pool: 0,
+            scheduled_interviews: Vec::new(),
+            relationships: HashMap::new(),
+            focus_skill: None,
+            burnout: Burnout::default(),
+            venture: None,
+            part_time: None,
+            warmed_up_for: None,
+            certifications: HashSet::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_sets_skills_and_money() {
+        let player = Player::builder("Test")
+            .money(50000)
+            .skill("Python", Proficiency::Expert)
+            .build();
+
+        assert_eq!(player.money, 50000);
+        assert_eq!(player.get_skill_proficiency("Python"), Proficiency::Expert);
+        assert_eq!(player.get_skill_proficiency("SQL"), Proficiency::None);
+        assert_eq!(player.skills.len(), get_all_skills().len());
+    }
+
+    #[test]
+    fn test_builder_employment_and_day() {
+        let player = Player::builder("Test").employed(120000).day(30).energy(500).build();
+        assert!(player.employed);
+        assert_eq!(player.current_salary, 120000);
+        assert_eq!(player.day, 30);
+        assert_eq!(player.energy, player.max_energy);
+    }
+
+    #[test]
+    fn test_start_money_override() {
+        let lookup = |key: &str| (key == START_MONEY_ENV).then(|| "25000".to_string());
+        let mut problems = Vec::new();
+        let player = Player::builder("Test").overrides_from(lookup, &mut problems).build();
+        assert_eq!(player.money, 25000);
+        assert!(problems.is_empty());
+        assert_eq!(player.energy, 100);
+    }
+
+    #[test]
+    fn test_malformed_override_falls_back_to_default() {
+        let lookup = |_: &str| Some("lots".to_string());
+        let mut problems = Vec::new();
+        let player = Player::builder("Test").overrides_from(lookup, &mut problems).build();
+        assert_eq!(player.money, 1000);
+        assert_eq!(player.energy, 100);
+        assert_eq!(problems.len(), 2);
+    }
+
+    #[test]
+    fn test_defaults_match_new() {
+        let built = Player::builder("Test").build();
+        let new = Player::new("Test");
+        assert_eq!(built.money, new.money);
+        assert_eq!(built.energy, new.energy);
+        assert_eq!(built.day, new.day);
+        assert!(!built.employed);
+    }
+}
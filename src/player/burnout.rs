@@ -0,0 +1,133 @@
+//! Burnout
+//!
+//! Ending day after day running on empty wears the player down. After a
+//! streak of exhausted days burnout sets in, cutting study XP and
+//! interview performance. It deepens with every further exhausted day and
+//! only eases after several well-rested days.
+
+use serde::{Deserialize, Serialize};
+
+use super::Player;
+
+/// A day ending below this much energy counts as exhausted
+pub const BURNOUT_ENERGY_THRESHOLD: u32 = 20;
+/// Exhausted days in a row before burnout sets in
+pub const BURNOUT_STREAK_DAYS: u32 = 3;
+/// Deepest burnout can get
+pub const MAX_BURNOUT_INTENSITY: u32 = 3;
+/// Share of max energy a day has to end with to count as well rested
+pub const BURNOUT_RESTED_SHARE: f32 = 0.8;
+/// Well-rested days needed to ease burnout by one level
+pub const BURNOUT_RECOVERY_DAYS: u32 = 2;
+/// Study XP lost per level of burnout
+pub const BURNOUT_XP_PENALTY: f32 = 0.2;
+/// Interview score lost per level of burnout
+pub const BURNOUT_SCORE_PENALTY: f32 = 0.1;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Burnout {
+    /// Exhausted days in a row
+    pub low_energy_streak: u32,
+    /// 0 when healthy, up to `MAX_BURNOUT_INTENSITY`
+    pub intensity: u32,
+    /// Well-rested days toward easing the current level
+    pub rested_days: u32,
+}
+
+impl Burnout {
+    pub fn is_active(&self) -> bool {
+        self.intensity > 0
+    }
+
+    /// Multiplier on study XP
+    pub fn xp_multiplier(&self) -> f32 {
+        1.0 - BURNOUT_XP_PENALTY * self.intensity as f32
+    }
+
+    /// Multiplier on interview answer scores
+    pub fn score_multiplier(&self) -> f32 {
+        1.0 - BURNOUT_SCORE_PENALTY * self.intensity as f32
+    }
+
+    /// Update from the energy the player ended the day with
+    pub fn end_day(&mut self, energy: u32, max_energy: u32) {
+        if energy < BURNOUT_ENERGY_THRESHOLD {
+            self.low_energy_streak += 1;
+            self.rested_days = 0;
+            if self.low_energy_streak >= BURNOUT_STREAK_DAYS {
+                self.intensity = (self.intensity + 1).min(MAX_BURNOUT_INTENSITY);
+            }
+            return;
+        }
+        self.low_energy_streak = 0;
+        if self.is_active() && energy as f32 >= max_energy as f32 * BURNOUT_RESTED_SHARE {
+            self.rested_days += 1;
+            if self.rested_days >= BURNOUT_RECOVERY_DAYS {
+                self.intensity -= 1;
+                self.rested_days = 0;
+            }
+        }
+    }
+
+    /// HUD warning while burned out
+    pub fn warning(&self) -> Option<String> {
+        self.is_active().then(|| {
+            format!(
+                "BURNOUT {}: -{:.0}% study XP, weaker interviews - rest to recover",
+                self.intensity,
+                (1.0 - self.xp_multiplier()) * 100.0
+            )
+        })
+    }
+}
+
+impl Player {
+    /// Record how much energy the day ended with; called when a day ends
+    pub(super) fn track_burnout(&mut self) {
+        self.burnout.end_day(self.energy, self.max_energy);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_three_exhausted_days_trigger_burnout() {
+        let mut player = Player::new("Test");
+        for day in 1..=BURNOUT_STREAK_DAYS {
+            assert!(!player.burnout.is_active(), "burned out early on day {}", day);
+            player.energy = 5;
+            player.advance_day();
+        }
+        assert_eq!(player.burnout.intensity, 1);
+        assert!(player.burnout.xp_multiplier() < 1.0);
+
+        let mut fresh = Player::new("Fresh");
+        fresh.study("SQL", 2, 14.0).unwrap();
+        player.energy = player.max_energy;
+        player.study("SQL", 2, 14.0).unwrap();
+        assert!(player.invested_xp("SQL") < fresh.invested_xp("SQL"));
+    }
+
+    #[test]
+    fn test_resting_clears_burnout_over_time() {
+        let mut burnout = Burnout { low_energy_streak: 4, intensity: 2, rested_days: 0 };
+
+        for _ in 0..BURNOUT_RECOVERY_DAYS {
+            burnout.end_day(100, 100);
+        }
+        assert_eq!(burnout.intensity, 1);
+        assert_eq!(burnout.low_energy_streak, 0);
+
+        // A middling day neither helps nor hurts
+        burnout.end_day(50, 100);
+        assert_eq!(burnout.intensity, 1);
+
+        for _ in 0..BURNOUT_RECOVERY_DAYS {
+            burnout.end_day(100, 100);
+        }
+        assert!(!burnout.is_active());
+        assert!(burnout.warning().is_none());
+    }
+}
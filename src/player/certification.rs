@@ -0,0 +1,74 @@
+//! Skill Certifications
+//!
+//! Proof of a skill that interviewers can check. A certificate is earned
+//! by passing a paid exam on that one skill, and from then on answers on
+//! the skill never score below `CERT_SCORE_FLOOR`, however the day goes.
+
+use super::Player;
+
+/// Fee for sitting a certification exam, paid whether or not it's passed
+pub const CERT_EXAM_FEE: u32 = 150;
+/// Energy a certification exam takes
+pub const CERT_EXAM_ENERGY: u32 = 20;
+/// Hours a certification exam takes
+pub const CERT_EXAM_HOURS: u32 = 2;
+/// Lowest interview score on a certified skill's questions
+pub const CERT_SCORE_FLOOR: f32 = 0.65;
+
+impl Player {
+    pub fn is_certified(&self, skill_name: &str) -> bool {
+        self.certifications.contains(skill_name)
+    }
+
+    /// Minimum answer score on a skill, if the player is certified in it
+    pub fn certified_score_floor(&self, skill_name: &str) -> Option<f32> {
+        self.is_certified(skill_name).then_some(CERT_SCORE_FLOOR)
+    }
+
+    /// Pay the fee and energy to sit a skill's certification exam
+    pub fn pay_for_certification_exam(&mut self, skill_name: &str) -> Result<(), String> {
+        if !self.skills.contains_key(skill_name) {
+            return Err(format!("There's no certification for {}", skill_name));
+        }
+        if self.is_certified(skill_name) {
+            return Err(format!("You're already certified in {}", skill_name));
+        }
+        if self.money < CERT_EXAM_FEE {
+            return Err(format!("The exam costs ${}, but you only have ${}", CERT_EXAM_FEE, self.money));
+        }
+        if self.energy < CERT_EXAM_ENERGY {
+            return Err(format!("The exam takes {} energy, but you only have {}", CERT_EXAM_ENERGY, self.energy));
+        }
+        self.money -= CERT_EXAM_FEE;
+        self.energy -= CERT_EXAM_ENERGY;
+        Ok(())
+    }
+
+    /// Record a passed certification exam
+    pub fn certify(&mut self, skill_name: &str) {
+        self.certifications.insert(skill_name.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exam_costs_money_and_energy_once_per_skill() {
+        let mut player = Player::builder("Test").money(CERT_EXAM_FEE + 10).build();
+        let energy = player.energy;
+
+        assert!(player.pay_for_certification_exam("Juggling").is_err());
+        player.pay_for_certification_exam("Python").unwrap();
+        assert_eq!(player.money, 10);
+        assert_eq!(player.energy, energy - CERT_EXAM_ENERGY);
+        assert!(player.pay_for_certification_exam("SQL").is_err());
+
+        player.certify("Python");
+        player.money = CERT_EXAM_FEE;
+        assert!(player.pay_for_certification_exam("Python").is_err());
+        assert_eq!(player.certified_score_floor("Python"), Some(CERT_SCORE_FLOOR));
+        assert_eq!(player.certified_score_floor("SQL"), None);
+    }
+}
@@ -0,0 +1,109 @@
+//! Coffee Shop Menu
+//!
+//! Drinks loaded from `config/coffee_menu.toml`. Each restores some energy
+//! and may grant a timed buff or schedule a later energy crash.
+
+use serde::Deserialize;
+
+use super::buffs::Buff;
+use super::effects::EnergyCrash;
+use super::Player;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MenuItem {
+    pub name: String,
+    pub price: u32,
+    pub energy: u32,
+    #[serde(default)]
+    pub buff: Option<Buff>,
+    #[serde(default)]
+    pub crash: Option<EnergyCrash>,
+}
+
+impl MenuItem {
+    /// Dialog label, e.g. "Latte ($6)"
+    pub fn label(&self) -> String {
+        format!("{} (${})", self.name, self.price)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CoffeeMenu {
+    pub items: Vec<MenuItem>,
+}
+
+impl CoffeeMenu {
+    /// Load the menu from embedded config file
+    pub fn load() -> Self {
+        const CONFIG: &str = include_str!("../config/coffee_menu.toml");
+        toml::from_str(CONFIG).expect("Failed to parse coffee_menu.toml")
+    }
+
+    /// Find the item matching a dialog choice
+    pub fn find_by_label(&self, choice: &str) -> Option<&MenuItem> {
+        self.items.iter().find(|item| choice == item.label())
+    }
+}
+
+impl Player {
+    /// Pay for a drink and apply its energy and buff
+    pub fn buy(&mut self, item: &MenuItem) -> Result<String, String> {
+        if self.money < item.price {
+            return Err(format!("You can't afford a {}", item.name));
+        }
+        self.money -= item.price;
+        self.energy = (self.energy + item.energy).min(self.max_energy);
+        let mut message = format!("{}: +{} energy", item.name, item.energy);
+        if let Some(buff) = &item.buff {
+            self.add_buff(buff.clone());
+            message.push_str(&format!(", {}", buff.label()));
+        }
+        if let Some(crash) = &item.crash {
+            self.schedule_crash(crash.clone());
+            message.push_str(&format!("\nWarning: you'll crash (-{} energy) in {:.0}h", crash.energy, crash.hours));
+        }
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::BuffKind;
+
+    #[test]
+    fn test_buy_latte_charges_and_buffs() {
+        let menu = CoffeeMenu::load();
+        let latte = menu.find_by_label("Latte ($6)").expect("latte on menu");
+        let mut player = Player::builder("Test").money(100).energy(50).build();
+
+        player.buy(latte).unwrap();
+
+        assert_eq!(player.money, 94);
+        assert_eq!(player.energy, 60);
+        assert_eq!(player.buffs.len(), 1);
+        assert_eq!(player.buffs[0].kind, BuffKind::StudyXp);
+        assert!(player.study_xp_buff() > 1.0);
+    }
+
+    #[test]
+    fn test_energy_drink_boosts_now_and_schedules_crash() {
+        let menu = CoffeeMenu::load();
+        let drink = menu.items.iter().find(|i| i.name == "Energy drink").unwrap();
+        let mut player = Player::builder("Test").money(100).energy(20).build();
+
+        player.buy(drink).unwrap();
+
+        assert_eq!(player.energy, 20 + drink.energy);
+        let crash = player.next_crash().expect("crash scheduled");
+        assert_eq!(crash, drink.crash.as_ref().unwrap());
+    }
+
+    #[test]
+    fn test_buy_without_money_fails() {
+        let menu = CoffeeMenu::load();
+        let mut player = Player::builder("Test").money(1).build();
+        assert!(player.buy(&menu.items[0]).is_err());
+        assert_eq!(player.money, 1);
+    }
+}
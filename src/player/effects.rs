@@ -0,0 +1,80 @@
+//! Scheduled Effects
+//!
+//! Delayed consequences queued on the player, such as the crash that
+//! follows an energy drink. They count down in game-hours like buffs
+//! and fire once when their time comes.
+
+use serde::{Deserialize, Serialize};
+
+use super::Player;
+
+/// An energy penalty that lands some hours after a drink
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnergyCrash {
+    /// Energy lost when the crash hits
+    pub energy: u32,
+    /// Game-hours until the crash
+    pub hours: f32,
+}
+
+impl EnergyCrash {
+    pub fn label(&self) -> String {
+        format!("Crash -{} energy in {:.1}h", self.energy, self.hours)
+    }
+}
+
+impl Player {
+    /// Queue a crash to hit after its delay
+    pub fn schedule_crash(&mut self, crash: EnergyCrash) {
+        self.scheduled_crashes.push(crash);
+    }
+
+    /// Count scheduled crashes down and apply the ones that are due
+    ///
+    /// Returns the total energy lost.
+    pub fn tick_scheduled(&mut self, hours: f32) -> u32 {
+        let mut lost = 0;
+        for crash in &mut self.scheduled_crashes {
+            crash.hours -= hours;
+            if crash.hours <= 0.0 {
+                lost += crash.energy;
+            }
+        }
+        self.scheduled_crashes.retain(|c| c.hours > 0.0);
+        self.energy = self.energy.saturating_sub(lost);
+        lost
+    }
+
+    /// The crash that will hit soonest, for the HUD warning
+    pub fn next_crash(&self) -> Option<&EnergyCrash> {
+        self.scheduled_crashes
+            .iter()
+            .min_by(|a, b| a.hours.total_cmp(&b.hours))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crash_fires_once_when_due() {
+        let mut player = Player::builder("Test").energy(80).build();
+        player.schedule_crash(EnergyCrash { energy: 30, hours: 3.0 });
+
+        assert_eq!(player.tick_scheduled(2.0), 0);
+        assert_eq!(player.energy, 80);
+        assert_eq!(player.tick_scheduled(1.0), 30);
+        assert_eq!(player.energy, 50);
+        assert!(player.next_crash().is_none());
+        assert_eq!(player.tick_scheduled(5.0), 0);
+    }
+
+    #[test]
+    fn test_crash_does_not_underflow() {
+        let mut player = Player::builder("Test").energy(10).build();
+        player.schedule_crash(EnergyCrash { energy: 30, hours: 1.0 });
+        player.tick_scheduled(1.0);
+        assert_eq!(player.energy, 0);
+    }
+}
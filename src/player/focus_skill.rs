@@ -0,0 +1,101 @@
+//! Focus Skill
+//!
+//! A skill the player commits to keeps improving a little every day from
+//! ongoing self-study, even on days spent on other things. The trickle is
+//! smaller when the player goes to bed drained or works a full-time job.
+
+use super::Player;
+
+/// XP the focus skill gains per day when rested and not working
+pub const FOCUS_DAILY_XP: u32 = 10;
+/// Share of the trickle kept while employed
+pub const FOCUS_EMPLOYED_FRACTION: f32 = 0.5;
+
+impl Player {
+    /// Pick the skill that gets daily self-study XP, or clear it with `None`
+    pub fn set_focus_skill(&mut self, skill_name: Option<&str>) -> Result<(), String> {
+        let Some(name) = skill_name else {
+            self.focus_skill = None;
+            return Ok(());
+        };
+        let skill = self.skills.get(name).ok_or_else(|| format!("Unknown skill: {}", name))?;
+        if skill.is_maxed() {
+            return Err(format!("{} is already mastered", name));
+        }
+        self.focus_skill = Some(name.to_string());
+        Ok(())
+    }
+
+    /// XP the focus skill would get at the end of today
+    ///
+    /// Half the trickle depends on how much energy is left, so running
+    /// yourself into the ground leaves less room for side study.
+    pub fn focus_daily_xp(&self) -> u32 {
+        let Some(skill) = self.focus_skill.as_ref().and_then(|name| self.skills.get(name)) else { return 0 };
+        if skill.is_maxed() {
+            return 0;
+        }
+        let energy_share = self.energy as f32 / self.max_energy.max(1) as f32;
+        let mut xp = FOCUS_DAILY_XP as f32 * (0.5 + 0.5 * energy_share.min(1.0));
+        if self.employed {
+            xp *= FOCUS_EMPLOYED_FRACTION;
+        }
+        xp.round() as u32
+    }
+
+    /// Give the focus skill its daily XP; called when a day ends
+    pub(super) fn apply_focus_trickle(&mut self) {
+        let xp = self.focus_daily_xp();
+        if xp == 0 {
+            return;
+        }
+        if let Some(skill) = self.focus_skill.as_ref().and_then(|name| self.skills.get_mut(name)) {
+            skill.add_experience(xp);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skills::Proficiency;
+
+    #[test]
+    fn test_focus_skill_gains_xp_each_day() {
+        let mut player = Player::builder("Test").build();
+        player.set_focus_skill(Some("SQL")).unwrap();
+
+        for _ in 0..5 {
+            player.advance_day();
+        }
+
+        assert_eq!(player.invested_xp("SQL"), 5 * FOCUS_DAILY_XP);
+        assert!(player.skills.keys().filter(|name| *name != "SQL").all(|name| player.invested_xp(name) == 0));
+    }
+
+    #[test]
+    fn test_trickle_shrinks_when_employed_or_tired() {
+        let mut player = Player::builder("Test").build();
+        player.set_focus_skill(Some("SQL")).unwrap();
+        let rested = player.focus_daily_xp();
+
+        player.energy = 0;
+        let tired = player.focus_daily_xp();
+        player.employed = true;
+
+        assert!(tired < rested);
+        assert!(player.focus_daily_xp() < tired);
+    }
+
+    #[test]
+    fn test_maxed_or_unknown_skill_cannot_be_focus() {
+        let mut player = Player::builder("Test").skill("Python", Proficiency::Expert).build();
+        assert!(player.set_focus_skill(Some("Python")).is_err());
+        assert!(player.set_focus_skill(Some("Juggling")).is_err());
+        assert_eq!(player.focus_daily_xp(), 0);
+
+        player.focus_skill = Some("Python".to_string());
+        player.advance_day();
+        assert_eq!(player.skills["Python"].experience_points, 0);
+    }
+}
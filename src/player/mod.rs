@@ -1,12 +1,102 @@
-use std::collections::HashMap;
+mod buffs;
+mod builder;
+mod burnout;
+mod certification;
+mod coffee;
+mod effects;
+mod focus_skill;
+mod name;
+mod offers;
+mod part_time;
+mod relationships;
+mod respec;
+mod resume;
+mod schedule;
+mod study_group;
+mod venture;
+mod venue;
+mod warm_up;
 
-use crate::skills::{get_all_skills, Proficiency, Skill, SkillCategory};
+use std::collections::{HashMap, HashSet};
 
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+use crate::jobs::Job;
+use crate::skills::{Proficiency, Skill, SkillCategory};
+
+pub use buffs::{Buff, BuffKind};
+pub use builder::{PlayerBuilder, DEBUG_START_ENV, START_ENERGY_ENV, START_MONEY_ENV};
+pub use burnout::{Burnout, BURNOUT_ENERGY_THRESHOLD, BURNOUT_STREAK_DAYS, MAX_BURNOUT_INTENSITY};
+pub use certification::{CERT_EXAM_ENERGY, CERT_EXAM_FEE, CERT_EXAM_HOURS, CERT_SCORE_FLOOR};
+pub use coffee::{CoffeeMenu, MenuItem};
+pub use effects::EnergyCrash;
+pub use focus_skill::{FOCUS_DAILY_XP, FOCUS_EMPLOYED_FRACTION};
+pub use name::{NameInput, MAX_NAME_CHARS};
+pub use offers::{Offer, OFFER_WINDOW_DAYS};
+pub use part_time::PartTimeJob;
+pub use relationships::{Relationship, FRIENDLINESS_PER_TALK, FRIEND_THRESHOLD, MAX_FRIENDLINESS};
+pub use respec::{RESPEC_COST, RESPEC_REFUND_FRACTION, RESPEC_REPUTATION_PENALTY};
+pub use resume::{Resume, ResumeJob, ResumeSkill};
+pub use schedule::{
+    is_business_day, next_business_day, weekday_name, ScheduledInterview, BUSINESS_DAYS_PER_WEEK, INTERVIEW_HOUR,
+    INTERVIEW_LENGTH_HOURS,
+};
+pub use study_group::{
+    MAX_STUDY_GROUP_BONUS, MAX_STUDENT_FRIENDSHIP, STUDY_GROUP_BASE_BONUS, STUDY_GROUP_COST,
+};
+pub use venture::{
+    Venture, VentureOutcome, FOUNDING_COST, FOUNDING_PROFICIENCY, FOUNDING_SKILLS_REQUIRED, VENTURE_EXIT_BALANCE,
+};
+pub use venue::{StudyVenue, UNIVERSITY_TUITION, UNIVERSITY_XP_MULTIPLIER};
+pub use warm_up::{warm_up_topics, WARM_UP_ENERGY, WARM_UP_HOURS};
+
+/// Energy spent per hour of study, before scaling by skill difficulty
+pub const STUDY_ENERGY_PER_HOUR: u32 = 10;
+/// XP gained per hour of study
+pub const STUDY_XP_PER_HOUR: u32 = 25;
+/// Length of a single study session at the library, in hours
+pub const STUDY_SESSION_HOURS: u32 = 2;
+/// Days to wait before retrying a failed interview
+pub const INTERVIEW_COOLDOWN_DAYS: u32 = 3;
+/// Hours spent talking to a recruiter to research a job's interview
+pub const RESEARCH_HOURS: u32 = 1;
+/// Usable energy ceiling late at night
+pub const NIGHT_ENERGY_CAP: u32 = 60;
+/// Hour at which the player starts feeling tired
+pub const TIRED_FROM_HOUR: f32 = 22.0;
+/// Hour at which the player stops feeling tired
+pub const TIRED_UNTIL_HOUR: f32 = 6.0;
+
+/// XP from None to Basic per point of skill difficulty
+pub const XP_PER_DIFFICULTY: u32 = 100;
+/// Each proficiency level costs this much more XP than the one before
+pub const XP_LEVEL_GROWTH: f32 = 1.5;
+
+/// XP needed to go from `proficiency` to the next level, or 0 at Expert
+pub fn xp_to_next_level(difficulty: u8, proficiency: Proficiency) -> u32 {
+    if proficiency.next().is_none() {
+        return 0;
+    }
+    let base = difficulty as u32 * XP_PER_DIFFICULTY;
+    (base as f32 * XP_LEVEL_GROWTH.powi(proficiency as i32)).round() as u32
+}
+
+/// Weighted proficiency points needed per career level
+pub const CAREER_POINTS_PER_LEVEL: u32 = 4;
+
+/// Whether the given hour falls in the late-night tired window
+pub fn is_tired_hour(time_of_day: f32) -> bool {
+    !(TIRED_UNTIL_HOUR..TIRED_FROM_HOUR).contains(&time_of_day)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerSkill {
     pub skill: Skill,
     pub proficiency: Proficiency,
     pub experience_points: u32,
+    /// Day of the last successful study session, if any
+    #[serde(default)]
+    pub last_studied_day: Option<u32>,
 }
 
 impl PlayerSkill {
@@ -15,20 +105,75 @@ impl PlayerSkill {
             skill,
             proficiency: Proficiency::None,
             experience_points: 0,
+            last_studied_day: None,
+        }
+    }
+
+    /// When the skill was last studied, e.g. "3 days ago" or "never"
+    pub fn last_practiced_label(&self, today: u32) -> String {
+        match self.last_studied_day.map(|day| today.saturating_sub(day)) {
+            None => "never".to_string(),
+            Some(0) => "today".to_string(),
+            Some(1) => "1 day ago".to_string(),
+            Some(days) => format!("{} days ago", days),
         }
     }
 
+    /// XP needed for the next level, or 0 once the skill is mastered
     pub fn points_to_next_level(&self) -> u32 {
-        (self.skill.difficulty as u32) * 100
+        xp_to_next_level(self.skill.difficulty, self.proficiency)
+    }
+
+    /// Progress toward the next level in whole percent, or None once mastered
+    pub fn progress_percent(&self) -> Option<u32> {
+        let needed = self.points_to_next_level();
+        if self.is_maxed() || needed == 0 {
+            return None;
+        }
+        Some((self.experience_points * 100 / needed).min(100))
+    }
+
+    /// Progress readout for skill lists, e.g. "57%" or "MAX"
+    pub fn progress_label(&self) -> String {
+        match self.progress_percent() {
+            Some(percent) => format!("{}%", percent),
+            None => "MAX".to_string(),
+        }
+    }
+
+    /// Whether the skill is at Expert and can't level further
+    pub fn is_maxed(&self) -> bool {
+        self.proficiency.next().is_none()
+    }
+
+    /// Energy needed to study this skill for the given number of hours
+    ///
+    /// Harder skills are more draining: the base hourly cost is
+    /// multiplied by the skill's difficulty.
+    pub fn study_energy_cost(&self, hours: u32) -> u32 {
+        hours
+            .saturating_mul(STUDY_ENERGY_PER_HOUR)
+            .saturating_mul(self.skill.difficulty.max(1) as u32)
+    }
+
+    /// Proficiency level scaled by how hard the skill is
+    pub fn weighted_proficiency(&self) -> u32 {
+        self.proficiency as u32 * self.skill.difficulty.max(1) as u32
     }
 
     pub fn add_experience(&mut self, points: u32) -> bool {
+        if self.is_maxed() {
+            return false;
+        }
         self.experience_points += points;
         let needed = self.points_to_next_level();
         if self.experience_points >= needed {
             if let Some(next) = self.proficiency.next() {
                 self.proficiency = next;
                 self.experience_points -= needed;
+                if self.is_maxed() {
+                    self.experience_points = 0;
+                }
                 return true;
             }
         }
@@ -36,7 +181,19 @@ impl PlayerSkill {
     }
 }
 
-#[derive(Debug, Clone)]
+/// A job the player has held
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRecord {
+    pub title: String,
+    pub company: String,
+    pub salary: u32,
+    pub start_day: u32,
+    /// Skills the job's mandatory requirements name
+    #[serde(default)]
+    pub required_skills: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub name: String,
     pub skills: HashMap<String, PlayerSkill>,
@@ -47,41 +204,193 @@ pub struct Player {
     pub employed: bool,
     pub current_salary: u32,
     pub reputation: u32,
+    pub experience_days: u32,
+    pub job_history: Vec<JobRecord>,
+    /// Number of interviews attempted per job id
+    pub interview_attempts: HashMap<u32, u32>,
+    /// Day on which each job id may be interviewed for again
+    pub interview_cooldowns: HashMap<u32, u32>,
+    /// Offers from passed interviews the player hasn't decided on
+    #[serde(default)]
+    pub pending_offers: Vec<Offer>,
+    /// Friendship with the Student NPC, grown through study group sessions
+    pub student_friendship: u32,
+    /// Timed effects such as coffee shop drinks
+    pub buffs: Vec<Buff>,
+    /// Delayed energy crashes, e.g. after an energy drink
+    pub scheduled_crashes: Vec<EnergyCrash>,
+    /// Job ids the player starred on the job board
+    pub bookmarked_jobs: HashSet<u32>,
+    /// Job ids whose interview structure the player has researched
+    pub researched_jobs: HashSet<u32>,
+    /// XP refunded by respecs, waiting to be put into a skill
+    #[serde(default)]
+    pub xp_pool: u32,
+    /// Interviews booked for a later slot
+    #[serde(default)]
+    pub scheduled_interviews: Vec<ScheduledInterview>,
+    /// What each NPC remembers about the player, keyed by NPC name
+    #[serde(default)]
+    pub relationships: HashMap<String, Relationship>,
+    /// Skill that gets a daily trickle of self-study XP
+    #[serde(default)]
+    pub focus_skill: Option<String>,
+    /// Wear from ending too many days exhausted
+    #[serde(default)]
+    pub burnout: Burnout,
+    /// Startup founded in the late game, kept after it ends to show how it went
+    #[serde(default)]
+    pub venture: Option<Venture>,
+    /// Side job worked on business days until a full-time offer comes
+    #[serde(default)]
+    pub part_time: Option<PartTimeJob>,
+    /// Job id the player warmed up for, used up by that job's next interview
+    #[serde(default)]
+    pub warmed_up_for: Option<u32>,
+    /// Skills the player passed a certification exam in
+    #[serde(default)]
+    pub certifications: HashSet<String>,
 }
 
 impl Player {
+    /// New-game player; `RPG_START_MONEY` / `RPG_START_ENERGY` override
+    /// the defaults for debugging
     pub fn new(name: &str) -> Self {
-        let mut skills = HashMap::new();
-        for skill in get_all_skills() {
-            skills.insert(skill.name.clone(), PlayerSkill::new(skill));
+        Self::builder(name).env_overrides().build()
+    }
+
+    /// Start building a player with custom starting conditions
+    pub fn builder(name: &str) -> PlayerBuilder {
+        PlayerBuilder::new(name)
+    }
+
+    /// Block re-interviewing for a job for `INTERVIEW_COOLDOWN_DAYS`
+    pub fn start_interview_cooldown(&mut self, job_id: u32) {
+        self.interview_cooldowns
+            .insert(job_id, self.day + INTERVIEW_COOLDOWN_DAYS);
+    }
+
+    /// Days left before the player may interview for a job again
+    pub fn cooldown_remaining(&self, job_id: u32) -> Option<u32> {
+        self.interview_cooldowns
+            .get(&job_id)
+            .filter(|&&until| until > self.day)
+            .map(|until| until - self.day)
+    }
+
+    /// Record a new interview attempt for a job
+    ///
+    /// Returns the zero-based attempt number.
+    pub fn record_interview_attempt(&mut self, job_id: u32) -> u32 {
+        let attempts = self.interview_attempts.entry(job_id).or_insert(0);
+        let attempt = *attempts;
+        *attempts += 1;
+        attempt
+    }
+
+    /// Star or unstar a job; returns whether it's now bookmarked
+    pub fn toggle_bookmark(&mut self, job_id: u32) -> bool {
+        if self.bookmarked_jobs.remove(&job_id) {
+            false
+        } else {
+            self.bookmarked_jobs.insert(job_id);
+            true
         }
+    }
 
-        Self {
-            name: name.to_string(),
-            skills,
-            money: 1000,
-            energy: 100,
-            max_energy: 100,
-            day: 1,
-            employed: false,
-            current_salary: 0,
-            reputation: 0,
+    pub fn is_bookmarked(&self, job_id: u32) -> bool {
+        self.bookmarked_jobs.contains(&job_id)
+    }
+
+    /// Remember that the player researched a job's interview
+    pub fn research_job(&mut self, job_id: u32) {
+        self.researched_jobs.insert(job_id);
+    }
+
+    pub fn has_researched(&self, job_id: u32) -> bool {
+        self.researched_jobs.contains(&job_id)
+    }
+
+    /// Spend reputation, failing without change if there isn't enough
+    pub fn spend_reputation(&mut self, amount: u32) -> Result<(), String> {
+        if self.reputation < amount {
+            return Err(format!(
+                "Not enough reputation (need {}, have {})",
+                amount, self.reputation
+            ));
         }
+        self.reputation -= amount;
+        Ok(())
+    }
+
+    /// Start working at a job with the given salary
+    pub fn accept_job(&mut self, job: &Job, salary: u32) {
+        self.employed = true;
+        self.part_time = None;
+        self.current_salary = salary;
+        self.job_history.push(JobRecord {
+            title: job.title.clone(),
+            company: job.company.clone(),
+            salary,
+            start_day: self.day,
+            required_skills: job
+                .requirements
+                .iter()
+                .filter(|r| r.mandatory)
+                .map(|r| r.skill_name.clone())
+                .collect(),
+        });
     }
 
     pub fn rest(&mut self) {
         self.energy = self.max_energy;
     }
 
-    pub fn study(&mut self, skill_name: &str, hours: u32) -> Result<String, String> {
-        let energy_cost = hours * 10;
-        if self.energy < energy_cost {
-            return Err("Not enough energy to study".to_string());
+    /// Energy ceiling usable for activities at the given time of day
+    pub fn max_effective_energy(&self, time_of_day: f32) -> u32 {
+        if is_tired_hour(time_of_day) {
+            self.max_energy.min(NIGHT_ENERGY_CAP)
+        } else {
+            self.max_energy
         }
+    }
+
+    /// Energy actually available right now, after the time-of-day cap
+    pub fn usable_energy(&self, time_of_day: f32) -> u32 {
+        self.energy.min(self.max_effective_energy(time_of_day))
+    }
+
+    pub fn study(&mut self, skill_name: &str, hours: u32, time_of_day: f32) -> Result<String, String> {
+        self.study_with_multiplier(skill_name, hours, time_of_day, 1.0)
+    }
 
+    /// Study with XP scaled by `xp_multiplier` (e.g. from a study group)
+    pub fn study_with_multiplier(
+        &mut self,
+        skill_name: &str,
+        hours: u32,
+        time_of_day: f32,
+        xp_multiplier: f32,
+    ) -> Result<String, String> {
+        let usable = self.usable_energy(time_of_day);
+        let today = self.day;
+        let xp_multiplier = xp_multiplier * self.study_xp_buff() * self.burnout.xp_multiplier();
+        let base_cost = self.skills.get(skill_name).map(|s| s.study_energy_cost(hours));
+        let energy_cost = base_cost.map(|cost| self.focused_cost(cost)).unwrap_or(0);
         if let Some(player_skill) = self.skills.get_mut(skill_name) {
+            if player_skill.is_maxed() {
+                return Err(format!("{} is already mastered. Nothing left to learn here", skill_name));
+            }
+            if usable < energy_cost {
+                if is_tired_hour(time_of_day) && self.energy >= energy_cost {
+                    return Err("You're too tired to study. Get some sleep".to_string());
+                }
+                return Err("Not enough energy to study".to_string());
+            }
+
             self.energy -= energy_cost;
-            let xp_gained = hours * 25;
+            player_skill.last_studied_day = Some(today);
+            let xp_gained = ((hours * STUDY_XP_PER_HOUR) as f32 * xp_multiplier).round() as u32;
             let leveled_up = player_skill.add_experience(xp_gained);
             
             if leveled_up {
@@ -102,6 +411,21 @@ impl Player {
         }
     }
 
+    /// Jump a skill straight to a proficiency, for debugging and QA
+    ///
+    /// Leftover XP is cleared so the skill looks as if it had just
+    /// reached that level. Career level is derived from skills, so it
+    /// follows automatically.
+    pub fn set_skill(&mut self, skill_name: &str, proficiency: Proficiency) -> Result<(), String> {
+        let skill = self
+            .skills
+            .get_mut(skill_name)
+            .ok_or_else(|| format!("Unknown skill: {}", skill_name))?;
+        skill.proficiency = proficiency;
+        skill.experience_points = 0;
+        Ok(())
+    }
+
     pub fn get_skill_proficiency(&self, skill_name: &str) -> Proficiency {
         self.skills
             .get(skill_name)
@@ -109,11 +433,32 @@ impl Player {
             .unwrap_or(Proficiency::None)
     }
 
-    pub fn advance_day(&mut self) {
+    /// Move to the next day. Returns the offers that lapsed overnight.
+    pub fn advance_day(&mut self) -> Vec<Offer> {
+        self.track_burnout();
+        self.apply_focus_trickle();
         self.day += 1;
         if self.employed {
             self.money += self.current_salary / 22;
+            self.experience_days += 1;
         }
+        self.run_venture();
+        self.expire_offers()
+    }
+
+    /// Aggregate proficiency in a category, weighted by skill difficulty
+    pub fn category_proficiency(&self, category: SkillCategory) -> u32 {
+        self.skills
+            .values()
+            .filter(|s| s.skill.category == category)
+            .map(PlayerSkill::weighted_proficiency)
+            .sum()
+    }
+
+    /// Headline progress number derived from proficiency across all skills
+    pub fn career_level(&self) -> u32 {
+        let points: u32 = self.skills.values().map(PlayerSkill::weighted_proficiency).sum();
+        points / CAREER_POINTS_PER_LEVEL
     }
 
     pub fn get_skills_by_category(&self) -> HashMap<SkillCategory, Vec<(&String, &PlayerSkill)>> {
@@ -131,6 +476,21 @@ impl Player {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::skills::get_all_skills;
+
+    #[test]
+    fn test_progress_percent() {
+        let mut skill = Player::new("Test").skills["Python"].clone();
+        assert_eq!(skill.progress_label(), "0%");
+
+        let needed = skill.points_to_next_level();
+        skill.experience_points = needed * 57 / 100;
+        assert_eq!(skill.progress_percent(), Some(57));
+
+        skill.proficiency = Proficiency::Expert;
+        assert_eq!(skill.progress_percent(), None);
+        assert_eq!(skill.progress_label(), "MAX");
+    }
 
     #[test]
     fn test_player_creation() {
@@ -139,14 +499,14 @@ mod tests {
         assert_eq!(player.energy, 100);
         assert_eq!(player.money, 1000);
         assert!(!player.employed);
-        assert!(player.skills.len() > 0);
+        assert!(!player.skills.is_empty());
     }
 
     #[test]
     fn test_study_reduces_energy() {
         let mut player = Player::new("Test");
         let initial_energy = player.energy;
-        let result = player.study("Python", 2);
+        let result = player.study("Python", 2, 14.0);
         assert!(result.is_ok());
         assert_eq!(player.energy, initial_energy - 20);
     }
@@ -154,19 +514,68 @@ mod tests {
     #[test]
     fn test_study_unknown_skill() {
         let mut player = Player::new("Test");
-        let result = player.study("NonexistentSkill", 2);
+        let result = player.study("NonexistentSkill", 2, 14.0);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_study_records_last_studied_day() {
+        let mut player = Player::new("Test");
+        player.day = 7;
+        assert_eq!(player.skills["SQL"].last_practiced_label(7), "never");
+
+        player.study("SQL", 2, 14.0).unwrap();
+
+        assert_eq!(player.skills["SQL"].last_studied_day, Some(7));
+        assert_eq!(player.skills["SQL"].last_practiced_label(10), "3 days ago");
+        assert_eq!(player.skills["Python"].last_studied_day, None);
+    }
+
     #[test]
     fn test_study_not_enough_energy() {
         let mut player = Player::new("Test");
         player.energy = 5;
-        let result = player.study("Python", 2);
+        let result = player.study("Python", 2, 14.0);
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Not enough energy"));
     }
 
+    #[test]
+    fn test_study_cost_scales_with_difficulty() {
+        let mut player = Player::new("Test");
+        player.study("Python", 2, 14.0).unwrap();
+        let easy_cost = 100 - player.energy;
+
+        player.rest();
+        player.study("Rust", 2, 14.0).unwrap();
+        let hard_cost = 100 - player.energy;
+
+        assert_eq!(easy_cost, 20);
+        assert_eq!(hard_cost, 60);
+        assert!(hard_cost > easy_cost);
+    }
+
+    #[test]
+    fn test_study_capped_at_night() {
+        let mut player = Player::new("Test");
+        player.energy = 80;
+        // Seven hours of Python costs 70: affordable by day, above the night cap
+        let result = player.study("Python", 7, 3.0);
+        assert!(result.unwrap_err().contains("tired"));
+        assert_eq!(player.energy, 80);
+        assert!(player.study("Python", 7, 14.0).is_ok());
+    }
+
+    #[test]
+    fn test_study_hard_skill_not_enough_energy() {
+        let mut player = Player::new("Test");
+        player.energy = 40;
+        // Python (difficulty 1) is affordable, Rust (difficulty 3) is not
+        assert!(player.study("Rust", 2, 14.0).is_err());
+        assert_eq!(player.energy, 40);
+        assert!(player.study("Python", 2, 14.0).is_ok());
+    }
+
     #[test]
     fn test_skill_level_up() {
         let skill = get_all_skills().into_iter().find(|s| s.name == "Python").unwrap();
@@ -179,6 +588,16 @@ mod tests {
         assert_eq!(player_skill.proficiency, Proficiency::Basic);
     }
 
+    #[test]
+    fn test_xp_curve_grows_with_level() {
+        let none_to_basic = xp_to_next_level(2, Proficiency::None);
+        let advanced_to_expert = xp_to_next_level(2, Proficiency::Advanced);
+        assert_eq!(none_to_basic, 200);
+        assert!(advanced_to_expert > none_to_basic);
+        assert!(xp_to_next_level(2, Proficiency::Basic) < advanced_to_expert);
+        assert_eq!(xp_to_next_level(2, Proficiency::Expert), 0);
+    }
+
     #[test]
     fn test_skill_partial_xp() {
         let skill = get_all_skills().into_iter().find(|s| s.name == "Python").unwrap();
@@ -190,6 +609,68 @@ mod tests {
         assert_eq!(player_skill.experience_points, 50);
     }
 
+    #[test]
+    fn test_study_mastered_skill_is_free_and_reported() {
+        let mut player = Player::builder("Test").skill("Python", Proficiency::Expert).build();
+        let energy = player.energy;
+
+        let err = player.study("Python", 2, 14.0).unwrap_err();
+
+        assert!(err.contains("already mastered"));
+        assert_eq!(player.energy, energy);
+        assert_eq!(player.skills["Python"].points_to_next_level(), 0);
+    }
+
+    #[test]
+    fn test_reaching_expert_clears_leftover_xp() {
+        let skill = get_all_skills().into_iter().find(|s| s.name == "Python").unwrap();
+        let mut player_skill = PlayerSkill::new(skill);
+        player_skill.proficiency = Proficiency::Advanced;
+
+        let needed = player_skill.points_to_next_level();
+        assert!(player_skill.add_experience(needed + 50));
+        assert!(player_skill.is_maxed());
+        assert_eq!(player_skill.experience_points, 0);
+        assert!(!player_skill.add_experience(100));
+        assert_eq!(player_skill.experience_points, 0);
+    }
+
+    #[test]
+    fn test_set_skill_sets_level_and_clears_xp() {
+        let mut player = Player::new("Test");
+        player.skills.get_mut("Python").unwrap().experience_points = 40;
+        let level_before = player.career_level();
+
+        player.set_skill("Python", Proficiency::Expert).unwrap();
+
+        let python = &player.skills["Python"];
+        assert_eq!(python.proficiency, Proficiency::Expert);
+        assert_eq!(python.experience_points, 0);
+        assert!(player.career_level() > level_before);
+
+        player.set_skill("Python", Proficiency::None).unwrap();
+        assert_eq!(player.career_level(), level_before);
+        assert!(player.set_skill("Cobol", Proficiency::Basic).is_err());
+    }
+
+    #[test]
+    fn test_toggle_bookmark() {
+        let mut player = Player::new("Test");
+        assert!(player.toggle_bookmark(4));
+        assert!(player.is_bookmarked(4));
+        assert!(!player.toggle_bookmark(4));
+        assert!(!player.is_bookmarked(4));
+        assert!(player.bookmarked_jobs.is_empty());
+    }
+
+    #[test]
+    fn test_research_job_is_remembered() {
+        let mut player = Player::new("Test");
+        assert!(!player.has_researched(3));
+        player.research_job(3);
+        assert!(player.has_researched(3));
+    }
+
     #[test]
     fn test_rest() {
         let mut player = Player::new("Test");
@@ -206,6 +687,34 @@ mod tests {
         assert_eq!(player.day, initial_day + 1);
     }
 
+    #[test]
+    fn test_accept_job_records_history() {
+        let mut player = Player::new("Test");
+        let job = crate::companies::get_all_companies()[0].open_positions[0].clone();
+        player.accept_job(&job, 95000);
+
+        assert!(player.employed);
+        assert_eq!(player.current_salary, 95000);
+        assert_eq!(player.job_history.len(), 1);
+        assert_eq!(player.job_history[0].title, job.title);
+
+        player.advance_day();
+        assert_eq!(player.experience_days, 1);
+    }
+
+    #[test]
+    fn test_interview_cooldown_expires() {
+        let mut player = Player::new("Test");
+        player.start_interview_cooldown(1);
+        assert_eq!(player.cooldown_remaining(1), Some(INTERVIEW_COOLDOWN_DAYS));
+        assert_eq!(player.cooldown_remaining(2), None);
+
+        for _ in 0..INTERVIEW_COOLDOWN_DAYS {
+            player.advance_day();
+        }
+        assert_eq!(player.cooldown_remaining(1), None);
+    }
+
     #[test]
     fn test_employed_salary() {
         let mut player = Player::new("Test");
@@ -215,4 +724,25 @@ mod tests {
         player.advance_day();
         assert!(player.money > initial_money);
     }
+
+    #[test]
+    fn test_fresh_player_career_level_zero() {
+        let player = Player::new("Test");
+        assert_eq!(player.career_level(), 0);
+        assert_eq!(player.category_proficiency(SkillCategory::Programming), 0);
+    }
+
+    #[test]
+    fn test_career_level_rises_monotonically() {
+        let mut player = Player::new("Test");
+        let mut last = player.career_level();
+        for name in ["Python", "SQL", "Rust", "Python", "Rust"] {
+            let skill = player.skills.get_mut(name).unwrap();
+            skill.proficiency = skill.proficiency.next().unwrap();
+            let level = player.career_level();
+            assert!(level >= last);
+            last = level;
+        }
+        assert!(last > 0);
+    }
 }
@@ -0,0 +1,93 @@
+//! Player Name Input
+//!
+//! Text entry for the title screen. Any Unicode letter or digit is
+//! accepted (so "José" or "Łukasz" work), as are spaces. Combining marks
+//! and other non-alphanumerics are rejected, which keeps every stored
+//! `char` a visible character: the length cap counts them, and backspace
+//! removes exactly one.
+
+/// Longest name allowed, in characters
+pub const MAX_NAME_CHARS: usize = 20;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NameInput {
+    text: String,
+}
+
+impl NameInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a typed character; returns false if it was rejected
+    pub fn push(&mut self, c: char) -> bool {
+        let allowed = c.is_alphanumeric() || c == ' ';
+        if !allowed || self.char_count() >= MAX_NAME_CHARS {
+            return false;
+        }
+        self.text.push(c);
+        true
+    }
+
+    /// Remove the last character
+    pub fn backspace(&mut self) {
+        self.text.pop();
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Text as typed, for display
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Name to use for the player, without surrounding spaces
+    pub fn name(&self) -> &str {
+        self.text.trim()
+    }
+
+    /// True until something other than spaces has been typed
+    pub fn is_empty(&self) -> bool {
+        self.name().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accented_characters_accepted() {
+        let mut input = NameInput::new();
+        for c in "José Ñoño".chars() {
+            assert!(input.push(c));
+        }
+        assert_eq!(input.name(), "José Ñoño");
+        assert_eq!(input.char_count(), 9);
+
+        input.backspace();
+        assert_eq!(input.name(), "José Ñoñ");
+        assert!(!input.push('\u{301}'));
+        assert!(!input.push('!'));
+    }
+
+    #[test]
+    fn test_cap_counts_chars_not_bytes() {
+        let mut input = NameInput::new();
+        for _ in 0..MAX_NAME_CHARS {
+            assert!(input.push('é'));
+        }
+        assert!(!input.push('é'));
+        assert_eq!(input.char_count(), MAX_NAME_CHARS);
+        assert_eq!(input.as_str().len(), MAX_NAME_CHARS * 2);
+    }
+
+    #[test]
+    fn test_spaces_only_is_empty() {
+        let mut input = NameInput::new();
+        input.push(' ');
+        assert!(input.is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+//! Pending Offers
+//!
+//! A passed interview leaves its offer open instead of hiring on the
+//! spot, so the player can weigh it against others. Open offers lapse
+//! after `OFFER_WINDOW_DAYS`, which forces a decision.
+
+use serde::{Deserialize, Serialize};
+
+use super::Player;
+use crate::jobs::Job;
+
+/// Days an offer stays open after the day it was made
+pub const OFFER_WINDOW_DAYS: u32 = 3;
+
+/// A job offer the player hasn't accepted or declined yet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Offer {
+    pub job: Job,
+    pub salary: u32,
+    /// Last day the offer can be accepted
+    pub expires_on_day: u32,
+}
+
+impl Offer {
+    pub fn new(job: &Job, salary: u32, today: u32) -> Self {
+        Self {
+            job: job.clone(),
+            salary,
+            expires_on_day: today + OFFER_WINDOW_DAYS,
+        }
+    }
+
+    /// Days left to decide, counting today
+    pub fn days_remaining(&self, today: u32) -> u32 {
+        (self.expires_on_day + 1).saturating_sub(today)
+    }
+
+    pub fn is_expired(&self, today: u32) -> bool {
+        today > self.expires_on_day
+    }
+}
+
+impl Player {
+    /// Hold an offer open, replacing an earlier one for the same job
+    pub fn receive_offer(&mut self, job: &Job, salary: u32) -> &Offer {
+        self.pending_offers.retain(|o| o.job.id != job.id);
+        self.pending_offers.push(Offer::new(job, salary, self.day));
+        self.pending_offers.last().expect("offer was just added")
+    }
+
+    pub fn pending_offer(&self, job_id: u32) -> Option<&Offer> {
+        self.pending_offers.iter().find(|o| o.job.id == job_id)
+    }
+
+    /// Remove an offer, e.g. when the player accepts or declines it
+    pub fn take_offer(&mut self, job_id: u32) -> Option<Offer> {
+        let idx = self.pending_offers.iter().position(|o| o.job.id == job_id)?;
+        Some(self.pending_offers.remove(idx))
+    }
+
+    /// Take the job from a pending offer
+    ///
+    /// Refused while the player already has a job or runs a startup; the
+    /// offer then stays open.
+    pub fn accept_offer(&mut self, job_id: u32) -> Result<Offer, String> {
+        if self.pending_offer(job_id).is_none() {
+            return Err("That offer is no longer open".to_string());
+        }
+        if self.employed {
+            let employer = self.job_history.last().map_or("your employer", |job| job.company.as_str());
+            return Err(format!("You already work at {}. Offers can only be taken between jobs.", employer));
+        }
+        if self.active_venture().is_some() {
+            return Err("You're running a startup. Offers can only be taken once it ends.".to_string());
+        }
+        let offer = self.take_offer(job_id).expect("offer was just found");
+        self.accept_job(&offer.job, offer.salary);
+        Ok(offer)
+    }
+
+    /// Drop offers past their last day, returning them
+    pub fn expire_offers(&mut self) -> Vec<Offer> {
+        let day = self.day;
+        let (expired, kept): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.pending_offers).into_iter().partition(|o| o.is_expired(day));
+        self.pending_offers = kept;
+        expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies::get_all_companies;
+
+    #[test]
+    fn test_offer_lapses_after_its_window() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut player = Player::new("Test");
+        assert_eq!(player.day, 1);
+        player.receive_offer(&job, 120000);
+
+        player.advance_day();
+        player.advance_day();
+        assert_eq!(player.day, 3);
+        assert_eq!(player.pending_offer(job.id).unwrap().days_remaining(player.day), 2);
+
+        let expired: Vec<Offer> = (0..2).flat_map(|_| player.advance_day()).collect();
+        assert_eq!(player.day, 5);
+        assert!(player.pending_offer(job.id).is_none());
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].job.company, job.company);
+    }
+
+    #[test]
+    fn test_accepting_an_offer_hires_at_its_salary() {
+        let job = get_all_companies()[0].open_positions[0].clone();
+        let mut player = Player::new("Test");
+        player.receive_offer(&job, 120000);
+
+        let offer = player.accept_offer(job.id).unwrap();
+        assert_eq!(offer.salary, 120000);
+        assert!(player.employed);
+        assert_eq!(player.current_salary, 120000);
+        assert!(player.pending_offers.is_empty());
+        assert!(player.accept_offer(job.id).is_err());
+    }
+
+    #[test]
+    fn test_offer_stays_open_while_employed_or_running_a_startup() {
+        let companies = get_all_companies();
+        let (first, second) = (&companies[0].open_positions[0], &companies[1].open_positions[0]);
+        let mut player = Player::new("Test");
+        player.accept_job(first, 90000);
+        player.receive_offer(second, 120000);
+
+        let err = player.accept_offer(second.id).unwrap_err();
+        assert!(err.contains(&first.company));
+        assert_eq!(player.current_salary, 90000);
+        assert!(player.pending_offer(second.id).is_some());
+
+        player.employed = false;
+        player.venture = Some(crate::player::Venture {
+            seed: 1,
+            founded_day: 1,
+            balance: 10_000,
+            last_result: 0,
+            outcome: None,
+        });
+        assert!(player.accept_offer(second.id).is_err());
+        assert!(player.pending_offer(second.id).is_some());
+    }
+}
@@ -0,0 +1,115 @@
+//! Part-time Work
+//!
+//! Low-skill side jobs that bridge the gap between unemployed and a first
+//! ML role. A shift is worked every business day: it pays a little and
+//! costs energy and hours, but it isn't career experience.
+
+use serde::{Deserialize, Serialize};
+
+use super::{is_business_day, Player};
+use crate::skills::Proficiency;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PartTimeJob {
+    DataLabeler,
+    TeachingAssistant,
+}
+
+impl PartTimeJob {
+    pub const ALL: [PartTimeJob; 2] = [PartTimeJob::DataLabeler, PartTimeJob::TeachingAssistant];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            PartTimeJob::DataLabeler => "Data Labeler",
+            PartTimeJob::TeachingAssistant => "TA",
+        }
+    }
+
+    /// Money earned per shift
+    pub fn daily_pay(self) -> u32 {
+        match self {
+            PartTimeJob::DataLabeler => 90,
+            PartTimeJob::TeachingAssistant => 140,
+        }
+    }
+
+    /// Energy a shift takes out of the day
+    pub fn energy_cost(self) -> u32 {
+        match self {
+            PartTimeJob::DataLabeler => 25,
+            PartTimeJob::TeachingAssistant => 30,
+        }
+    }
+
+    /// Length of a shift
+    pub fn hours(self) -> f32 {
+        match self {
+            PartTimeJob::DataLabeler => 4.0,
+            PartTimeJob::TeachingAssistant => 3.0,
+        }
+    }
+
+    /// Skill needed to be hired, if any
+    pub fn requirement(self) -> Option<(&'static str, Proficiency)> {
+        match self {
+            PartTimeJob::DataLabeler => None,
+            PartTimeJob::TeachingAssistant => Some(("Python", Proficiency::Basic)),
+        }
+    }
+
+    /// Dialog choice offering this job
+    pub fn label(self) -> String {
+        format!("Part-time {} (${}/workday, {}h)", self.title(), self.daily_pay(), self.hours())
+    }
+}
+
+impl Player {
+    /// Start a part-time job, replacing any current one
+    pub fn take_part_time_job(&mut self, job: PartTimeJob) -> Result<(), String> {
+        if self.employed {
+            return Err("You already have a full-time job".to_string());
+        }
+        if self.part_time == Some(job) {
+            return Err(format!("You already work as a {}", job.title()));
+        }
+        if let Some((skill, level)) = job.requirement() {
+            if self.get_skill_proficiency(skill) < level {
+                return Err(format!("A {} needs {} at {} or better", job.title(), skill, level.as_str()));
+            }
+        }
+        self.part_time = Some(job);
+        Ok(())
+    }
+
+    /// Leave the part-time job, returning it if there was one
+    pub fn quit_part_time_job(&mut self) -> Option<PartTimeJob> {
+        self.part_time.take()
+    }
+
+    /// Work today's shift if it's a business day
+    ///
+    /// Pays and costs energy; returns the hours the shift took. Shifts
+    /// don't count toward `experience_days`.
+    pub fn work_part_time_shift(&mut self) -> Option<f32> {
+        let job = self.part_time.filter(|_| !self.employed && is_business_day(self.day))?;
+        self.energy = self.energy.saturating_sub(job.energy_cost());
+        self.money += job.daily_pay();
+        Some(job.hours())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_teaching_needs_python_and_full_time_ends_part_time() {
+        let mut player = Player::builder("Test").skill("Python", Proficiency::None).build();
+        assert!(player.take_part_time_job(PartTimeJob::TeachingAssistant).is_err());
+        player.take_part_time_job(PartTimeJob::DataLabeler).unwrap();
+
+        player.employed = true;
+        assert_eq!(player.work_part_time_shift(), None);
+        assert!(player.take_part_time_job(PartTimeJob::TeachingAssistant).is_err());
+    }
+}
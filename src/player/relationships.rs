@@ -0,0 +1,97 @@
+//! NPC Relationships
+//!
+//! What each NPC remembers about the player: how often they've talked,
+//! how friendly they are, and the last few things said. Kept on the
+//! player so it's saved with the game.
+
+use serde::{Deserialize, Serialize};
+
+use super::Player;
+
+/// Friendliness gained per conversation
+pub const FRIENDLINESS_PER_TALK: u32 = 5;
+/// Friendliness stops growing past this
+pub const MAX_FRIENDLINESS: u32 = 100;
+/// Friendliness at which an NPC treats the player as a friend
+pub const FRIEND_THRESHOLD: u32 = 25;
+/// Past lines remembered per NPC
+const MAX_NOTES: usize = 3;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Relationship {
+    pub friendliness: u32,
+    pub times_talked: u32,
+    pub last_talked_day: u32,
+    /// Most recent lines from past conversations, oldest first
+    pub notes: Vec<String>,
+}
+
+impl Relationship {
+    /// Opening line for rule-mode dialog, or None for a first meeting
+    pub fn greeting(&self, player_name: &str) -> Option<String> {
+        match self.times_talked {
+            0 => None,
+            _ if self.friendliness >= FRIEND_THRESHOLD => {
+                Some(format!("{}! Always a pleasure.", player_name))
+            }
+            _ => Some(format!("Oh, hi again, {}.", player_name)),
+        }
+    }
+
+    /// Continuity notes for an LLM system prompt
+    pub fn prompt_section(&self) -> String {
+        let mut section = format!(
+            "RELATIONSHIP:\n- You've spoken with this player {} time(s)\n- Friendliness: {}/{}",
+            self.times_talked, self.friendliness, MAX_FRIENDLINESS
+        );
+        if !self.notes.is_empty() {
+            section.push_str(&format!("\n- Recently discussed: {}", self.notes.join(" | ")));
+        }
+        section
+    }
+}
+
+impl Player {
+    pub fn relationship(&self, npc_name: &str) -> Option<&Relationship> {
+        self.relationships.get(npc_name)
+    }
+
+    /// Remember a conversation with an NPC and what was said
+    pub fn record_talk(&mut self, npc_name: &str, line: &str) -> &Relationship {
+        let day = self.day;
+        let relationship = self.relationships.entry(npc_name.to_string()).or_default();
+        relationship.times_talked += 1;
+        relationship.friendliness = (relationship.friendliness + FRIENDLINESS_PER_TALK).min(MAX_FRIENDLINESS);
+        relationship.last_talked_day = day;
+        relationship.notes.push(line.to_string());
+        if relationship.notes.len() > MAX_NOTES {
+            relationship.notes.remove(0);
+        }
+        relationship
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_talking_builds_friendliness_and_changes_greeting() {
+        let mut player = Player::new("Ada");
+        assert!(player.relationship("Alex").is_none());
+
+        let first = player.record_talk("Alex", "Hey! I'm a recruiter.").clone();
+        assert_eq!(first.times_talked, 1);
+        assert_eq!(first.friendliness, FRIENDLINESS_PER_TALK);
+        assert_eq!(first.greeting("Ada").as_deref(), Some("Oh, hi again, Ada."));
+
+        for i in 0..10 {
+            player.record_talk("Alex", &format!("Line {}", i));
+        }
+        let rel = player.relationship("Alex").unwrap();
+        assert!(rel.friendliness >= FRIEND_THRESHOLD);
+        assert!(rel.greeting("Ada").unwrap().contains("pleasure"));
+        assert_eq!(rel.notes, vec!["Line 7", "Line 8", "Line 9"]);
+        assert!(rel.prompt_section().contains("11 time(s)"));
+    }
+}
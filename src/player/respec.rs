@@ -0,0 +1,146 @@
+//! Skill Respec
+//!
+//! Lets the player undo study time: a skill is reset to None for a fee
+//! and part of the XP it took is refunded into a pool that can be poured
+//! into other skills.
+
+use super::{xp_to_next_level, Player};
+use crate::skills::Proficiency;
+
+/// Money charged per respec
+pub const RESPEC_COST: u32 = 300;
+/// Reputation lost per respec, down to zero
+pub const RESPEC_REPUTATION_PENALTY: u32 = 2;
+/// Share of a skill's invested XP returned to the pool
+pub const RESPEC_REFUND_FRACTION: f32 = 0.5;
+
+impl Player {
+    /// XP spent on a skill so far: every level reached plus progress to the next
+    pub fn invested_xp(&self, skill_name: &str) -> u32 {
+        let Some(skill) = self.skills.get(skill_name) else { return 0 };
+        let mut total = skill.experience_points;
+        let mut level = Proficiency::None;
+        while level < skill.proficiency {
+            total += xp_to_next_level(skill.skill.difficulty, level);
+            match level.next() {
+                Some(next) => level = next,
+                None => break,
+            }
+        }
+        total
+    }
+
+    /// Reset a skill to None, refunding part of its XP into `xp_pool`
+    ///
+    /// Costs money and some reputation. Skills the current job requires
+    /// can't be reset. Returns the XP refunded.
+    pub fn respec_skill(&mut self, skill_name: &str) -> Result<u32, String> {
+        let invested = match self.skills.get(skill_name) {
+            Some(_) => self.invested_xp(skill_name),
+            None => return Err(format!("Unknown skill: {}", skill_name)),
+        };
+        if invested == 0 {
+            return Err(format!("You haven't put any time into {} yet", skill_name));
+        }
+        if self.employed {
+            if let Some(job) = self.job_history.last() {
+                if job.required_skills.iter().any(|s| s == skill_name) {
+                    return Err(format!("Your job at {} needs {}", job.company, skill_name));
+                }
+            }
+        }
+        if self.money < RESPEC_COST {
+            return Err(format!("A respec costs ${}", RESPEC_COST));
+        }
+
+        self.money -= RESPEC_COST;
+        self.reputation = self.reputation.saturating_sub(RESPEC_REPUTATION_PENALTY);
+        let refund = (invested as f32 * RESPEC_REFUND_FRACTION).round() as u32;
+        self.xp_pool += refund;
+        if let Some(skill) = self.skills.get_mut(skill_name) {
+            skill.proficiency = Proficiency::None;
+            skill.experience_points = 0;
+        }
+        Ok(refund)
+    }
+
+    /// Move pooled XP into a skill, leveling it as far as the pool allows
+    ///
+    /// XP left over once the skill is mastered stays in the pool.
+    pub fn allocate_pool_xp(&mut self, skill_name: &str) -> Result<String, String> {
+        if self.xp_pool == 0 {
+            return Err("No refunded XP to spend".to_string());
+        }
+        let skill = self
+            .skills
+            .get_mut(skill_name)
+            .ok_or_else(|| format!("Unknown skill: {}", skill_name))?;
+        if skill.is_maxed() {
+            return Err(format!("{} is already mastered", skill_name));
+        }
+
+        let mut spent = 0;
+        while spent < self.xp_pool && !skill.is_maxed() {
+            let needed = skill.points_to_next_level().saturating_sub(skill.experience_points);
+            let step = needed.min(self.xp_pool - spent);
+            skill.add_experience(step);
+            spent += step;
+        }
+        self.xp_pool -= spent;
+        Ok(format!(
+            "Put {} XP into {} (now {})",
+            spent,
+            skill_name,
+            skill.proficiency.as_str()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_respec_refunds_part_of_xp_and_resets_skill() {
+        let mut player = Player::builder("Test").money(1000).build();
+        player.set_skill("Python", Proficiency::Intermediate).unwrap();
+        let invested = player.invested_xp("Python");
+        assert!(invested > 0);
+
+        let refund = player.respec_skill("Python").unwrap();
+
+        assert!(refund > 0 && refund < invested);
+        assert_eq!(player.xp_pool, refund);
+        assert_eq!(player.get_skill_proficiency("Python"), Proficiency::None);
+        assert_eq!(player.skills["Python"].experience_points, 0);
+        assert_eq!(player.money, 1000 - RESPEC_COST);
+    }
+
+    #[test]
+    fn test_pool_xp_levels_another_skill() {
+        let mut player = Player::builder("Test").build();
+        player.xp_pool = 100_000;
+        let before = player.xp_pool;
+
+        player.allocate_pool_xp("SQL").unwrap();
+
+        assert_eq!(player.get_skill_proficiency("SQL"), Proficiency::Expert);
+        assert!(player.xp_pool > 0 && player.xp_pool < before);
+    }
+
+    #[test]
+    fn test_cannot_respec_skill_current_job_needs() {
+        let companies = crate::companies::get_all_companies();
+        let job = companies
+            .iter()
+            .flat_map(|c| &c.open_positions)
+            .find(|j| j.requirements.iter().any(|r| r.skill_name == "Python" && r.mandatory))
+            .expect("some job requires Python");
+        let mut player = Player::builder("Test").skill("Python", Proficiency::Advanced).build();
+        player.accept_job(job, 100_000);
+
+        assert!(player.respec_skill("Python").is_err());
+        assert_eq!(player.get_skill_proficiency("Python"), Proficiency::Advanced);
+        assert_eq!(player.xp_pool, 0);
+    }
+}
@@ -0,0 +1,137 @@
+//! Player Resume Export
+//!
+//! A shareable, human-readable summary of the player's career.
+//! The schema is kept separate from game state so exported files
+//! stay stable even as the internal player model changes.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::Player;
+use crate::skills::Proficiency;
+
+/// A skill listed on the resume
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResumeSkill {
+    pub name: String,
+    pub level: String,
+}
+
+/// A position listed on the resume
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResumeJob {
+    pub title: String,
+    pub company: String,
+    pub salary: u32,
+    pub start_day: u32,
+}
+
+/// Exportable resume built from the player's current state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Resume {
+    pub name: String,
+    pub skills: Vec<ResumeSkill>,
+    pub jobs: Vec<ResumeJob>,
+    pub experience_days: u32,
+    pub achievements: Vec<String>,
+}
+
+impl Resume {
+    /// Serialize as pretty-printed JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Write the resume as JSON to the given path
+    pub fn write_to(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+}
+
+impl Player {
+    /// Build a resume from the player's current state
+    ///
+    /// Only skills the player has actually learned are listed,
+    /// strongest first.
+    pub fn to_resume(&self) -> Resume {
+        let mut learned: Vec<_> = self
+            .skills
+            .iter()
+            .filter(|(_, s)| s.proficiency > Proficiency::None)
+            .collect();
+        learned.sort_by(|a, b| b.1.proficiency.cmp(&a.1.proficiency).then(a.0.cmp(b.0)));
+
+        let skills = learned
+            .iter()
+            .map(|(name, s)| ResumeSkill {
+                name: name.to_string(),
+                level: s.proficiency.as_str().to_string(),
+            })
+            .collect();
+
+        let jobs = self
+            .job_history
+            .iter()
+            .map(|j| ResumeJob {
+                title: j.title.clone(),
+                company: j.company.clone(),
+                salary: j.salary,
+                start_day: j.start_day,
+            })
+            .collect();
+
+        let mut achievements = Vec::new();
+        if !self.job_history.is_empty() {
+            achievements.push("Landed first job".to_string());
+        }
+        for (name, s) in &learned {
+            if s.proficiency == Proficiency::Expert {
+                achievements.push(format!("Mastered {}", name));
+            }
+        }
+
+        Resume {
+            name: self.name.clone(),
+            skills,
+            jobs,
+            experience_days: self.experience_days,
+            achievements,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resume_lists_learned_skill() {
+        let mut player = Player::new("Ada");
+        player.skills.get_mut("Python").unwrap().proficiency = Proficiency::Intermediate;
+
+        let resume = player.to_resume();
+        assert_eq!(resume.name, "Ada");
+        assert_eq!(
+            resume.skills,
+            vec![ResumeSkill {
+                name: "Python".to_string(),
+                level: "Intermediate".to_string(),
+            }]
+        );
+        assert!(resume.jobs.is_empty());
+    }
+
+    #[test]
+    fn test_resume_json_round_trip() {
+        let mut player = Player::new("Ada");
+        player.skills.get_mut("SQL").unwrap().proficiency = Proficiency::Expert;
+
+        let resume = player.to_resume();
+        let json = resume.to_json().unwrap();
+        assert!(json.contains("\"Mastered SQL\""));
+
+        let parsed: Resume = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, resume);
+    }
+}
@@ -0,0 +1,126 @@
+//! Interview Scheduling
+//!
+//! Applying books an interview slot on the next business day instead of
+//! starting it on the spot. Slots the player sleeps through are forfeited.
+
+use serde::{Deserialize, Serialize};
+
+use super::Player;
+use crate::jobs::Job;
+
+/// Hour of the day interviews are booked for
+pub const INTERVIEW_HOUR: f32 = 10.0;
+/// How long an interview takes
+pub const INTERVIEW_LENGTH_HOURS: u32 = 2;
+/// Day 1 is a Monday; the last two days of each week are the weekend
+pub const BUSINESS_DAYS_PER_WEEK: u32 = 5;
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Short weekday name for a game day
+pub fn weekday_name(day: u32) -> &'static str {
+    WEEKDAYS[(day.saturating_sub(1) % 7) as usize]
+}
+
+pub fn is_business_day(day: u32) -> bool {
+    day.saturating_sub(1) % 7 < BUSINESS_DAYS_PER_WEEK
+}
+
+/// First business day strictly after `today`
+pub fn next_business_day(today: u32) -> u32 {
+    let mut day = today + 1;
+    while !is_business_day(day) {
+        day += 1;
+    }
+    day
+}
+
+/// An interview the player has booked but not yet attended
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledInterview {
+    pub job_id: u32,
+    pub title: String,
+    pub company: String,
+    pub day: u32,
+    pub hour: f32,
+}
+
+impl ScheduledInterview {
+    /// When the slot is, e.g. "Tue 10:00 (day 2)"
+    pub fn slot_label(&self) -> String {
+        format!("{} {:02}:00 (day {})", weekday_name(self.day), self.hour as u32, self.day)
+    }
+
+    /// Whether the slot has already passed at the given time
+    pub fn is_missed(&self, day: u32, time_of_day: f32) -> bool {
+        day > self.day || (day == self.day && time_of_day > self.hour)
+    }
+}
+
+impl Player {
+    /// Book an interview for the job on the next business day
+    pub fn schedule_interview(&mut self, job: &Job, today: u32) -> Result<ScheduledInterview, String> {
+        if let Some(existing) = self.scheduled_interview(job.id) {
+            return Err(format!("You're already booked with {} on {}", job.company, existing.slot_label()));
+        }
+        let slot = ScheduledInterview {
+            job_id: job.id,
+            title: job.title.clone(),
+            company: job.company.clone(),
+            day: next_business_day(today),
+            hour: INTERVIEW_HOUR,
+        };
+        self.scheduled_interviews.push(slot.clone());
+        Ok(slot)
+    }
+
+    pub fn scheduled_interview(&self, job_id: u32) -> Option<&ScheduledInterview> {
+        self.scheduled_interviews.iter().find(|s| s.job_id == job_id)
+    }
+
+    /// Remove a booking, e.g. when the player goes to it
+    pub fn take_scheduled_interview(&mut self, job_id: u32) -> Option<ScheduledInterview> {
+        let idx = self.scheduled_interviews.iter().position(|s| s.job_id == job_id)?;
+        Some(self.scheduled_interviews.remove(idx))
+    }
+
+    /// Drop bookings whose slot has passed, putting those jobs on cooldown
+    pub fn forfeit_missed_interviews(&mut self, day: u32, time_of_day: f32) -> Vec<ScheduledInterview> {
+        let (missed, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.scheduled_interviews)
+            .into_iter()
+            .partition(|s| s.is_missed(day, time_of_day));
+        self.scheduled_interviews = kept;
+        for slot in &missed {
+            self.start_interview_cooldown(slot.job_id);
+        }
+        missed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_business_day_skips_weekend() {
+        assert_eq!(next_business_day(1), 2);
+        assert_eq!(next_business_day(5), 8);
+        assert_eq!(next_business_day(6), 8);
+        assert_eq!(weekday_name(8), "Mon");
+    }
+
+    #[test]
+    fn test_missed_slot_is_forfeited_with_cooldown() {
+        let job = crate::companies::get_all_companies()[0].open_positions[0].clone();
+        let mut player = Player::new("Test");
+        player.schedule_interview(&job, 1).unwrap();
+        assert!(player.schedule_interview(&job, 1).is_err());
+
+        assert!(player.forfeit_missed_interviews(2, INTERVIEW_HOUR).is_empty());
+        let missed = player.forfeit_missed_interviews(2, INTERVIEW_HOUR + 1.0);
+
+        assert_eq!(missed.len(), 1);
+        assert!(player.scheduled_interviews.is_empty());
+        assert!(player.cooldown_remaining(job.id).is_some());
+    }
+}
@@ -0,0 +1,91 @@
+//! Study Group
+//!
+//! Collaborative study sessions with the Student NPC. Sessions cost a
+//! little money but grant bonus XP, and the bonus grows with friendship.
+
+use super::Player;
+
+/// Money the player chips in for each study group session
+pub const STUDY_GROUP_COST: u32 = 10;
+/// XP multiplier for a first session with the Student
+pub const STUDY_GROUP_BASE_BONUS: f32 = 1.25;
+/// Extra multiplier per friendship level
+pub const STUDY_GROUP_BONUS_PER_FRIENDSHIP: f32 = 0.05;
+/// Upper bound on the study group multiplier
+pub const MAX_STUDY_GROUP_BONUS: f32 = 1.75;
+/// Friendship stops growing past this level
+pub const MAX_STUDENT_FRIENDSHIP: u32 = 10;
+
+impl Player {
+    /// XP multiplier for studying with the Student at the current friendship
+    pub fn study_group_multiplier(&self) -> f32 {
+        let bonus = STUDY_GROUP_BASE_BONUS
+            + self.student_friendship as f32 * STUDY_GROUP_BONUS_PER_FRIENDSHIP;
+        bonus.min(MAX_STUDY_GROUP_BONUS)
+    }
+
+    /// Study together with the Student, splitting costs for bonus XP
+    pub fn study_with_group(
+        &mut self,
+        skill_name: &str,
+        hours: u32,
+        time_of_day: f32,
+    ) -> Result<String, String> {
+        if self.money < STUDY_GROUP_COST {
+            return Err(format!("You need ${} to join the study group", STUDY_GROUP_COST));
+        }
+
+        let multiplier = self.study_group_multiplier();
+        let result = self.study_with_multiplier(skill_name, hours, time_of_day, multiplier)?;
+        self.money -= STUDY_GROUP_COST;
+        self.student_friendship = (self.student_friendship + 1).min(MAX_STUDENT_FRIENDSHIP);
+        Ok(format!("{} (study group x{:.2})", result, multiplier))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_study_group_bonus_grows_with_friendship() {
+        let mut player = Player::new("Test");
+        let first = player.study_group_multiplier();
+        assert_eq!(first, STUDY_GROUP_BASE_BONUS);
+
+        player.study_with_group("Python", 1, 14.0).unwrap();
+        assert_eq!(player.student_friendship, 1);
+        assert_eq!(player.money, 1000 - STUDY_GROUP_COST);
+        assert!(player.study_group_multiplier() > first);
+    }
+
+    #[test]
+    fn test_study_group_bonus_is_capped() {
+        let mut player = Player::new("Test");
+        player.student_friendship = 100;
+        assert_eq!(player.study_group_multiplier(), MAX_STUDY_GROUP_BONUS);
+
+        for _ in 0..3 {
+            player.energy = player.max_energy;
+            player.study_with_group("Python", 1, 14.0).unwrap();
+        }
+        assert_eq!(player.student_friendship, MAX_STUDENT_FRIENDSHIP);
+    }
+
+    #[test]
+    fn test_study_group_grants_more_xp() {
+        let mut solo = Player::new("Solo");
+        let mut group = Player::new("Group");
+        solo.study("Python", 2, 14.0).unwrap();
+        group.study_with_group("Python", 2, 14.0).unwrap();
+        assert!(group.skills["Python"].experience_points > solo.skills["Python"].experience_points);
+    }
+
+    #[test]
+    fn test_study_group_requires_money() {
+        let mut player = Player::new("Test");
+        player.money = 5;
+        assert!(player.study_with_group("Python", 2, 14.0).is_err());
+        assert_eq!(player.student_friendship, 0);
+    }
+}
@@ -0,0 +1,209 @@
+//! Startup Venture
+//!
+//! The late-game alternative to a salary: put savings and hard-won skills
+//! into founding a startup. Each day the company books a profit or loss
+//! that depends on the founder's skills and a swinging market. It ends in
+//! an acquisition that pays out the balance, or in bankruptcy.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::Player;
+use crate::skills::Proficiency;
+
+/// Money the player puts in to found the startup; it becomes the starting balance
+pub const FOUNDING_COST: u32 = 50_000;
+/// Skills needed at `FOUNDING_PROFICIENCY` or better before founding
+pub const FOUNDING_SKILLS_REQUIRED: usize = 3;
+/// Level the founding skills must reach
+pub const FOUNDING_PROFICIENCY: Proficiency = Proficiency::Advanced;
+/// Fixed daily costs: rent, servers, payroll
+pub const VENTURE_DAILY_BURN: i64 = 2_000;
+/// Daily revenue in an average market with every skill at Expert
+pub const VENTURE_MAX_REVENUE: f32 = 6_000.0;
+/// Balance at which the startup gets acquired
+pub const VENTURE_EXIT_BALANCE: i64 = 250_000;
+
+/// How a venture ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VentureOutcome {
+    /// Bought out; the balance was paid to the founder
+    Acquired,
+    /// Ran out of money
+    Bankrupt,
+}
+
+/// A startup the player founded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Venture {
+    /// Seeds the daily market swings
+    pub seed: u64,
+    pub founded_day: u32,
+    /// Company money; profits and losses land here, not in the player's wallet
+    pub balance: i64,
+    /// Profit (or loss, if negative) booked at the end of the last day
+    pub last_result: i64,
+    /// Set once the venture is over
+    pub outcome: Option<VentureOutcome>,
+}
+
+impl Venture {
+    pub fn is_running(&self) -> bool {
+        self.outcome.is_none()
+    }
+
+    /// Market multiplier on revenue for a day, between 0 and 2
+    fn market(&self, day: u32) -> f32 {
+        let mut rng = StdRng::seed_from_u64(self.seed ^ (day as u64).wrapping_mul(0x2545_F491_4F6C_DD1D));
+        rng.gen_range(0.0..2.0)
+    }
+
+    /// Book one day's result and settle the venture if it hit an ending
+    fn run_day(&mut self, day: u32, edge: f32) {
+        let revenue = (VENTURE_MAX_REVENUE * edge * self.market(day)).round() as i64;
+        self.last_result = revenue - VENTURE_DAILY_BURN;
+        self.balance += self.last_result;
+        if self.balance <= 0 {
+            self.outcome = Some(VentureOutcome::Bankrupt);
+        } else if self.balance >= VENTURE_EXIT_BALANCE {
+            self.outcome = Some(VentureOutcome::Acquired);
+        }
+    }
+}
+
+impl Player {
+    /// The venture still being run, if any
+    pub fn active_venture(&self) -> Option<&Venture> {
+        self.venture.as_ref().filter(|v| v.is_running())
+    }
+
+    /// Why the player can't found a startup right now, if they can't
+    pub fn startup_readiness(&self) -> Result<(), String> {
+        if self.active_venture().is_some() {
+            return Err("You're already running a startup".to_string());
+        }
+        if self.money < FOUNDING_COST {
+            return Err(format!("Founding takes ${}, but you only have ${}", FOUNDING_COST, self.money));
+        }
+        let strong = self.skills.values().filter(|s| s.proficiency >= FOUNDING_PROFICIENCY).count();
+        if strong < FOUNDING_SKILLS_REQUIRED {
+            return Err(format!(
+                "Investors want {} skills at {} or better; you have {}",
+                FOUNDING_SKILLS_REQUIRED,
+                FOUNDING_PROFICIENCY.as_str(),
+                strong
+            ));
+        }
+        Ok(())
+    }
+
+    /// Quit any job and put `FOUNDING_COST` into a new startup
+    pub fn found_startup(&mut self, seed: u64) -> Result<(), String> {
+        self.startup_readiness()?;
+        self.money -= FOUNDING_COST;
+        self.employed = false;
+        self.current_salary = 0;
+        self.venture = Some(Venture {
+            seed,
+            founded_day: self.day,
+            balance: FOUNDING_COST as i64,
+            last_result: 0,
+            outcome: None,
+        });
+        Ok(())
+    }
+
+    /// How well the founder's skills turn into revenue, from 0 (no skills) to 1 (all Expert)
+    pub fn venture_edge(&self) -> f32 {
+        if self.skills.is_empty() {
+            return 0.0;
+        }
+        let levels: u32 = self.skills.values().map(|s| s.proficiency as u32).sum();
+        levels as f32 / (self.skills.len() as u32 * Proficiency::Expert as u32) as f32
+    }
+
+    /// Book the venture's daily result; called when a day ends
+    pub(super) fn run_venture(&mut self) {
+        let edge = self.venture_edge();
+        let day = self.day;
+        let Some(venture) = self.venture.as_mut().filter(|v| v.is_running()) else { return };
+        venture.run_day(day, edge);
+        if venture.outcome == Some(VentureOutcome::Acquired) {
+            self.money += venture.balance as u32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn founder(level: Proficiency) -> Player {
+        Player::builder("Founder")
+            .money(FOUNDING_COST + 1_000)
+            .skill("Python", level)
+            .skill("SQL", level)
+            .skill("Statistics", level)
+            .build()
+    }
+
+    #[test]
+    fn test_founding_consumes_the_required_money() {
+        let mut player = Player::builder("Broke").money(FOUNDING_COST - 1).skill("Python", Proficiency::Expert).build();
+        assert!(player.found_startup(1).is_err());
+        assert!(player.venture.is_none());
+
+        let mut player = founder(Proficiency::Advanced);
+        player.employed = true;
+        player.found_startup(1).unwrap();
+
+        assert_eq!(player.money, 1_000);
+        assert!(!player.employed);
+        assert_eq!(player.active_venture().unwrap().balance, FOUNDING_COST as i64);
+        assert!(player.found_startup(1).is_err());
+    }
+
+    #[test]
+    fn test_venture_income_grows_with_skill() {
+        let run = |mut player: Player| {
+            player.found_startup(7).unwrap();
+            for _ in 0..10 {
+                player.advance_day();
+            }
+            player.venture.unwrap()
+        };
+
+        let mut expert = founder(Proficiency::Expert);
+        for skill in expert.skills.values_mut() {
+            skill.proficiency = Proficiency::Expert;
+        }
+        let strong = run(expert.clone());
+        let weak = run(founder(Proficiency::Advanced));
+
+        assert_eq!(strong, run(expert));
+        assert!(strong.balance > weak.balance);
+        assert!(weak.balance < FOUNDING_COST as i64);
+    }
+
+    #[test]
+    fn test_venture_ends_in_acquisition_or_bankruptcy() {
+        let mut player = founder(Proficiency::Advanced);
+        player.found_startup(3).unwrap();
+        player.venture.as_mut().unwrap().balance = 1;
+        for skill in player.skills.values_mut() {
+            skill.proficiency = Proficiency::None;
+        }
+        player.advance_day();
+        assert_eq!(player.venture.as_ref().unwrap().outcome, Some(VentureOutcome::Bankrupt));
+        assert!(player.active_venture().is_none());
+
+        let mut player = founder(Proficiency::Advanced);
+        player.found_startup(3).unwrap();
+        player.venture.as_mut().unwrap().balance = VENTURE_EXIT_BALANCE + VENTURE_DAILY_BURN;
+        player.advance_day();
+        let venture = player.venture.as_ref().unwrap();
+        assert_eq!(venture.outcome, Some(VentureOutcome::Acquired));
+        assert_eq!(player.money, 1_000 + venture.balance as u32);
+    }
+}
@@ -0,0 +1,92 @@
+//! Study Venues
+//!
+//! Where a study session happens. The Library is free; the University
+//! charges tuition per session in exchange for more XP.
+
+use super::Player;
+
+/// Tuition charged per University session
+pub const UNIVERSITY_TUITION: u32 = 40;
+/// XP multiplier for studying at the University
+pub const UNIVERSITY_XP_MULTIPLIER: f32 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudyVenue {
+    pub name: &'static str,
+    pub xp_multiplier: f32,
+    /// Money charged per session
+    pub cost: u32,
+}
+
+impl StudyVenue {
+    pub const LIBRARY: StudyVenue = StudyVenue {
+        name: "Library",
+        xp_multiplier: 1.0,
+        cost: 0,
+    };
+
+    pub const UNIVERSITY: StudyVenue = StudyVenue {
+        name: "University",
+        xp_multiplier: UNIVERSITY_XP_MULTIPLIER,
+        cost: UNIVERSITY_TUITION,
+    };
+
+    /// Study screen title, e.g. "University ($40/session, x1.5 XP)"
+    pub fn label(&self) -> String {
+        if self.cost == 0 {
+            format!("{} (free)", self.name)
+        } else {
+            format!("{} (${}/session, x{:.1} XP)", self.name, self.cost, self.xp_multiplier)
+        }
+    }
+}
+
+impl Player {
+    /// Study at a venue, paying its tuition only if the session happens
+    pub fn study_at(
+        &mut self,
+        venue: &StudyVenue,
+        skill_name: &str,
+        hours: u32,
+        time_of_day: f32,
+    ) -> Result<String, String> {
+        if self.money < venue.cost {
+            return Err(format!("Tuition at the {} is ${}", venue.name, venue.cost));
+        }
+
+        let result = self.study_with_multiplier(skill_name, hours, time_of_day, venue.xp_multiplier)?;
+        self.money -= venue.cost;
+        if venue.cost == 0 {
+            Ok(result)
+        } else {
+            Ok(format!("{} (tuition ${})", result, venue.cost))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_university_gives_more_xp_for_tuition() {
+        let mut library = Player::builder("Test").money(100).build();
+        let mut university = Player::builder("Test").money(100).build();
+
+        library.study_at(&StudyVenue::LIBRARY, "Python", 1, 14.0).unwrap();
+        university.study_at(&StudyVenue::UNIVERSITY, "Python", 1, 14.0).unwrap();
+
+        let xp = |p: &Player| p.skills["Python"].experience_points;
+        assert!(xp(&university) > xp(&library));
+        assert_eq!(library.money, 100);
+        assert_eq!(university.money, 100 - UNIVERSITY_TUITION);
+    }
+
+    #[test]
+    fn test_university_without_tuition_fails_unchanged() {
+        let mut player = Player::builder("Test").money(UNIVERSITY_TUITION - 1).build();
+        assert!(player.study_at(&StudyVenue::UNIVERSITY, "Python", 1, 14.0).is_err());
+        assert_eq!(player.money, UNIVERSITY_TUITION - 1);
+        assert_eq!(player.skills["Python"].experience_points, 0);
+    }
+}
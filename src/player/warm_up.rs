@@ -0,0 +1,81 @@
+//! Interview Warm-up
+//!
+//! A short session before an interview that runs through the topics it
+//! will cover. It reveals which skills get tested and leaves the player
+//! sharper for the first question of that job's next interview.
+
+use super::Player;
+use crate::jobs::Job;
+
+/// Hours a warm-up takes
+pub const WARM_UP_HOURS: u32 = 1;
+/// Energy a warm-up costs
+pub const WARM_UP_ENERGY: u32 = 10;
+
+/// Skills a job's interview tests, from its mandatory requirements
+pub fn warm_up_topics(job: &Job) -> Vec<String> {
+    let mut topics: Vec<String> = Vec::new();
+    for req in job.requirements.iter().filter(|r| r.mandatory) {
+        if !topics.contains(&req.skill_name) {
+            topics.push(req.skill_name.clone());
+        }
+    }
+    topics
+}
+
+impl Player {
+    /// Warm up for a job's interview, returning the topics it will test
+    ///
+    /// Only one warm-up is held at a time; warming up for another job
+    /// replaces it.
+    pub fn warm_up(&mut self, job: &Job) -> Result<Vec<String>, String> {
+        if self.is_warmed_up_for(job.id) {
+            return Err(format!("You're already warmed up for {}", job.title));
+        }
+        if self.energy < WARM_UP_ENERGY {
+            return Err(format!("A warm-up takes {} energy, but you only have {}", WARM_UP_ENERGY, self.energy));
+        }
+        self.energy -= WARM_UP_ENERGY;
+        self.warmed_up_for = Some(job.id);
+        Ok(warm_up_topics(job))
+    }
+
+    pub fn is_warmed_up_for(&self, job_id: u32) -> bool {
+        self.warmed_up_for == Some(job_id)
+    }
+
+    /// Use up the warm-up as this job's interview starts; true if there was one
+    pub fn consume_warm_up(&mut self, job_id: u32) -> bool {
+        let warmed_up = self.is_warmed_up_for(job_id);
+        if warmed_up {
+            self.warmed_up_for = None;
+        }
+        warmed_up
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies::get_all_companies;
+
+    #[test]
+    fn test_warm_up_lists_mandatory_topics_and_lasts_one_interview() {
+        let companies = get_all_companies();
+        let job = companies.iter().flat_map(|c| &c.open_positions).find(|j| j.id == 2).unwrap();
+        let mut player = Player::new("Test");
+        let energy = player.energy;
+
+        let topics = player.warm_up(job).unwrap();
+
+        let mandatory: Vec<&str> = job.requirements.iter().filter(|r| r.mandatory).map(|r| r.skill_name.as_str()).collect();
+        assert_eq!(topics, mandatory);
+        assert!(job.requirements.iter().any(|r| !r.mandatory && !topics.contains(&r.skill_name)));
+        assert_eq!(player.energy, energy - WARM_UP_ENERGY);
+        assert!(player.warm_up(job).is_err());
+
+        assert!(!player.consume_warm_up(job.id + 1));
+        assert!(player.consume_warm_up(job.id));
+        assert!(!player.consume_warm_up(job.id));
+    }
+}
@@ -0,0 +1,312 @@
+//! Headless Control Server
+//!
+//! Lets bots, tutorials and remote tests drive a `GameSim` over a local
+//! socket. The protocol is line-delimited JSON: one command object per
+//! line in, one response object per line out.
+//!
+//! ```text
+//! > {"cmd":"study","skill":"Python","hours":2}
+//! < {"ok":true,"message":"Studied Python ...","state":{"day":1,"energy":80,...}}
+//! > {"cmd":"sleep"}
+//! > {"cmd":"interview","job_id":3}
+//! > {"cmd":"accept_offer","job_id":3}
+//! > {"cmd":"quit"}
+//! ```
+//!
+//! Only built with the `server` feature, and never touches the GUI.
+
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::game::{ActionResult, GameSim};
+use crate::player::STUDY_SESSION_HOURS;
+
+/// Address the server binds to unless told otherwise
+pub const DEFAULT_SERVER_ADDR: &str = "127.0.0.1:7878";
+
+/// Longest study request the server accepts, in hours
+pub const MAX_STUDY_HOURS: u32 = 24;
+
+/// A command sent by the client
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum Command {
+    /// Report the current state without changing anything
+    State,
+    /// Study a skill, by default for one session
+    Study { skill: String, hours: Option<u32> },
+    /// Sleep until the next morning
+    Sleep,
+    /// Let the scripted player take a whole day
+    RunDay,
+    /// The best job to apply for right now
+    BestJob,
+    /// Interview for a job by id; a pass leaves an offer open
+    Interview { job_id: u32 },
+    /// Take the job from a pending offer
+    AcceptOffer { job_id: u32 },
+    /// Turn down a pending offer
+    DeclineOffer { job_id: u32 },
+    /// Close the connection
+    Quit,
+}
+
+/// What the client needs to know about the game after each command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StateSummary {
+    pub day: u32,
+    pub time: String,
+    pub energy: u32,
+    pub max_energy: u32,
+    pub money: u32,
+    pub employed: bool,
+    pub salary: u32,
+    pub career_level: u32,
+    /// Job ids with an offer waiting for an answer
+    pub pending_offers: Vec<u32>,
+    /// Proficiency name by skill
+    pub skills: BTreeMap<String, String>,
+}
+
+impl StateSummary {
+    pub fn of(sim: &GameSim) -> Self {
+        let player = &sim.state.player;
+        Self {
+            day: sim.state.day,
+            time: sim.state.time_string(),
+            energy: player.energy,
+            max_energy: player.max_energy,
+            money: player.money,
+            employed: player.employed,
+            salary: player.current_salary,
+            career_level: player.career_level(),
+            pending_offers: player.pending_offers.iter().map(|offer| offer.job.id).collect(),
+            skills: player
+                .skills
+                .iter()
+                .map(|(name, skill)| (name.clone(), skill.proficiency.as_str().to_string()))
+                .collect(),
+        }
+    }
+}
+
+/// Reply to one command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Response {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateSummary>,
+}
+
+impl Response {
+    fn ok(sim: &GameSim, message: impl Into<String>) -> Self {
+        Self {
+            ok: true,
+            message: Some(message.into()),
+            error: None,
+            state: Some(StateSummary::of(sim)),
+        }
+    }
+
+    fn from_action(result: ActionResult, sim: &GameSim) -> Self {
+        if result.succeeded() {
+            Self::ok(sim, result.message())
+        } else {
+            Self::error(result.message())
+        }
+    }
+
+    fn error(error: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            message: None,
+            error: Some(error.into()),
+            state: None,
+        }
+    }
+}
+
+/// Run one command against the simulation
+pub fn execute(sim: &mut GameSim, command: Command) -> Response {
+    match command {
+        Command::State => Response::ok(sim, ""),
+        Command::Study { skill, hours } => {
+            let hours = hours.unwrap_or(STUDY_SESSION_HOURS);
+            if !(1..=MAX_STUDY_HOURS).contains(&hours) {
+                return Response::error(format!("Study hours must be between 1 and {}", MAX_STUDY_HOURS));
+            }
+            match sim.study_for(&skill, hours) {
+                Ok(message) => Response::ok(sim, message),
+                Err(reason) => Response::error(reason),
+            }
+        }
+        Command::Sleep => {
+            sim.sleep();
+            Response::ok(sim, format!("Woke up on day {}", sim.state.day))
+        }
+        Command::RunDay => {
+            sim.run_day();
+            Response::ok(sim, format!("Played through to day {}", sim.state.day))
+        }
+        Command::BestJob => match sim.best_job() {
+            Some((job, score)) => Response::ok(
+                sim,
+                format!("{} at {} (id {}, {:.0}% match)", job.title, job.company, job.id, score * 100.0),
+            ),
+            None => Response::ok(sim, "No job is open to you yet"),
+        },
+        Command::Interview { job_id } => {
+            let Some(job) = sim.find_job(job_id).cloned() else {
+                return Response::error(format!("No job with id {}", job_id));
+            };
+            if let Err(reason) = job.check_eligibility(&sim.state.player) {
+                return Response::error(reason);
+            }
+            if let Some(days) = sim.state.player.cooldown_remaining(job_id) {
+                return Response::error(format!("On cooldown for {} more day(s)", days));
+            }
+            let message = if sim.interview(&job) {
+                let days = sim.state.player.pending_offer(job_id).map_or(0, |offer| offer.days_remaining(sim.state.day));
+                format!("Offered {} at {}, open for {} day(s)", job.title, job.company, days)
+            } else {
+                format!("Didn't pass the {} interview", job.title)
+            };
+            Response::ok(sim, message)
+        }
+        Command::AcceptOffer { job_id } => Response::from_action(sim.accept_offer(job_id), sim),
+        Command::DeclineOffer { job_id } => Response::from_action(sim.decline_offer(job_id), sim),
+        Command::Quit => Response::ok(sim, "Bye"),
+    }
+}
+
+/// Answer commands line by line until `quit` or end of input
+///
+/// Generic over the transport so tests can drive it in memory.
+pub fn serve_connection(sim: &mut GameSim, reader: impl BufRead, mut writer: impl Write) -> Result<()> {
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (response, quit) = match serde_json::from_str::<Command>(&line) {
+            Ok(command) => {
+                let quit = command == Command::Quit;
+                (execute(sim, command), quit)
+            }
+            Err(e) => (Response::error(format!("Bad command: {}", e)), false),
+        };
+        serde_json::to_writer(&mut writer, &response)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        if quit {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Listen on `addr` and serve one client at a time, all sharing one game
+pub fn serve(addr: &str, seed: u64) -> Result<()> {
+    let listener = TcpListener::bind(addr).with_context(|| format!("Failed to bind {}", addr))?;
+    let mut sim = GameSim::new(seed);
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let reader = BufReader::new(stream.try_clone()?);
+        if let Err(e) = serve_connection(&mut sim, reader, stream) {
+            eprintln!("Connection closed: {:#}", e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(sim: &mut GameSim, input: &str) -> Vec<serde_json::Value> {
+        let mut output = Vec::new();
+        serve_connection(sim, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_commands_change_the_state() {
+        let mut sim = GameSim::new(1);
+        let responses = run(
+            &mut sim,
+            "{\"cmd\":\"state\"}\n{\"cmd\":\"study\",\"skill\":\"Python\",\"hours\":2}\n{\"cmd\":\"sleep\"}\n",
+        );
+
+        assert_eq!(responses.len(), 3);
+        assert!(responses.iter().all(|r| r["ok"] == true));
+        let energy_before = responses[0]["state"]["energy"].as_u64().unwrap();
+        let energy_after_study = responses[1]["state"]["energy"].as_u64().unwrap();
+        assert!(energy_after_study < energy_before);
+        assert_eq!(responses[2]["state"]["day"], 2);
+        assert!(sim.state.player.skills["Python"].experience_points > 0);
+    }
+
+    #[test]
+    fn test_bad_input_is_reported_and_quit_stops() {
+        let mut sim = GameSim::new(1);
+        let responses = run(
+            &mut sim,
+            "not json\n{\"cmd\":\"study\",\"skill\":\"Juggling\"}\n{\"cmd\":\"quit\"}\n{\"cmd\":\"sleep\"}\n",
+        );
+
+        assert_eq!(responses.len(), 3);
+        assert_eq!(responses[0]["ok"], false);
+        assert!(responses[1]["error"].as_str().unwrap().contains("Juggling"));
+        assert_eq!(sim.state.day, 1);
+    }
+
+    #[test]
+    fn test_offers_are_accepted_and_declined_by_job_id() {
+        let companies = crate::companies::get_all_companies();
+        let (first, second) = (companies[0].open_positions[0].clone(), companies[1].open_positions[0].clone());
+        let mut sim = GameSim::new(1);
+        sim.state.player.receive_offer(&first, 90_000);
+        sim.state.player.receive_offer(&second, 120_000);
+        let responses = run(
+            &mut sim,
+            &format!(
+                "{{\"cmd\":\"state\"}}\n{{\"cmd\":\"decline_offer\",\"job_id\":{0}}}\n\
+                 {{\"cmd\":\"accept_offer\",\"job_id\":{0}}}\n{{\"cmd\":\"accept_offer\",\"job_id\":{1}}}\n",
+                first.id, second.id
+            ),
+        );
+
+        assert_eq!(responses.len(), 4);
+        assert_eq!(responses[0]["state"]["pending_offers"], serde_json::json!([first.id, second.id]));
+        assert_eq!(responses[1]["ok"], true);
+        assert_eq!(responses[2]["ok"], false);
+        assert_eq!(responses[3]["state"]["employed"], true);
+        assert_eq!(responses[3]["state"]["salary"], 120_000);
+        assert_eq!(responses[3]["state"]["pending_offers"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_out_of_range_study_hours_are_rejected() {
+        let mut sim = GameSim::new(1);
+        let responses = run(
+            &mut sim,
+            "{\"cmd\":\"study\",\"skill\":\"Python\",\"hours\":4294967295}\n{\"cmd\":\"study\",\"skill\":\"Python\",\"hours\":0}\n",
+        );
+
+        assert_eq!(responses.len(), 2);
+        assert!(responses.iter().all(|r| r["ok"] == false));
+        assert_eq!(sim.state.time_of_day, GameSim::new(1).state.time_of_day);
+    }
+}
@@ -17,8 +17,9 @@ pub enum SkillCategory {
 }
 
 /// Proficiency levels for skills
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
 pub enum Proficiency {
+    #[default]
     None = 0,
     Basic = 1,
     Intermediate = 2,
@@ -26,12 +27,6 @@ pub enum Proficiency {
     Expert = 4,
 }
 
-impl Default for Proficiency {
-    fn default() -> Self {
-        Proficiency::None
-    }
-}
-
 impl Proficiency {
     pub fn next(&self) -> Option<Proficiency> {
         match self {
@@ -136,7 +131,7 @@ mod tests {
     #[test]
     fn test_get_all_skills() {
         let skills = get_all_skills();
-        assert!(skills.len() > 0);
+        assert!(!skills.is_empty());
 
         let python = skills.iter().find(|s| s.name == "Python");
         assert!(python.is_some());
@@ -1,7 +1,6 @@
-use crate::testing::input::{InputSnapshot, InputSource};
+use crate::testing::input::InputSnapshot;
 use crate::testing::canvas::{UiCanvas, MockCanvas};
 use crate::player::Player;
-use crate::skills::Proficiency;
 
 pub struct TestHarness {
     pub player: Player,
@@ -9,6 +8,14 @@ pub struct TestHarness {
     pub frames: Vec<InputSnapshot>,
     pub current_frame: usize,
     pub elapsed_time: f32,
+    /// In-game hour used for time-dependent player actions
+    pub time_of_day: f32,
+}
+
+impl Default for TestHarness {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TestHarness {
@@ -19,6 +26,7 @@ impl TestHarness {
             frames: Vec::new(),
             current_frame: 0,
             elapsed_time: 0.0,
+            time_of_day: 12.0,
         }
     }
     
@@ -74,7 +82,7 @@ impl TestHarness {
     }
     
     pub fn study_skill(&mut self, skill_name: &str, hours: u32) -> Result<String, String> {
-        self.player.study(skill_name, hours)
+        self.player.study(skill_name, hours, self.time_of_day)
     }
     
     pub fn rest(&mut self) {
@@ -96,16 +104,15 @@ impl TestHarness {
     }
 }
 
+#[derive(Default)]
 pub struct ScriptedInput {
     frames: Vec<InputSnapshot>,
-    current: usize,
 }
 
 impl ScriptedInput {
     pub fn new() -> Self {
         Self {
             frames: Vec::new(),
-            current: 0,
         }
     }
     
@@ -138,6 +145,7 @@ impl ScriptedInput {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::skills::Proficiency;
     
     #[test]
     fn test_harness_creation() {
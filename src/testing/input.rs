@@ -1,9 +1,15 @@
 use std::collections::HashSet;
 
+/// Everything the player did with keyboard and mouse in one frame
+///
+/// Keys are named by their lowercased macroquad `KeyCode`: "e", "enter",
+/// "escape", "up", "f3", "key1" and so on.
 #[derive(Clone, Debug, Default)]
 pub struct InputSnapshot {
     pub keys_down: HashSet<String>,
     pub keys_pressed: HashSet<String>,
+    /// Characters typed this frame, in order
+    pub chars_typed: Vec<char>,
     pub mouse_x: f32,
     pub mouse_y: f32,
     pub mouse_left_down: bool,
@@ -15,6 +21,7 @@ impl InputSnapshot {
         Self {
             keys_down: HashSet::new(),
             keys_pressed: HashSet::new(),
+            chars_typed: Vec::new(),
             mouse_x: 0.0,
             mouse_y: 0.0,
             mouse_left_down: false,
@@ -32,6 +39,11 @@ impl InputSnapshot {
         self
     }
 
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.chars_typed.extend(text.chars());
+        self
+    }
+
     pub fn with_mouse_pos(mut self, x: f32, y: f32) -> Self {
         self.mouse_x = x;
         self.mouse_y = y;
@@ -58,10 +70,12 @@ impl InputSnapshot {
 
     pub fn clear_pressed(&mut self) {
         self.keys_pressed.clear();
+        self.chars_typed.clear();
         self.mouse_left_pressed = false;
     }
 }
 
+/// Where each frame's input comes from: the window in the game, a script in tests
 pub trait InputSource {
     fn snapshot(&mut self) -> InputSnapshot;
 }
@@ -2,7 +2,7 @@ pub mod input;
 pub mod canvas;
 pub mod harness;
 
-pub use input::{InputSnapshot, InputSource};
+pub use input::{InputSnapshot, InputSource, ScriptedInputSource};
 pub use canvas::{UiCanvas, MockCanvas, DrawOp, Color};
 pub use harness::{TestHarness, ScriptedInput};
 
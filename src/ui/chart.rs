@@ -0,0 +1,61 @@
+//! Line Chart
+//!
+//! A bare line chart of a series of values, scaled to fill its box, for
+//! showing how something changed over time.
+
+use crate::graphics::draw_text_crisp;
+use macroquad::prelude::*;
+
+/// Screen positions of `values` spread across a `w` x `h` box at (`x`, `y`)
+///
+/// The lowest value sits on the bottom edge and the highest on the top;
+/// a flat series runs along the bottom.
+pub fn chart_points(values: &[f32], x: f32, y: f32, w: f32, h: f32) -> Vec<Vec2> {
+    let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+    let step = if values.len() > 1 { w / (values.len() - 1) as f32 } else { 0.0 };
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| {
+            let share = if range > 0.0 { (v - min) / range } else { 0.0 };
+            vec2(x + step * i as f32, y + h - share * h)
+        })
+        .collect()
+}
+
+/// Framed line chart with the highest and lowest values labeled
+pub fn draw_line_chart(values: &[f32], x: f32, y: f32, w: f32, h: f32, color: Color, label: impl Fn(f32) -> String) {
+    let frame = Color::from_rgba(80, 80, 80, 255);
+    draw_rectangle_lines(x, y, w, h, 1.0, frame);
+    if values.is_empty() {
+        draw_text_crisp("No data yet", x + 8.0, y + h / 2.0, 12.0, GRAY);
+        return;
+    }
+    let points = chart_points(values, x, y, w, h);
+    for pair in points.windows(2) {
+        draw_line(pair[0].x, pair[0].y, pair[1].x, pair[1].y, 2.0, color);
+    }
+    if let [only] = points.as_slice() {
+        draw_circle(only.x, only.y, 2.0, color);
+    }
+    let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+    draw_text_crisp(&label(max), x + w + 4.0, y + 10.0, 12.0, GRAY);
+    draw_text_crisp(&label(min), x + w + 4.0, y + h, 12.0, GRAY);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points_span_the_box() {
+        let points = chart_points(&[10.0, 30.0, 20.0], 0.0, 100.0, 200.0, 50.0);
+        assert_eq!(points, vec![vec2(0.0, 150.0), vec2(100.0, 100.0), vec2(200.0, 125.0)]);
+
+        let flat = chart_points(&[5.0, 5.0], 0.0, 0.0, 10.0, 10.0);
+        assert!(flat.iter().all(|p| p.y == 10.0));
+    }
+}
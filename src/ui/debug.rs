@@ -0,0 +1,146 @@
+use crate::game::GameState;
+use crate::graphics::{draw_text_crisp, fonts_loaded};
+use crate::player::Player;
+use crate::skills::Proficiency;
+use crate::world::TILE_SIZE;
+use macroquad::prelude::*;
+
+/// A named set of skill levels to jump to
+pub struct SkillProfile {
+    pub name: &'static str,
+    pub skills: &'static [(&'static str, Proficiency)],
+}
+
+/// Profiles cycled with F4 while the debug overlay is open
+pub const DEBUG_SKILL_PROFILES: &[SkillProfile] = &[
+    SkillProfile {
+        name: "Junior Data",
+        skills: &[
+            ("Python", Proficiency::Intermediate),
+            ("SQL", Proficiency::Intermediate),
+            ("Statistics", Proficiency::Basic),
+        ],
+    },
+    SkillProfile {
+        name: "LLM Engineer",
+        skills: &[
+            ("Python", Proficiency::Advanced),
+            ("PyTorch", Proficiency::Advanced),
+            ("Transformers", Proficiency::Advanced),
+            ("LLM Fine-tuning", Proficiency::Intermediate),
+            ("RAG", Proficiency::Intermediate),
+        ],
+    },
+    SkillProfile {
+        name: "Research Lead",
+        skills: &[
+            ("Python", Proficiency::Expert),
+            ("PyTorch", Proficiency::Expert),
+            ("Transformers", Proficiency::Expert),
+            ("LLM Fine-tuning", Proficiency::Expert),
+            ("Linear Algebra", Proficiency::Advanced),
+            ("System Design", Proficiency::Advanced),
+        ],
+    },
+];
+
+/// Set every skill in a profile; other skills are left alone
+pub fn apply_skill_profile(player: &mut Player, profile: &SkillProfile) -> Result<(), String> {
+    for (skill, proficiency) in profile.skills {
+        player.set_skill(skill, *proficiency)?;
+    }
+    Ok(())
+}
+
+/// Snapshot of the values shown in the F3 debug overlay
+#[derive(Debug, Clone)]
+pub struct DebugInfo {
+    pub fps: i32,
+    pub player_pos: (f32, f32),
+    pub camera_pos: (f32, f32),
+    pub nearest_interactable: Option<String>,
+}
+
+pub fn debug_overlay_lines(info: &DebugInfo, state: &GameState) -> Vec<String> {
+    let (px, py) = info.player_pos;
+    let tile_x = (px / TILE_SIZE).floor() as i32;
+    let tile_y = (py / TILE_SIZE).floor() as i32;
+
+    vec![
+        format!("FPS: {}", info.fps),
+        format!("Player: ({:.1}, {:.1}) tile ({}, {})", px, py, tile_x, tile_y),
+        format!("Camera: ({:.1}, {:.1})", info.camera_pos.0, info.camera_pos.1),
+        format!("Screen: {:?}", state.screen),
+        format!(
+            "Nearest: {}",
+            info.nearest_interactable.as_deref().unwrap_or("none")
+        ),
+        format!(
+            "Energy: {}/{} | ${} | Day {} {}",
+            state.player.energy,
+            state.player.max_energy,
+            state.player.money,
+            state.day,
+            state.time_string()
+        ),
+        format!("Font: {}", if fonts_loaded() { "custom" } else { "built-in (custom failed to load)" }),
+        "F4: apply next skill profile".to_string(),
+    ]
+}
+
+pub fn draw_debug_overlay(lines: &[String]) {
+    let font_size = 14.0;
+    let line_height = 16.0;
+    let width = 320.0;
+    let height = lines.len() as f32 * line_height + 10.0;
+    let x = screen_width() - width - 10.0;
+    let y = 40.0;
+
+    draw_rectangle(x, y, width, height, Color::from_rgba(0, 0, 0, 180));
+    for (i, line) in lines.iter().enumerate() {
+        draw_text_crisp(line, x + 6.0, y + 14.0 + i as f32 * line_height, font_size, LIME);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_overlay_lines() {
+        let state = GameState::with_seed("Test", 1);
+        let info = DebugInfo {
+            fps: 60,
+            player_pos: (TILE_SIZE * 3.5, TILE_SIZE * 2.0),
+            camera_pos: (10.0, 20.0),
+            nearest_interactable: Some("Library".to_string()),
+        };
+        let lines = debug_overlay_lines(&info, &state);
+        assert_eq!(lines[0], "FPS: 60");
+        assert!(lines[1].ends_with("tile (3, 2)"));
+        assert_eq!(lines[3], "Screen: Title");
+        assert_eq!(lines[4], "Nearest: Library");
+        assert!(lines[5].starts_with("Energy: 100/100"));
+    }
+
+    #[test]
+    fn test_debug_overlay_without_interactable() {
+        let state = GameState::with_seed("Test", 1);
+        let info = DebugInfo {
+            fps: 30,
+            player_pos: (0.0, 0.0),
+            camera_pos: (0.0, 0.0),
+            nearest_interactable: None,
+        };
+        assert_eq!(debug_overlay_lines(&info, &state)[4], "Nearest: none");
+    }
+
+    #[test]
+    fn test_skill_profiles_use_known_skills() {
+        for profile in DEBUG_SKILL_PROFILES {
+            let mut player = Player::new("Test");
+            apply_skill_profile(&mut player, profile).unwrap();
+            assert!(player.career_level() > 0, "{} grants no levels", profile.name);
+        }
+    }
+}
@@ -1,42 +1,215 @@
+use std::collections::BTreeSet;
+
+use crate::engine::HudConfig;
 use crate::game::GameState;
-use crate::graphics::draw_text_crisp;
+use crate::graphics::{draw_text_crisp, measure_text_crisp, AnimClock};
+use crate::player::Player;
 use macroquad::prelude::*;
 
-pub fn draw_hud(state: &GameState) {
-    let font_size = 20.0;
-    let mut x = 15.0;
+/// Left edge of the HUD row
+pub const HUD_START_X: f32 = 15.0;
+/// Space between HUD elements
+pub const HUD_GAP: f32 = 20.0;
+const HUD_FONT_SIZE: f32 = 20.0;
+/// Seconds per pulse of a critical warning
+const WARNING_PULSE_PERIOD: f32 = 0.8;
+
+/// Which optional HUD elements are shown
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HudSettings {
+    pub show_time: bool,
+    pub show_energy: bool,
+    pub show_money: bool,
+    /// Thresholds for the warning colors and icons
+    pub warnings: HudConfig,
+}
+
+impl Default for HudSettings {
+    fn default() -> Self {
+        Self {
+            show_time: true,
+            show_energy: true,
+            show_money: true,
+            warnings: HudConfig::default(),
+        }
+    }
+}
+
+/// A resource in a state the player should notice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HudWarning {
+    LowEnergy,
+    CriticalEnergy,
+    LowMoney,
+    Broke,
+    Burnout,
+}
+
+impl HudWarning {
+    /// Critical warnings pulse and get a double icon
+    pub fn is_critical(self) -> bool {
+        matches!(self, HudWarning::CriticalEnergy | HudWarning::Broke | HudWarning::Burnout)
+    }
+
+    pub fn icon(self) -> &'static str {
+        if self.is_critical() {
+            "!!"
+        } else {
+            "!"
+        }
+    }
+
+    pub fn color(self) -> Color {
+        if self.is_critical() {
+            RED
+        } else {
+            ORANGE
+        }
+    }
+}
+
+/// Warnings raised by the player's current resources
+///
+/// Energy and money each raise at most one warning, the critical one
+/// taking precedence over the low one.
+pub fn active_warnings(player: &Player, config: &HudConfig) -> BTreeSet<HudWarning> {
+    let mut warnings = BTreeSet::new();
+    if player.energy < config.energy_critical {
+        warnings.insert(HudWarning::CriticalEnergy);
+    } else if player.energy < config.energy_low {
+        warnings.insert(HudWarning::LowEnergy);
+    }
+    if player.money == 0 {
+        warnings.insert(HudWarning::Broke);
+    } else if player.money < config.money_low {
+        warnings.insert(HudWarning::LowMoney);
+    }
+    if player.burnout.is_active() {
+        warnings.insert(HudWarning::Burnout);
+    }
+    warnings
+}
+
+/// One piece of text in the HUD row
+#[derive(Debug, Clone, PartialEq)]
+pub struct HudElement {
+    pub text: String,
+    pub color: Color,
+    /// Warning this element is showing, if any
+    pub warning: Option<HudWarning>,
+}
+
+impl HudElement {
+    fn plain(text: String, color: Color) -> Self {
+        Self { text, color, warning: None }
+    }
+
+    /// `color` normally; the warning's icon and color while one is active
+    fn warned(text: String, color: Color, warning: Option<HudWarning>) -> Self {
+        match warning {
+            Some(w) => Self { text: format!("{} {}", w.icon(), text), color: w.color(), warning },
+            None => Self::plain(text, color),
+        }
+    }
+}
+
+/// Elements in the top HUD row, in display order
+pub fn hud_elements(state: &GameState, settings: &HudSettings) -> Vec<HudElement> {
+    let player = &state.player;
+    let warnings = active_warnings(player, &settings.warnings);
+    let find = |candidates: &[HudWarning]| candidates.iter().copied().find(|w| warnings.contains(w));
+    let mut elements = vec![
+        HudElement::plain(format!("Day {}", state.day), WHITE),
+        HudElement::plain(format!("Lv {}", player.career_level()), SKYBLUE),
+    ];
+    if settings.show_time {
+        elements.push(HudElement::plain(state.time_string(), LIGHTGRAY));
+    }
+    if settings.show_energy {
+        elements.push(HudElement::warned(
+            format!("Energy: {}/{}", player.energy, player.max_energy),
+            GREEN,
+            find(&[HudWarning::CriticalEnergy, HudWarning::LowEnergy]),
+        ));
+    }
+    if settings.show_money {
+        elements.push(HudElement::warned(
+            format!("${}", player.money),
+            GOLD,
+            find(&[HudWarning::Broke, HudWarning::LowMoney]),
+        ));
+    }
+    if player.employed {
+        elements.push(HudElement::plain(format!("EMPLOYED ${}/yr", player.current_salary), LIME));
+    }
+    if let Some(job) = player.part_time.filter(|_| !player.employed) {
+        elements.push(HudElement::plain(format!("PART-TIME {}", job.title()), LIME));
+    }
+    if let Some(venture) = player.active_venture() {
+        elements.push(HudElement::plain(
+            format!("STARTUP ${} ({:+}/day)", venture.balance, venture.last_result),
+            if venture.last_result < 0 { ORANGE } else { LIME },
+        ));
+    }
+    elements
+}
+
+/// X position of each element, placing each one after the previous one's width
+pub fn layout_row(start_x: f32, gap: f32, widths: &[f32]) -> Vec<f32> {
+    let mut x = start_x;
+    widths
+        .iter()
+        .map(|width| {
+            let at = x;
+            x += width + gap;
+            at
+        })
+        .collect()
+}
+
+/// `color`, fading in and out on the clock when `warning` is critical and pulsing is on
+fn warning_color(color: Color, warning: Option<HudWarning>, settings: &HudSettings, clock: &AnimClock) -> Color {
+    match warning {
+        Some(w) if w.is_critical() && settings.warnings.pulse_critical => {
+            Color { a: 0.4 + 0.6 * clock.pulse(WARNING_PULSE_PERIOD), ..color }
+        }
+        _ => color,
+    }
+}
+
+pub fn draw_hud(state: &GameState, settings: &HudSettings, clock: &AnimClock) {
     let y = 25.0;
 
-    draw_text_crisp(&format!("Day {}", state.day), x, y, font_size, WHITE);
-    x += 80.0;
+    let elements = hud_elements(state, settings);
+    let widths: Vec<f32> = elements
+        .iter()
+        .map(|e| measure_text_crisp(&e.text, HUD_FONT_SIZE))
+        .collect();
+    for (element, x) in elements.iter().zip(layout_row(HUD_START_X, HUD_GAP, &widths)) {
+        let color = warning_color(element.color, element.warning, settings, clock);
+        draw_text_crisp(&element.text, x, y, HUD_FONT_SIZE, color);
+    }
 
-    draw_text_crisp(&state.time_string(), x, y, font_size, LIGHTGRAY);
-    x += 70.0;
+    if let Some(skill) = state.hot_skill() {
+        draw_text_crisp(&format!("HOT: {} boom", skill), screen_width() - 240.0, y, HUD_FONT_SIZE, ORANGE);
+    }
 
-    let energy_color = if state.player.energy < 30 { RED } else { GREEN };
-    draw_text_crisp(
-        &format!(
-            "Energy: {}/{}",
-            state.player.energy, state.player.max_energy
-        ),
-        x,
-        y,
-        font_size,
-        energy_color,
-    );
-    x += 140.0;
+    let mut effects_y = y + 22.0;
+    if !state.player.buffs.is_empty() {
+        let buffs: Vec<String> = state.player.buffs.iter().map(|b| b.label()).collect();
+        draw_text_crisp(&buffs.join(" | "), 15.0, effects_y, 14.0, ORANGE);
+        effects_y += 18.0;
+    }
 
-    draw_text_crisp(&format!("${}", state.player.money), x, y, font_size, GOLD);
-    x += 90.0;
+    if let Some(crash) = state.player.next_crash() {
+        draw_text_crisp(&crash.label(), 15.0, effects_y, 14.0, RED);
+        effects_y += 18.0;
+    }
 
-    if state.player.employed {
-        draw_text_crisp(
-            &format!("EMPLOYED ${}/yr", state.player.current_salary),
-            x,
-            y,
-            font_size,
-            LIME,
-        );
+    if let Some(warning) = state.player.burnout.warning() {
+        let burnout = HudWarning::Burnout;
+        let color = warning_color(burnout.color(), Some(burnout), settings, clock);
+        draw_text_crisp(&format!("{} {}", burnout.icon(), warning), 15.0, effects_y, 14.0, color);
     }
 }
 
@@ -47,7 +220,59 @@ pub fn draw_interaction_hint(text: &str) {
 }
 
 pub fn draw_controls_hint() {
-    let text = "WASD: Move | E: Interact | I: Skills | J: Jobs | F: Font | ESC: Menu";
+    let text = "WASD: Move | E: Interact | I: Skills | J: Jobs | L: Legend | F: Font | ESC: Menu";
     let y = screen_height() - 20.0;
     draw_text_crisp(text, 10.0, y, 14.0, GRAY);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_row_spaces_by_measured_width() {
+        let xs = layout_row(15.0, 20.0, &[50.0, 120.0, 30.0]);
+        assert_eq!(xs, vec![15.0, 85.0, 225.0]);
+    }
+
+    #[test]
+    fn test_hidden_elements_are_skipped() {
+        let state = GameState::with_seed("Test", 1);
+        let settings = HudSettings { show_time: false, show_energy: true, show_money: false, ..HudSettings::default() };
+        let texts: Vec<String> = hud_elements(&state, &settings).into_iter().map(|e| e.text).collect();
+        assert_eq!(texts, vec!["Day 1", "Lv 0", "Energy: 100/100"]);
+    }
+
+    #[test]
+    fn test_active_warnings_follow_thresholds() {
+        let config = HudConfig { energy_low: 30, energy_critical: 10, money_low: 100, pulse_critical: true };
+        let warnings = |energy: u32, money: u32, burnout: u32| {
+            let mut player = Player::new("Test");
+            player.energy = energy;
+            player.money = money;
+            player.burnout.intensity = burnout;
+            active_warnings(&player, &config).into_iter().collect::<Vec<_>>()
+        };
+
+        assert_eq!(warnings(100, 500, 0), vec![]);
+        assert_eq!(warnings(30, 100, 0), vec![]);
+        assert_eq!(warnings(29, 99, 0), vec![HudWarning::LowEnergy, HudWarning::LowMoney]);
+        assert_eq!(warnings(9, 0, 0), vec![HudWarning::CriticalEnergy, HudWarning::Broke]);
+        assert_eq!(warnings(10, 1, 2), vec![HudWarning::LowEnergy, HudWarning::LowMoney, HudWarning::Burnout]);
+    }
+
+    #[test]
+    fn test_warned_elements_get_icon_and_color() {
+        let mut state = GameState::with_seed("Test", 1);
+        state.player.energy = 5;
+        state.player.money = 0;
+        let elements = hud_elements(&state, &HudSettings::default());
+
+        let energy = elements.iter().find(|e| e.text.contains("Energy")).unwrap();
+        assert_eq!(energy.text, "!! Energy: 5/100");
+        assert_eq!(energy.warning, Some(HudWarning::CriticalEnergy));
+        assert_eq!(energy.color, RED);
+        let money = elements.iter().find(|e| e.text.contains('$')).unwrap();
+        assert_eq!(money.warning, Some(HudWarning::Broke));
+    }
+}
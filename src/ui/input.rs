@@ -0,0 +1,53 @@
+//! Window input.
+//!
+//! Snapshots macroquad's keyboard and mouse state once per frame, so the
+//! game's update logic only ever sees an `InputSnapshot` and can be driven
+//! by scripted input in tests.
+
+use macroquad::prelude::*;
+
+use crate::testing::{InputSnapshot, InputSource};
+
+/// Name a key the way `InputSnapshot` expects, e.g. "escape" or "key1"
+pub fn key_name(key: KeyCode) -> String {
+    format!("{:?}", key).to_lowercase()
+}
+
+/// Reads the real keyboard and mouse each frame
+#[derive(Debug, Default)]
+pub struct MacroquadInput;
+
+impl InputSource for MacroquadInput {
+    fn snapshot(&mut self) -> InputSnapshot {
+        let (mouse_x, mouse_y) = mouse_position();
+        let mut chars_typed = Vec::new();
+        while let Some(c) = get_char_pressed() {
+            chars_typed.push(c);
+        }
+        InputSnapshot {
+            keys_down: get_keys_down().into_iter().map(key_name).collect(),
+            keys_pressed: get_keys_pressed().into_iter().map(key_name).collect(),
+            chars_typed,
+            mouse_x,
+            mouse_y,
+            mouse_left_down: is_mouse_button_down(MouseButton::Left),
+            mouse_left_pressed: is_mouse_button_pressed(MouseButton::Left),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_names_match_snapshot_lookups() {
+        let input = InputSnapshot::new()
+            .with_key_pressed(&key_name(KeyCode::Escape))
+            .with_key_pressed(&key_name(KeyCode::Key1))
+            .with_key_down(&key_name(KeyCode::W));
+        assert!(input.is_key_pressed("escape"));
+        assert!(input.is_key_pressed("key1"));
+        assert!(input.is_key_down("w"));
+    }
+}
@@ -0,0 +1,90 @@
+//! Map Legend
+//!
+//! Optional overlay (toggled with L) that explains what each building
+//! color means and labels every building currently on screen.
+
+use crate::graphics::{building_color, draw_text_crisp, draw_text_crisp_centered};
+use crate::world::{Building, BuildingType, Camera, TILE_SIZE};
+use macroquad::prelude::*;
+
+/// One row of the legend
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegendEntry {
+    pub building_type: BuildingType,
+    pub label: &'static str,
+}
+
+/// One entry per kind of building on the map, in map order
+pub fn legend_entries(buildings: &[Building]) -> Vec<LegendEntry> {
+    let mut entries: Vec<LegendEntry> = Vec::new();
+    for building in buildings {
+        if !entries.iter().any(|e| e.building_type == building.building_type) {
+            entries.push(LegendEntry {
+                building_type: building.building_type,
+                label: building.building_type.label(),
+            });
+        }
+    }
+    entries
+}
+
+pub fn draw_map_legend(buildings: &[Building]) {
+    let entries = legend_entries(buildings);
+    let row_height = 20.0;
+    let width = 210.0;
+    let height = 35.0 + entries.len() as f32 * row_height;
+    let x = screen_width() - width - 15.0;
+    let y = 50.0;
+
+    draw_rectangle(x, y, width, height, Color::from_rgba(0, 0, 0, 200));
+    draw_rectangle_lines(x, y, width, height, 2.0, WHITE);
+    draw_text_crisp("LEGEND (L)", x + 10.0, y + 20.0, 16.0, GOLD);
+
+    for (i, entry) in entries.iter().enumerate() {
+        let row_y = y + 32.0 + i as f32 * row_height;
+        draw_rectangle(x + 10.0, row_y, 14.0, 14.0, building_color(entry.building_type));
+        draw_text_crisp(entry.label, x + 32.0, row_y + 12.0, 14.0, WHITE);
+    }
+}
+
+/// Name tags above every building that is at least partly in view
+pub fn draw_building_labels(buildings: &[Building], camera: &Camera) {
+    let sw = screen_width();
+    let sh = screen_height();
+    for building in buildings {
+        let (sx, sy) = camera.world_to_screen(building.x as f32 * TILE_SIZE, building.y as f32 * TILE_SIZE);
+        let w = building.width as f32 * TILE_SIZE;
+        let h = building.height as f32 * TILE_SIZE;
+        if sx + w < 0.0 || sx > sw || sy + h < 0.0 || sy > sh {
+            continue;
+        }
+        draw_text_crisp_centered(&building.name, sx + w / 2.0, sy - 6.0, 16.0, YELLOW);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::GameMap;
+
+    #[test]
+    fn test_one_entry_per_building_type() {
+        let buildings = vec![
+            Building::new("A", 0, 0, 3, 2, BuildingType::Apartment),
+            Building::new("B", 5, 0, 3, 2, BuildingType::Company { tier: 1 }),
+            Building::new("C", 10, 0, 3, 2, BuildingType::Company { tier: 1 }),
+            Building::new("D", 15, 0, 3, 2, BuildingType::Company { tier: 2 }),
+        ];
+        let labels: Vec<&str> = legend_entries(&buildings).iter().map(|e| e.label).collect();
+        assert_eq!(labels, vec!["Home (rest)", "Mid-size Company", "Big Tech"]);
+    }
+
+    #[test]
+    fn test_default_map_types_all_in_legend() {
+        let map = GameMap::new();
+        let entries = legend_entries(&map.buildings);
+        for building in &map.buildings {
+            assert!(entries.iter().any(|e| e.building_type == building.building_type));
+        }
+    }
+}
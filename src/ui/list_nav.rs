@@ -0,0 +1,45 @@
+//! List Navigation
+//!
+//! Moves a selection cursor through a list. Every screen with a list goes
+//! through here, so an empty list never leads to `len - 1` underflowing.
+
+/// Keep a selection inside a list of `len` items; 0 when the list is empty
+pub fn clamp_selection(selected: usize, len: usize) -> usize {
+    selected.min(len.saturating_sub(1))
+}
+
+/// Selection after one step of up/down input in a list of `len` items
+pub fn step_selection(selected: usize, len: usize, up: bool, down: bool) -> usize {
+    let mut selected = clamp_selection(selected, len);
+    if up {
+        selected = selected.saturating_sub(1);
+    }
+    if down && selected + 1 < len {
+        selected += 1;
+    }
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_list_stays_at_zero() {
+        for (up, down) in [(false, false), (true, false), (false, true), (true, true)] {
+            assert_eq!(step_selection(0, 0, up, down), 0);
+            assert_eq!(step_selection(5, 0, up, down), 0);
+        }
+        assert_eq!(clamp_selection(3, 0), 0);
+    }
+
+    #[test]
+    fn test_steps_stop_at_the_ends() {
+        assert_eq!(step_selection(0, 3, true, false), 0);
+        assert_eq!(step_selection(0, 3, false, true), 1);
+        assert_eq!(step_selection(2, 3, false, true), 2);
+        // A list that shrank under the cursor
+        assert_eq!(step_selection(9, 3, false, false), 2);
+        assert_eq!(step_selection(9, 3, true, false), 1);
+    }
+}
@@ -1,3 +1,21 @@
+mod chart;
+mod debug;
 mod hud;
+mod input;
+mod legend;
+mod list_nav;
+mod panel;
+mod repeat_key;
+mod typewriter;
+mod wrap;
 
+pub use chart::*;
+pub use debug::*;
 pub use hud::*;
+pub use input::*;
+pub use legend::*;
+pub use list_nav::*;
+pub use panel::*;
+pub use repeat_key::*;
+pub use typewriter::*;
+pub use wrap::*;
@@ -0,0 +1,92 @@
+//! Centered Panel
+//!
+//! The dark, bordered box with a title that every menu screen draws.
+
+use crate::graphics::draw_text_crisp;
+use macroquad::prelude::*;
+
+const BACKGROUND: Color = Color::new(0.0, 0.0, 0.0, 240.0 / 255.0);
+const TITLE_GOLD: Color = Color::new(1.0, 215.0 / 255.0, 0.0, 1.0);
+/// Inset of panel content from the left and right edges
+pub const PANEL_PADDING: f32 = 20.0;
+
+#[derive(Debug, Clone)]
+pub struct Panel {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    title: Option<String>,
+    title_size: f32,
+    title_color: Color,
+}
+
+impl Panel {
+    /// Panel of the given size centered on the window
+    pub fn centered(width: f32, height: f32) -> Self {
+        Self::centered_in(screen_width(), screen_height(), width, height)
+    }
+
+    /// Panel of the given size centered in a `screen_w` x `screen_h` area
+    pub fn centered_in(screen_w: f32, screen_h: f32, width: f32, height: f32) -> Self {
+        Self {
+            x: (screen_w - width) / 2.0,
+            y: (screen_h - height) / 2.0,
+            width,
+            height,
+            title: None,
+            title_size: 24.0,
+            title_color: TITLE_GOLD,
+        }
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn title_size(mut self, size: f32) -> Self {
+        self.title_size = size;
+        self
+    }
+
+    pub fn title_color(mut self, color: Color) -> Self {
+        self.title_color = color;
+        self
+    }
+
+    /// Area inside the padding, below the title line
+    pub fn content_rect(&self) -> Rect {
+        Rect::new(
+            self.x + PANEL_PADDING,
+            self.y + PANEL_PADDING,
+            self.width - 2.0 * PANEL_PADDING,
+            self.height - 2.0 * PANEL_PADDING,
+        )
+    }
+
+    /// Draw the background, border and title, returning the panel for layout
+    pub fn draw(self) -> Self {
+        draw_rectangle(self.x, self.y, self.width, self.height, BACKGROUND);
+        draw_rectangle_lines(self.x, self.y, self.width, self.height, 2.0, WHITE);
+        if let Some(title) = &self.title {
+            draw_text_crisp(title, self.x + PANEL_PADDING, self.y + 30.0, self.title_size, self.title_color);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centered_content_rect() {
+        let panel = Panel::centered_in(800.0, 600.0, 600.0, 500.0);
+        assert_eq!((panel.x, panel.y), (100.0, 50.0));
+
+        let content = panel.content_rect();
+        assert_eq!(content, Rect::new(120.0, 70.0, 560.0, 460.0));
+        assert_eq!(content.center(), Vec2::new(400.0, 300.0));
+    }
+}
@@ -0,0 +1,103 @@
+//! Hold-to-repeat for list navigation.
+//!
+//! Fires once when a key goes down, then again every `REPEAT_INTERVAL`
+//! seconds after it has been held for `REPEAT_DELAY`. Both timings can be
+//! changed per key with `RepeatKey::with_timing`.
+
+/// Seconds a key must be held before it starts repeating
+pub const REPEAT_DELAY: f32 = 0.4;
+/// Seconds between repeats once repeating
+pub const REPEAT_INTERVAL: f32 = 0.08;
+
+#[derive(Debug, Clone)]
+pub struct RepeatKey {
+    /// Seconds held before repeating starts
+    pub delay: f32,
+    /// Seconds between repeats
+    pub interval: f32,
+    down: bool,
+    held: f32,
+    next_repeat: f32,
+}
+
+impl Default for RepeatKey {
+    fn default() -> Self {
+        Self::with_timing(REPEAT_DELAY, REPEAT_INTERVAL)
+    }
+}
+
+impl RepeatKey {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A key with its own repeat delay and interval, in seconds
+    pub fn with_timing(delay: f32, interval: f32) -> Self {
+        Self {
+            delay,
+            interval,
+            down: false,
+            held: 0.0,
+            next_repeat: 0.0,
+        }
+    }
+
+    /// Feed this frame's key state; returns true when navigation should step
+    pub fn update(&mut self, down: bool, dt: f32) -> bool {
+        if !down {
+            self.down = false;
+            return false;
+        }
+        if !self.down {
+            self.down = true;
+            self.held = 0.0;
+            self.next_repeat = self.delay;
+            return true;
+        }
+        self.held += dt;
+        if self.held >= self.next_repeat {
+            self.next_repeat += self.interval;
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fires_on_press_then_repeats_after_delay() {
+        let mut key = RepeatKey::new();
+        let dt = 0.02;
+
+        assert!(key.update(true, dt));
+        // Held but still inside the initial delay
+        let early: usize = (0..15).filter(|_| key.update(true, dt)).count();
+        assert_eq!(early, 0);
+
+        // 0.4s in, then one repeat every 0.08s (4 frames)
+        let fired: usize = (0..20).filter(|_| key.update(true, dt)).count();
+        assert!((4..=6).contains(&fired), "fired {} times", fired);
+    }
+
+    #[test]
+    fn test_custom_timing() {
+        let mut key = RepeatKey::with_timing(0.1, 0.05);
+        assert!(key.update(true, 0.02));
+        let fired: usize = (0..15).filter(|_| key.update(true, 0.02)).count();
+        // Repeats at 0.1, 0.15, 0.2, 0.25, 0.3s
+        assert!((4..=6).contains(&fired), "fired {} times", fired);
+    }
+
+    #[test]
+    fn test_release_resets() {
+        let mut key = RepeatKey::new();
+        assert!(key.update(true, 0.5));
+        assert!(key.update(true, 0.5));
+        assert!(!key.update(false, 0.1));
+        assert!(key.update(true, 0.01));
+        assert!(!key.update(true, 0.01));
+    }
+}
@@ -0,0 +1,107 @@
+//! Typewriter reveal for dialog text.
+//!
+//! Shows a line a few characters at a time. The first confirm press skips
+//! to the full text; only once it's all shown does confirm advance.
+
+/// Default reveal speed for dialog text
+pub const DIALOG_CHARS_PER_SEC: f32 = 45.0;
+
+/// Number of characters visible after `elapsed` seconds, capped at `total`
+pub fn revealed_chars(elapsed: f32, chars_per_sec: f32, total: usize) -> usize {
+    if chars_per_sec <= 0.0 {
+        return total;
+    }
+    ((elapsed.max(0.0) * chars_per_sec) as usize).min(total)
+}
+
+/// Prefix of `text` visible after `elapsed` seconds
+pub fn reveal(text: &str, elapsed: f32, chars_per_sec: f32) -> &str {
+    let shown = revealed_chars(elapsed, chars_per_sec, text.chars().count());
+    match text.char_indices().nth(shown) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+/// Reveal progress of the dialog currently on screen
+#[derive(Debug, Clone)]
+pub struct Typewriter {
+    chars_per_sec: f32,
+    text: String,
+    elapsed: f32,
+    skipped: bool,
+}
+
+impl Typewriter {
+    pub fn new(chars_per_sec: f32) -> Self {
+        Self {
+            chars_per_sec,
+            text: String::new(),
+            elapsed: 0.0,
+            skipped: false,
+        }
+    }
+
+    /// Advance the reveal, restarting it whenever the text changes
+    pub fn update(&mut self, text: &str, dt: f32) {
+        if self.text != text {
+            self.text = text.to_string();
+            self.elapsed = 0.0;
+            self.skipped = false;
+            return;
+        }
+        self.elapsed += dt;
+    }
+
+    /// Show the rest of the text at once
+    pub fn skip(&mut self) {
+        self.skipped = true;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.skipped
+            || revealed_chars(self.elapsed, self.chars_per_sec, self.text.chars().count())
+                == self.text.chars().count()
+    }
+
+    /// The part of the current text to draw
+    pub fn visible(&self) -> &str {
+        if self.skipped {
+            &self.text
+        } else {
+            reveal(&self.text, self.elapsed, self.chars_per_sec)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reveal_length_follows_elapsed_time() {
+        let text = "Welcome home! Would you like to rest?";
+        assert_eq!(reveal(text, 0.0, 10.0), "");
+        assert_eq!(reveal(text, 0.5, 10.0), "Welco");
+        assert_eq!(reveal(text, 1.25, 10.0).len(), 12);
+        assert_eq!(reveal(text, 60.0, 10.0), text);
+        assert_eq!(reveal("café au lait", 0.4, 10.0), "café");
+    }
+
+    #[test]
+    fn test_skip_then_new_text_restarts() {
+        let mut tw = Typewriter::new(10.0);
+        tw.update("Hello there", 0.0);
+        tw.update("Hello there", 0.2);
+        assert_eq!(tw.visible(), "He");
+        assert!(!tw.is_done());
+
+        tw.skip();
+        assert!(tw.is_done());
+        assert_eq!(tw.visible(), "Hello there");
+
+        tw.update("Next line", 0.016);
+        assert_eq!(tw.visible(), "");
+        assert!(!tw.is_done());
+    }
+}
@@ -0,0 +1,101 @@
+//! Text Wrapping
+//!
+//! Breaks text into lines that fit a pixel width. The measuring function
+//! is passed in, so line breaking can be tested without a window.
+
+use crate::graphics::measure_text_crisp;
+
+/// Split text into lines no wider than `max_width` as measured by `measure`
+///
+/// Breaks at whitespace and keeps explicit newlines. A word too wide for
+/// a line of its own is split between characters, so long URLs and text
+/// without spaces still fit.
+pub fn wrap_text(text: &str, max_width: f32, measure: impl Fn(&str) -> f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+            if measure(&candidate) <= max_width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            if measure(word) <= max_width {
+                line = word.to_string();
+                continue;
+            }
+            for ch in word.chars() {
+                line.push(ch);
+                if measure(&line) > max_width && line.chars().count() > 1 {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, ch.to_string()));
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// `wrap_text` measured the way `draw_text_crisp` draws
+pub fn wrap_text_crisp(text: &str, font_size: f32, max_width: f32) -> Vec<String> {
+    wrap_text(text, max_width, |line| measure_text_crisp(line, font_size))
+}
+
+/// The parts of wrapped lines covered by the first `visible_chars` characters
+///
+/// Each line break counts as one character, standing in for the space or
+/// newline the break replaced, so a typewriter reveal lines up with the
+/// original text.
+pub fn visible_lines(lines: &[String], visible_chars: usize) -> Vec<&str> {
+    let mut remaining = visible_chars;
+    let mut shown = Vec::new();
+    for line in lines {
+        let len = line.chars().count();
+        let end = line.char_indices().nth(remaining).map_or(line.len(), |(i, _)| i);
+        shown.push(&line[..end]);
+        if remaining <= len {
+            break;
+        }
+        remaining -= len + 1;
+    }
+    shown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every character 10px wide
+    fn measure(text: &str) -> f32 {
+        text.chars().count() as f32 * 10.0
+    }
+
+    #[test]
+    fn test_long_text_wraps_at_word_boundaries() {
+        let text = "Transformers changed everything. Study attention first, then fine-tuning, and you'll ace the interview.";
+        let lines = wrap_text(text, 200.0, measure);
+
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| measure(line) <= 200.0));
+        assert_eq!(lines.join(" "), text);
+        assert_eq!(lines[0], "Transformers changed");
+    }
+
+    #[test]
+    fn test_newlines_and_overlong_words() {
+        let lines = wrap_text("Hi!\nこんにちは世界、ようこそ", 50.0, measure);
+        assert_eq!(lines, vec!["Hi!", "こんにちは", "世界、よう", "こそ"]);
+    }
+
+    #[test]
+    fn test_visible_lines_follow_the_reveal() {
+        let lines = vec!["Hello there".to_string(), "friend".to_string()];
+        assert_eq!(visible_lines(&lines, 5), vec!["Hello"]);
+        assert_eq!(visible_lines(&lines, 14), vec!["Hello there", "fr"]);
+        assert_eq!(visible_lines(&lines, 100), vec!["Hello there", "friend"]);
+    }
+}
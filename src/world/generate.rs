@@ -0,0 +1,223 @@
+//! Map Randomizer
+//!
+//! Builds a city layout from a seed for replayability. The same buildings
+//! as the hand-authored map are placed at varied spots around a random
+//! grid of roads, and every placement is checked with the pathfinder so
+//! each door stays reachable from the spawn. Seed 0 is the hand-authored
+//! map.
+
+use anyhow::{anyhow, Result};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::map::{Building, BuildingType, GameMap, Tile, MAP_HEIGHT, MAP_WIDTH, SPAWN_TILE};
+use super::npc::{get_npcs, Npc, NpcType};
+use super::pathfind::find_path;
+use super::TILE_SIZE;
+use crate::game::GameRng;
+
+/// Map seed that keeps the hand-authored layout
+pub const HAND_AUTHORED_SEED: u64 = 0;
+/// Random spots tried for each building before the seed is given up on
+const PLACEMENT_ATTEMPTS: usize = 400;
+/// Furthest the apartment's door may be from the spawn, in tiles
+const HOME_RADIUS: i32 = 10;
+/// Furthest an NPC stands from the building it hangs around, in tiles
+const NPC_RADIUS: i32 = 4;
+
+/// Every tile of a 2-tile-wide road along row `y`
+fn lay_avenue(tiles: &mut [[Tile; MAP_HEIGHT]; MAP_WIDTH], y: usize) {
+    for column in tiles.iter_mut().take(MAP_WIDTH - 2).skip(2) {
+        column[y] = Tile::Path;
+        column[y + 1] = Tile::Path;
+    }
+}
+
+impl GameMap {
+    /// Map for a seed: the hand-authored one for `HAND_AUTHORED_SEED`,
+    /// otherwise a randomized layout
+    ///
+    /// # Errors
+    /// Returns an error if the hand-authored map fails to load, or if the
+    /// seed's roads leave no room for every building. Callers fall back to
+    /// `GameMap::new` either way.
+    pub fn generate(seed: u64) -> Result<Self> {
+        if seed == HAND_AUTHORED_SEED {
+            return Self::load();
+        }
+        Self::randomized(seed).ok_or_else(|| anyhow!("Map seed {} left no room for every building", seed))
+    }
+
+    fn randomized(seed: u64) -> Option<Self> {
+        let mut rng = GameRng::new(seed);
+        let mut tiles = [[Tile::Grass; MAP_HEIGHT]; MAP_WIDTH];
+
+        // Three avenues, the bottom one through the spawn, joined by streets
+        let top = rng.gen_range(7..=10);
+        let middle = rng.gen_range(top + 6..=top + 8);
+        let bottom = SPAWN_TILE.1 as usize - 1;
+        for y in [top, middle, bottom] {
+            lay_avenue(&mut tiles, y);
+        }
+        let mut streets: Vec<usize> = (4..MAP_WIDTH - 4).step_by(2).collect();
+        streets.shuffle(&mut rng);
+        let mut picked: Vec<usize> = Vec::new();
+        for x in streets {
+            if picked.len() < 3 && picked.iter().all(|&p| p.abs_diff(x) >= 6) {
+                picked.push(x);
+            }
+        }
+        for x in picked {
+            for column in tiles[x].iter_mut().take(bottom).skip(top) {
+                *column = Tile::Path;
+            }
+        }
+
+        let mut map = Self { tiles, buildings: Vec::new() };
+        // Biggest first, so they get the pick of the open ground
+        let mut catalog = Self::new().buildings;
+        catalog.sort_by_key(|b| std::cmp::Reverse(b.width * b.height));
+        catalog.sort_by_key(|b| b.building_type != BuildingType::Apartment);
+        for building in catalog {
+            let placed = map.place(&building, &mut rng)?;
+            map.lay_spur(&placed);
+            map.buildings.push(placed);
+        }
+        Some(map)
+    }
+
+    /// Try random spots for a building until one is clear and reachable
+    fn place(&self, building: &Building, rng: &mut GameRng) -> Option<Building> {
+        let (width, height) = (building.width as i32, building.height as i32);
+        (0..PLACEMENT_ATTEMPTS).find_map(|_| {
+            let x = rng.gen_range(1..=MAP_WIDTH as i32 - 1 - width);
+            let y = rng.gen_range(1..=MAP_HEIGHT as i32 - 1 - height);
+            let candidate = Building::new(&building.name, x, y, building.width, building.height, building.building_type);
+            let (dx, dy) = (candidate.door.0 - SPAWN_TILE.0, candidate.door.1 - SPAWN_TILE.1);
+            if building.building_type == BuildingType::Apartment && dx.abs() + dy.abs() > HOME_RADIUS {
+                return None;
+            }
+            self.is_clear(&candidate).then_some(candidate).filter(|c| self.keeps_doors_reachable(c))
+        })
+    }
+
+    /// No road, other building or the spawn within a tile of the footprint
+    fn is_clear(&self, candidate: &Building) -> bool {
+        let spawn_area = |x: i32, y: i32| (x - SPAWN_TILE.0).abs() <= 1 && (y - SPAWN_TILE.1).abs() <= 1;
+        for x in candidate.x - 1..=candidate.x + candidate.width as i32 {
+            for y in candidate.y - 1..=candidate.y + candidate.height as i32 {
+                if self.tiles[x as usize][y as usize] == Tile::Path
+                    || spawn_area(x, y)
+                    || self.buildings.iter().any(|b| b.contains_tile(x, y))
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Whether the spawn still reaches every door with the candidate placed
+    fn keeps_doors_reachable(&self, candidate: &Building) -> bool {
+        let mut with = Self { tiles: self.tiles, buildings: self.buildings.clone() };
+        with.buildings.push(candidate.clone());
+        with.buildings.iter().all(|b| find_path(&with, SPAWN_TILE, b.door).is_some())
+    }
+
+    /// Path from below a building's door down to the next road, if nothing
+    /// is in the way
+    fn lay_spur(&mut self, building: &Building) {
+        let x = building.door.0;
+        let spur: Vec<i32> = (building.door.1 + 1..MAP_HEIGHT as i32)
+            .take_while(|&y| self.tiles[x as usize][y as usize] != Tile::Path)
+            .collect();
+        let reaches_road = spur.last().is_none_or(|&y| y + 1 < MAP_HEIGHT as i32);
+        if reaches_road && !spur.iter().any(|&y| self.buildings.iter().any(|b| b.contains_tile(x, y))) {
+            for y in spur {
+                self.tiles[x as usize][y as usize] = Tile::Path;
+            }
+        }
+    }
+
+    /// First building of a type
+    fn building_of(&self, building_type: BuildingType) -> Option<&Building> {
+        self.buildings.iter().find(|b| b.building_type == building_type)
+    }
+}
+
+/// Building an NPC hangs around on a randomized map
+fn haunt(npc_type: &NpcType) -> BuildingType {
+    match npc_type {
+        NpcType::Recruiter => BuildingType::Company { tier: 1 },
+        NpcType::Engineer => BuildingType::Company { tier: 2 },
+        NpcType::Student => BuildingType::Library,
+        NpcType::Professor => BuildingType::University,
+        NpcType::Barista => BuildingType::CoffeeShop,
+    }
+}
+
+/// NPCs for a map seed: the usual spots on the hand-authored map,
+/// otherwise on a road near the building each one belongs to
+pub fn npcs_for_map(map: &GameMap, seed: u64) -> Vec<Npc> {
+    let npcs = get_npcs();
+    if seed == HAND_AUTHORED_SEED {
+        return npcs;
+    }
+    let mut rng = GameRng::new(seed.rotate_left(32));
+    npcs.into_iter()
+        .map(|npc| {
+            let Some(building) = map.building_of(haunt(&npc.npc_type)) else { return npc };
+            let (door_x, door_y) = building.door;
+            let spots: Vec<(i32, i32)> = (door_x - NPC_RADIUS..=door_x + NPC_RADIUS)
+                .flat_map(|x| (door_y + 1..=door_y + NPC_RADIUS).map(move |y| (x, y)))
+                .filter(|&(x, y)| map.is_walkable(x, y) && map.tiles[x as usize][y as usize] == Tile::Path)
+                .collect();
+            let (x, y) = spots.choose(&mut rng).copied().unwrap_or((door_x, door_y + 1));
+            Npc {
+                x: x as f32 * TILE_SIZE,
+                y: y as f32 * TILE_SIZE,
+                ..npc
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_maps_have_every_building_reachable_from_spawn() {
+        let hand_authored = GameMap::generate(HAND_AUTHORED_SEED).unwrap();
+        for seed in 1..=20 {
+            let map = GameMap::generate(seed).unwrap();
+
+            assert_eq!(map.buildings.len(), hand_authored.buildings.len(), "seed {}", seed);
+            for building in &hand_authored.buildings {
+                assert!(map.building_of(building.building_type).is_some(), "seed {} lacks {:?}", seed, building.building_type);
+            }
+            for building in &map.buildings {
+                assert!(find_path(&map, SPAWN_TILE, building.door).is_some(), "seed {}: {} unreachable", seed, building.name);
+            }
+            assert!(
+                map.buildings.iter().any(|b| hand_authored.buildings.iter().all(|h| (h.x, h.y) != (b.x, b.y))),
+                "seed {} kept the hand-authored layout",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_map_and_npcs() {
+        let (a, b) = (GameMap::generate(7).unwrap(), GameMap::generate(7).unwrap());
+        assert_eq!(a.tiles, b.tiles);
+        assert!(a.buildings.iter().zip(&b.buildings).all(|(a, b)| (a.x, a.y, a.door) == (b.x, b.y, b.door)));
+
+        let positions = |map: &GameMap| npcs_for_map(map, 7).iter().map(|n| (n.x, n.y)).collect::<Vec<_>>();
+        assert_eq!(positions(&a), positions(&b));
+        for npc in npcs_for_map(&a, 7) {
+            let tile = ((npc.x / TILE_SIZE) as i32, (npc.y / TILE_SIZE) as i32);
+            assert!(a.is_walkable(tile.0, tile.1), "{} stands inside a building", npc.name);
+        }
+    }
+}
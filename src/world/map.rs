@@ -4,6 +4,8 @@ use super::TILE_SIZE;
 
 pub const MAP_WIDTH: usize = 40;
 pub const MAP_HEIGHT: usize = 30;
+/// Tile the player starts the game on
+pub const SPAWN_TILE: (i32, i32) = (5, MAP_HEIGHT as i32 - 5);
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Tile {
@@ -22,24 +24,100 @@ pub struct Building {
     pub width: u32,
     pub height: u32,
     pub building_type: BuildingType,
+    /// Passable entry tile; the player must be on or next to it to enter
+    pub door: (i32, i32),
+}
+
+impl Building {
+    /// Create a building with its door at the bottom-center tile
+    pub fn new(
+        name: &str,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        building_type: BuildingType,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            x,
+            y,
+            width,
+            height,
+            building_type,
+            door: Self::default_door(x, y, width, height),
+        }
+    }
+
+    /// Bottom-center tile, matching where `draw_building` draws the door
+    pub fn default_door(x: i32, y: i32, width: u32, height: u32) -> (i32, i32) {
+        (x + width as i32 / 2, y + height as i32 - 1)
+    }
+
+    /// Whether a tile lies within this building's footprint
+    pub fn contains_tile(&self, tx: i32, ty: i32) -> bool {
+        tx >= self.x
+            && tx < self.x + self.width as i32
+            && ty >= self.y
+            && ty < self.y + self.height as i32
+    }
+
+    /// Whether a tile is the door or one of its neighbors
+    pub fn is_at_entrance(&self, tx: i32, ty: i32) -> bool {
+        (tx - self.door.0).abs() <= 1 && (ty - self.door.1).abs() <= 1
+    }
+
+    /// Whether a world position is within `radius` pixels of the door's center
+    pub fn is_within(&self, x: f32, y: f32, radius: f32) -> bool {
+        let door_x = (self.door.0 as f32 + 0.5) * TILE_SIZE;
+        let door_y = (self.door.1 as f32 + 0.5) * TILE_SIZE;
+        (x - door_x).hypot(y - door_y) <= radius
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BuildingType {
     Apartment,
     Library,
+    University,
     CoffeeShop,
     Company { tier: u8 },
     JobCenter,
     Park,
 }
 
+impl BuildingType {
+    /// What this kind of building is, as shown in the map legend
+    pub fn label(&self) -> &'static str {
+        match self {
+            BuildingType::Apartment => "Home (rest)",
+            BuildingType::Library => "Library (study)",
+            BuildingType::University => "University (paid study)",
+            BuildingType::CoffeeShop => "Coffee Shop (energy)",
+            BuildingType::Company { tier: 0 } => "Startup",
+            BuildingType::Company { tier: 1 } => "Mid-size Company",
+            BuildingType::Company { tier: 2 } => "Big Tech",
+            BuildingType::Company { .. } => "FAANG",
+            BuildingType::JobCenter => "Job Center",
+            BuildingType::Park => "Park",
+        }
+    }
+}
+
 pub struct GameMap {
     pub tiles: [[Tile; MAP_HEIGHT]; MAP_WIDTH],
     pub buildings: Vec<Building>,
 }
 
+impl Default for GameMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GameMap {
+    /// Hand-built default map, used when config/map.toml can't be loaded
+    #[allow(clippy::needless_range_loop)]
     pub fn new() -> Self {
         let mut tiles = [[Tile::Grass; MAP_HEIGHT]; MAP_WIDTH];
         
@@ -83,66 +161,74 @@ impl GameMap {
 
         let buildings = vec![
             // === RESIDENTIAL (bottom) ===
-            Building {
-                name: "Your Apartment".to_string(),
-                x: 3,
-                y: MAP_HEIGHT as i32 - 10,
-                width: 3,
-                height: 3,
-                building_type: BuildingType::Apartment,
-            },
+            Building::new(
+                "Your Apartment",
+                3,
+                MAP_HEIGHT as i32 - 10,
+                3,
+                3,
+                BuildingType::Apartment,
+            ),
             
             // === DOWNTOWN (center) ===
-            Building {
-                name: "Library".to_string(),
-                x: MAP_WIDTH as i32 / 2 - 2,
-                y: MAP_HEIGHT as i32 / 2 - 4,
-                width: 4,
-                height: 3,
-                building_type: BuildingType::Library,
-            },
-            Building {
-                name: "Coffee Shop".to_string(),
-                x: MAP_WIDTH as i32 / 2 + 5,
-                y: MAP_HEIGHT as i32 / 2 - 3,
-                width: 3,
-                height: 2,
-                building_type: BuildingType::CoffeeShop,
-            },
+            Building::new(
+                "Library",
+                MAP_WIDTH as i32 / 2 - 2,
+                MAP_HEIGHT as i32 / 2 - 4,
+                4,
+                3,
+                BuildingType::Library,
+            ),
+            Building::new(
+                "University",
+                11,
+                MAP_HEIGHT as i32 / 2 - 4,
+                4,
+                3,
+                BuildingType::University,
+            ),
+            Building::new(
+                "Coffee Shop",
+                MAP_WIDTH as i32 / 2 + 5,
+                MAP_HEIGHT as i32 / 2 - 3,
+                3,
+                2,
+                BuildingType::CoffeeShop,
+            ),
             
             // === TECH DISTRICT (top) ===
-            Building {
-                name: "DataStartup AI".to_string(),
-                x: 5,
-                y: 3,
-                width: 4,
-                height: 4,
-                building_type: BuildingType::Company { tier: 0 },
-            },
-            Building {
-                name: "TechCorp Inc".to_string(),
-                x: 12,
-                y: 3,
-                width: 5,
-                height: 4,
-                building_type: BuildingType::Company { tier: 1 },
-            },
-            Building {
-                name: "MegaTech".to_string(),
-                x: 20,
-                y: 2,
-                width: 6,
-                height: 5,
-                building_type: BuildingType::Company { tier: 2 },
-            },
-            Building {
-                name: "SearchGiant".to_string(),
-                x: 28,
-                y: 2,
-                width: 7,
-                height: 5,
-                building_type: BuildingType::Company { tier: 3 },
-            },
+            Building::new(
+                "DataStartup AI",
+                5,
+                3,
+                4,
+                4,
+                BuildingType::Company { tier: 0 },
+            ),
+            Building::new(
+                "TechCorp Inc",
+                12,
+                3,
+                5,
+                4,
+                BuildingType::Company { tier: 1 },
+            ),
+            Building::new(
+                "MegaTech",
+                20,
+                2,
+                6,
+                5,
+                BuildingType::Company { tier: 2 },
+            ),
+            Building::new(
+                "SearchGiant",
+                28,
+                2,
+                7,
+                5,
+                BuildingType::Company { tier: 3 },
+            ),
         ];
 
         Self { tiles, buildings }
@@ -179,9 +265,10 @@ impl GameMap {
             match building.building_type {
                 BuildingType::Apartment => draw_apartment(screen_x, screen_y),
                 BuildingType::Library => draw_library(screen_x, screen_y),
+                BuildingType::University => draw_building(screen_x, screen_y, building.width, building.height, &building.name, building_color(BuildingType::University)),
                 BuildingType::CoffeeShop => draw_coffee_shop(screen_x, screen_y),
                 BuildingType::Company { tier } => draw_company(screen_x, screen_y, &building.name, tier),
-                BuildingType::JobCenter => draw_building(screen_x, screen_y, building.width, building.height, &building.name, Color::from_rgba(150, 150, 200, 255)),
+                BuildingType::JobCenter => draw_building(screen_x, screen_y, building.width, building.height, &building.name, building_color(BuildingType::JobCenter)),
                 BuildingType::Park => draw_park(screen_x, screen_y, building.width, building.height),
             }
         }
@@ -199,10 +286,7 @@ impl GameMap {
                     return true;
                 }
                 for building in &self.buildings {
-                    let b_right = building.x + building.width as i32;
-                    let b_bottom = building.y + building.height as i32;
-                    
-                    if bx >= building.x && bx < b_right && by >= building.y && by < b_bottom {
+                    if building.contains_tile(bx, by) && (bx, by) != building.door {
                         return true;
                     }
                 }
@@ -212,9 +296,6 @@ impl GameMap {
     }
 
     pub fn get_building_near(&self, x: f32, y: f32, radius: f32) -> Option<&Building> {
-        let player_tile_x = (x / TILE_SIZE) as i32;
-        let player_tile_y = (y / TILE_SIZE) as i32;
-        
         let mut closest: Option<(&Building, f32)> = None;
         
         for building in &self.buildings {
@@ -239,7 +320,60 @@ impl GameMap {
         closest.map(|(b, _)| b)
     }
 
+    /// Building whose door the player is standing on or next to
     pub fn get_building_at(&self, x: f32, y: f32) -> Option<&Building> {
-        self.get_building_near(x, y, 80.0)
+        let tx = (x / TILE_SIZE).floor() as i32;
+        let ty = (y / TILE_SIZE).floor() as i32;
+        self.buildings.iter().find(|b| b.is_at_entrance(tx, ty))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile_center(tx: i32, ty: i32) -> (f32, f32) {
+        ((tx as f32 + 0.5) * TILE_SIZE, (ty as f32 + 0.5) * TILE_SIZE)
+    }
+
+    fn test_map() -> GameMap {
+        GameMap {
+            tiles: [[Tile::Grass; MAP_HEIGHT]; MAP_WIDTH],
+            buildings: vec![Building::new("Shop", 4, 4, 3, 2, BuildingType::CoffeeShop)],
+        }
+    }
+
+    #[test]
+    fn test_default_door_is_bottom_center() {
+        let building = Building::new("Shop", 4, 4, 3, 2, BuildingType::CoffeeShop);
+        assert_eq!(building.door, (5, 5));
+    }
+
+    #[test]
+    fn test_door_tile_is_walkable() {
+        let map = test_map();
+        let (x, y) = tile_center(5, 5);
+        assert!(!map.collides(x, y, 4.0, 4.0));
+    }
+
+    #[test]
+    fn test_rest_of_footprint_collides() {
+        let map = test_map();
+        for (tx, ty) in [(4, 4), (5, 4), (6, 4), (4, 5), (6, 5)] {
+            let (x, y) = tile_center(tx, ty);
+            assert!(map.collides(x, y, 4.0, 4.0), "tile ({}, {}) should be solid", tx, ty);
+        }
+    }
+
+    #[test]
+    fn test_entry_requires_door_proximity() {
+        let map = test_map();
+
+        let (x, y) = tile_center(5, 6);
+        assert_eq!(map.get_building_at(x, y).map(|b| b.name.as_str()), Some("Shop"));
+
+        // Beside the building but away from the door
+        let (x, y) = tile_center(2, 4);
+        assert!(map.get_building_at(x, y).is_none());
     }
 }
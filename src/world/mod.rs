@@ -1,11 +1,20 @@
 mod player;
 mod camera;
+mod generate;
 mod map;
+mod pathfind;
+mod tick;
+mod tilemap;
 pub mod npc;
 
 pub use player::{Direction, WorldPlayer};
 pub use camera::Camera;
-pub use map::{GameMap, Building, BuildingType, Tile, MAP_WIDTH, MAP_HEIGHT};
+pub use generate::{npcs_for_map, HAND_AUTHORED_SEED};
+pub use map::{GameMap, Building, BuildingType, Tile, MAP_WIDTH, MAP_HEIGHT, SPAWN_TILE};
+pub use pathfind::find_path;
 pub use npc::{Npc, NpcType, get_npcs};
+pub use tick::{FixedTimestep, MAX_FRAME_SECS, WORLD_TICK_HZ};
 
 pub const TILE_SIZE: f32 = 32.0;
+/// Hours spent getting into a building
+pub const COMMUTE_HOURS: f32 = 0.25;
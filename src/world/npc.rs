@@ -1,5 +1,14 @@
+use std::collections::HashMap;
+
 use macroquad::prelude::*;
+use ::rand::seq::SliceRandom;
+use ::rand::Rng;
 use crate::graphics::draw_npc;
+use crate::player::PlayerSkill;
+use crate::skills::Proficiency;
+
+/// Dialog line the Engineer and Professor replace with advice for the player's level
+const ADVICE_LINE: usize = 1;
 
 #[derive(Debug, Clone)]
 pub enum NpcType {
@@ -20,6 +29,26 @@ impl NpcType {
             NpcType::Barista => "Barista",
         }
     }
+
+    /// Key of the NPC's `[npc.classes]` section in the config
+    pub fn class_name(&self) -> &'static str {
+        match self {
+            NpcType::Recruiter => "recruiter",
+            NpcType::Engineer => "engineer",
+            NpcType::Student => "student",
+            NpcType::Professor => "professor",
+            NpcType::Barista => "barista",
+        }
+    }
+
+    /// Hours (from, until) the NPC is out in the world
+    pub fn availability(&self) -> (f32, f32) {
+        match self {
+            NpcType::Professor => (9.0, 17.0),
+            NpcType::Barista => (7.0, 19.0),
+            _ => (0.0, 24.0),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -28,8 +57,15 @@ pub struct Npc {
     pub y: f32,
     pub npc_type: NpcType,
     pub name: String,
+    /// Exchange for the current conversation, spoken line by line
     pub dialog: Vec<String>,
     pub current_dialog: usize,
+    /// Every exchange the NPC can open with, the built-in one first
+    pub variants: Vec<Vec<String>>,
+    /// Hour the NPC shows up
+    pub available_from: f32,
+    /// Hour the NPC leaves
+    pub available_until: f32,
 }
 
 impl Npc {
@@ -64,7 +100,7 @@ impl Npc {
                 vec![
                     "Welcome! I teach the advanced ML course.".to_string(),
                     "If you want to master LLMs, you need strong foundations.".to_string(),
-                    "Come back when you've studied the basics.".to_string(),
+                    "My office hours are 9 to 5 if you have questions.".to_string(),
                 ]
             ),
             NpcType::Barista => (
@@ -77,16 +113,33 @@ impl Npc {
             ),
         };
 
+        let (available_from, available_until) = npc_type.availability();
         Self {
             x,
             y,
             npc_type,
             name,
+            variants: vec![dialog.clone()],
             dialog,
             current_dialog: 0,
+            available_from,
+            available_until,
         }
     }
 
+    /// Whether the NPC is around at the given hour
+    pub fn is_available(&self, time_of_day: f32) -> bool {
+        time_of_day >= self.available_from && time_of_day < self.available_until
+    }
+
+    /// Shown when the player looks for the NPC outside their hours
+    pub fn absence_note(&self) -> String {
+        format!(
+            "{} isn't here right now. Usually around {:02}:00-{:02}:00.",
+            self.name, self.available_from as u32, self.available_until as u32
+        )
+    }
+
     pub fn npc_type_id(&self) -> u8 {
         match self.npc_type {
             NpcType::Recruiter => 0,
@@ -107,11 +160,49 @@ impl Npc {
         (dx * dx + dy * dy).sqrt()
     }
 
-    pub fn get_dialog(&self) -> (&str, &str) {
+    /// Speaker and current line; mentors tailor their advice to `skills`
+    pub fn get_dialog(&self, skills: &HashMap<String, PlayerSkill>) -> (&str, &str) {
+        if self.current_dialog == ADVICE_LINE {
+            if let Some(advice) = self.mentor_advice(skills) {
+                return (self.name.as_str(), advice);
+            }
+        }
         let text = self.dialog.get(self.current_dialog).unwrap_or(&self.dialog[0]);
         (self.name.as_str(), text.as_str())
     }
 
+    /// Advice pitched at the player's level, from the Engineer and Professor only
+    ///
+    /// The Engineer looks at Python and deep learning, the Professor at
+    /// statistics and the LLM stack.
+    pub fn mentor_advice(&self, skills: &HashMap<String, PlayerSkill>) -> Option<&'static str> {
+        let level = |name: &str| skills.get(name).map_or(Proficiency::None, |s| s.proficiency);
+        match self.npc_type {
+            NpcType::Engineer => {
+                let deep_learning = level("PyTorch").max(level("TensorFlow"));
+                Some(if level("Python") == Proficiency::None {
+                    "My advice? Focus on Python fundamentals first - everything else builds on them."
+                } else if deep_learning < Proficiency::Intermediate {
+                    "Your Python is coming along. Pick up PyTorch next and train a few models yourself."
+                } else if level("Python") >= Proficiency::Advanced && deep_learning >= Proficiency::Advanced {
+                    "You're ready for system design: serving, scaling and monitoring models in production."
+                } else {
+                    "Solid basics. Build something end to end, then dig into Transformers."
+                })
+            }
+            NpcType::Professor => Some(if level("Statistics") == Proficiency::None {
+                "If you want to master LLMs, you need strong foundations. Start with statistics."
+            } else if level("Transformers") < Proficiency::Intermediate {
+                "Your foundations are there. Study Transformers next - attention is the key idea."
+            } else if level("LLM Fine-tuning") < Proficiency::Advanced {
+                "You understand Transformers. Fine-tuning LLMs is the natural next step."
+            } else {
+                "There's little left I can teach you about LLMs. Go build something ambitious."
+            }),
+            _ => None,
+        }
+    }
+
     pub fn advance_dialog(&mut self) -> bool {
         self.current_dialog += 1;
         self.current_dialog < self.dialog.len()
@@ -120,6 +211,20 @@ impl Npc {
     pub fn reset_dialog(&mut self) {
         self.current_dialog = 0;
     }
+
+    /// Add exchanges from the config roster; empty ones are skipped
+    pub fn with_dialog_variants(mut self, variants: &[Vec<String>]) -> Self {
+        self.variants.extend(variants.iter().filter(|v| !v.is_empty()).cloned());
+        self
+    }
+
+    /// Open a conversation with one of the exchanges, picked by `rng`
+    pub fn start_conversation(&mut self, rng: &mut impl Rng) {
+        if let Some(variant) = self.variants.choose(rng) {
+            self.dialog = variant.clone();
+        }
+        self.reset_dialog();
+    }
 }
 
 pub fn get_npcs() -> Vec<Npc> {
@@ -131,3 +236,66 @@ pub fn get_npcs() -> Vec<Npc> {
         Npc::new(22.0 * 32.0, 14.0 * 32.0, NpcType::Barista),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameRng;
+    use crate::player::Player;
+
+    #[test]
+    fn test_professor_keeps_daytime_hours() {
+        let professor = Npc::new(0.0, 0.0, NpcType::Professor);
+        assert!(!professor.is_available(2.0));
+        assert!(professor.is_available(14.0));
+        assert!(professor.absence_note().contains("09:00-17:00"));
+
+        let recruiter = Npc::new(0.0, 0.0, NpcType::Recruiter);
+        assert!(recruiter.is_available(2.0));
+    }
+
+    #[test]
+    fn test_engineer_advice_follows_python_level() {
+        let mut engineer = Npc::new(0.0, 0.0, NpcType::Engineer);
+        engineer.current_dialog = ADVICE_LINE;
+        let beginner = Player::builder("New").build();
+        let expert = Player::builder("Pro")
+            .skill("Python", Proficiency::Expert)
+            .skill("PyTorch", Proficiency::Expert)
+            .build();
+
+        let (_, beginner_line) = engineer.get_dialog(&beginner.skills);
+        let (_, expert_line) = engineer.get_dialog(&expert.skills);
+
+        assert_ne!(beginner_line, expert_line);
+        assert!(beginner_line.contains("Python fundamentals"));
+        assert!(expert_line.contains("system design"));
+
+        // Other lines and other NPCs stay as written
+        engineer.current_dialog = 0;
+        assert_eq!(engineer.get_dialog(&expert.skills).1, engineer.dialog[0]);
+        assert!(Npc::new(0.0, 0.0, NpcType::Barista).mentor_advice(&expert.skills).is_none());
+    }
+
+    #[test]
+    fn test_greeting_variant_follows_the_seed() {
+        let skills = HashMap::new();
+        let variant = vec!["Morning! The usual?".to_string(), "Coffee helps.".to_string()];
+        let mut barista = Npc::new(0.0, 0.0, NpcType::Barista).with_dialog_variants(&[variant]);
+        let mut greeting = |seed: u64| {
+            barista.start_conversation(&mut GameRng::for_conversation(seed, 4, 0));
+            barista.get_dialog(&skills).1.to_string()
+        };
+
+        let first = greeting(1);
+        assert_eq!(greeting(1), first);
+        let other = (2..50).map(&mut greeting).find(|g| *g != first);
+        assert!(other.is_some(), "no seed picked the other greeting");
+
+        // The chosen exchange still advances line by line
+        barista.start_conversation(&mut GameRng::for_conversation(1, 4, 0));
+        let exchange = barista.dialog.clone();
+        assert!(barista.advance_dialog());
+        assert_eq!(barista.get_dialog(&skills).1, exchange[1]);
+    }
+}
@@ -0,0 +1,79 @@
+//! Tile Pathfinding
+//!
+//! Breadth-first search over the tiles a player can walk on. Paths move
+//! in the four compass directions, matching how the player walks, and are
+//! the shortest such route between two tiles.
+
+use std::collections::{HashMap, VecDeque};
+
+use super::map::{GameMap, MAP_HEIGHT, MAP_WIDTH};
+
+const STEPS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+impl GameMap {
+    /// Whether the player can stand on a tile: on the map and not inside a
+    /// building, except on its door
+    pub fn is_walkable(&self, tx: i32, ty: i32) -> bool {
+        tx >= 0
+            && ty >= 0
+            && tx < MAP_WIDTH as i32
+            && ty < MAP_HEIGHT as i32
+            && !self.buildings.iter().any(|b| b.contains_tile(tx, ty) && (tx, ty) != b.door)
+    }
+}
+
+/// Shortest walkable route between two tiles, both ends included
+///
+/// Returns `None` if either end is blocked or no route connects them.
+pub fn find_path(map: &GameMap, from: (i32, i32), to: (i32, i32)) -> Option<Vec<(i32, i32)>> {
+    if !map.is_walkable(from.0, from.1) || !map.is_walkable(to.0, to.1) {
+        return None;
+    }
+    let mut came_from = HashMap::from([(from, from)]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(tile) = queue.pop_front() {
+        if tile == to {
+            let mut path = vec![to];
+            let mut at = to;
+            while at != from {
+                at = came_from[&at];
+                path.push(at);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for (dx, dy) in STEPS {
+            let next = (tile.0 + dx, tile.1 + dy);
+            if map.is_walkable(next.0, next.1) && !came_from.contains_key(&next) {
+                came_from.insert(next, tile);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::{Building, BuildingType, Tile};
+
+    #[test]
+    fn test_path_goes_around_buildings_and_stops_at_walls() {
+        let mut wall = Building::new("Wall", 5, 0, 1, MAP_HEIGHT as u32 - 1, BuildingType::Park);
+        wall.door = (5, -1);
+        let mut map = GameMap {
+            tiles: [[Tile::Grass; MAP_HEIGHT]; MAP_WIDTH],
+            buildings: vec![wall],
+        };
+
+        let path = find_path(&map, (3, 0), (7, 0)).unwrap();
+        assert_eq!(path.first(), Some(&(3, 0)));
+        assert_eq!(path.last(), Some(&(7, 0)));
+        assert!(path.iter().all(|&(x, y)| map.is_walkable(x, y)));
+        assert!(path.contains(&(5, MAP_HEIGHT as i32 - 1)));
+
+        map.buildings[0].height = MAP_HEIGHT as u32;
+        assert_eq!(find_path(&map, (3, 0), (7, 0)), None);
+    }
+}
@@ -1,9 +1,14 @@
 use macroquad::prelude::*;
+use crate::testing::InputSnapshot;
 use crate::world::GameMap;
 use crate::world::TILE_SIZE;
 
 const PLAYER_SPEED: f32 = 200.0;
 const PLAYER_SIZE: f32 = 16.0;
+/// Default speed gained per second while a direction is held
+pub const PLAYER_ACCELERATION: f32 = 1600.0;
+/// Default speed lost per second after release
+pub const PLAYER_DECELERATION: f32 = 1200.0;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Direction {
@@ -20,6 +25,15 @@ pub struct WorldPlayer {
     pub direction: Direction,
     pub walking: bool,
     pub anim_timer: f32,
+    /// Current movement in pixels per second
+    pub velocity: Vec2,
+    pub top_speed: f32,
+    /// Ramp-up rate; 0 starts at top speed instantly
+    pub acceleration: f32,
+    /// Glide-out rate; 0 stops instantly
+    pub deceleration: f32,
+    /// Position before the last step, for drawing between world ticks
+    pub prev_position: Vec2,
 }
 
 impl WorldPlayer {
@@ -30,62 +44,149 @@ impl WorldPlayer {
             direction: Direction::Down,
             walking: false,
             anim_timer: 0.0,
+            velocity: Vec2::ZERO,
+            top_speed: PLAYER_SPEED,
+            acceleration: PLAYER_ACCELERATION,
+            deceleration: PLAYER_DECELERATION,
+            prev_position: Vec2::new(x, y),
         }
     }
 
-    pub fn update(&mut self, dt: f32, map: &GameMap) {
-        let mut dx = 0.0;
-        let mut dy = 0.0;
+    /// Move for one world tick with whatever direction keys are held
+    pub fn update(&mut self, input: &InputSnapshot, dt: f32, map: &GameMap) {
+        let mut dx: f32 = 0.0;
+        let mut dy: f32 = 0.0;
 
-        if is_key_down(KeyCode::W) || is_key_down(KeyCode::Up) {
+        if input.is_key_down("w") || input.is_key_down("up") {
             dy -= 1.0;
             self.direction = Direction::Up;
         }
-        if is_key_down(KeyCode::S) || is_key_down(KeyCode::Down) {
+        if input.is_key_down("s") || input.is_key_down("down") {
             dy += 1.0;
             self.direction = Direction::Down;
         }
-        if is_key_down(KeyCode::A) || is_key_down(KeyCode::Left) {
+        if input.is_key_down("a") || input.is_key_down("left") {
             dx -= 1.0;
             self.direction = Direction::Left;
         }
-        if is_key_down(KeyCode::D) || is_key_down(KeyCode::Right) {
+        if input.is_key_down("d") || input.is_key_down("right") {
             dx += 1.0;
             self.direction = Direction::Right;
         }
 
-        self.walking = dx != 0.0 || dy != 0.0;
+        self.step(Vec2::new(dx, dy), dt, map);
+    }
 
-        if self.walking {
-            let len: f32 = (dx * dx + dy * dy) as f32;
-            let len = len.sqrt();
-            if len > 0.0 {
-                dx /= len;
-                dy /= len;
-            }
-            
-            let new_x = self.x + dx * PLAYER_SPEED * dt;
-            let new_y = self.y + dy * PLAYER_SPEED * dt;
-            
-            if !map.collides(new_x, self.y, PLAYER_SIZE, PLAYER_SIZE) {
-                self.x = new_x;
+    /// Move for one tick with `input` as the held direction (zero when nothing is held)
+    ///
+    /// Velocity ramps toward top speed in the input direction and glides
+    /// to a stop after release. Diagonals are normalized, so they never
+    /// exceed top speed. Hitting a wall stops movement along that axis.
+    pub fn step(&mut self, input: Vec2, dt: f32, map: &GameMap) {
+        self.prev_position = self.position();
+        let target = input.normalize_or_zero() * self.top_speed;
+        let rate = if target == Vec2::ZERO { self.deceleration } else { self.acceleration };
+        self.velocity = if rate <= 0.0 {
+            target
+        } else {
+            let delta = target - self.velocity;
+            let max_change = rate * dt;
+            if delta.length() <= max_change {
+                target
+            } else {
+                self.velocity + delta.normalize() * max_change
             }
-            if !map.collides(self.x, new_y, PLAYER_SIZE, PLAYER_SIZE) {
-                self.y = new_y;
-            }
-            
-            self.x = self.x.max(PLAYER_SIZE).min((crate::world::MAP_WIDTH as f32 - 1.0) * TILE_SIZE);
-            self.y = self.y.max(PLAYER_SIZE).min((crate::world::MAP_HEIGHT as f32 - 1.0) * TILE_SIZE);
-            
-            self.anim_timer += dt;
+        };
+        self.velocity = self.velocity.clamp_length_max(self.top_speed);
+
+        self.walking = self.velocity != Vec2::ZERO;
+        if !self.walking {
+            return;
+        }
+
+        let new_x = self.x + self.velocity.x * dt;
+        let new_y = self.y + self.velocity.y * dt;
+
+        if map.collides(new_x, self.y, PLAYER_SIZE, PLAYER_SIZE) {
+            self.velocity.x = 0.0;
+        } else {
+            self.x = new_x;
         }
+        if map.collides(self.x, new_y, PLAYER_SIZE, PLAYER_SIZE) {
+            self.velocity.y = 0.0;
+        } else {
+            self.y = new_y;
+        }
+
+        self.x = self.x.max(PLAYER_SIZE).min((crate::world::MAP_WIDTH as f32 - 1.0) * TILE_SIZE);
+        self.y = self.y.max(PLAYER_SIZE).min((crate::world::MAP_HEIGHT as f32 - 1.0) * TILE_SIZE);
+
+        self.anim_timer += dt;
     }
 
     pub fn position(&self) -> Vec2 {
         Vec2::new(self.x, self.y)
     }
 
+    /// Where to draw the player, `alpha` of the way from the previous tick to the last
+    pub fn render_position(&self, alpha: f32) -> Vec2 {
+        self.prev_position.lerp(self.position(), alpha)
+    }
+
     pub fn tile_position(&self) -> (i32, i32) {
         ((self.x / TILE_SIZE) as i32, (self.y / TILE_SIZE) as i32)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn() -> WorldPlayer {
+        WorldPlayer::new(5.0 * TILE_SIZE, (crate::world::MAP_HEIGHT as f32 - 5.0) * TILE_SIZE)
+    }
+
+    #[test]
+    fn test_velocity_ramps_up_while_held_and_decays_after_release() {
+        let map = GameMap::new();
+        let mut player = spawn();
+        let dt = 1.0 / 60.0;
+
+        let mut last_speed = 0.0;
+        for _ in 0..5 {
+            player.step(Vec2::new(1.0, 0.0), dt, &map);
+            let speed = player.velocity.length();
+            assert!(speed > last_speed);
+            last_speed = speed;
+        }
+        for _ in 0..30 {
+            player.step(Vec2::new(1.0, 0.0), dt, &map);
+        }
+        assert_eq!(player.velocity.length(), PLAYER_SPEED);
+
+        let released_at = player.x;
+        player.step(Vec2::ZERO, dt, &map);
+        assert!(player.velocity.length() < PLAYER_SPEED);
+        assert!(player.x > released_at, "player should glide after release");
+        for _ in 0..60 {
+            player.step(Vec2::ZERO, dt, &map);
+        }
+        assert_eq!(player.velocity, Vec2::ZERO);
+        assert!(!player.walking);
+    }
+
+    #[test]
+    fn test_diagonal_caps_at_top_speed() {
+        let map = GameMap::new();
+        let mut player = spawn();
+        for _ in 0..60 {
+            player.step(Vec2::new(1.0, -1.0), 1.0 / 60.0, &map);
+            assert!(player.velocity.length() <= PLAYER_SPEED + 0.01);
+        }
+
+        let mut instant = spawn();
+        instant.acceleration = 0.0;
+        instant.step(Vec2::new(0.0, 1.0), 1.0 / 60.0, &map);
+        assert_eq!(instant.velocity.length(), PLAYER_SPEED);
+    }
+}
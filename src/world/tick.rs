@@ -0,0 +1,100 @@
+//! World Tick
+//!
+//! Fixed-timestep clock for world simulation. Frames feed in whatever
+//! time they took, and the clock says how many whole world ticks are due,
+//! so movement runs at the same rate on every machine and tests can step
+//! it exactly. Rendering uses `alpha` to draw between the last two ticks.
+
+/// World simulation steps per second
+pub const WORLD_TICK_HZ: f64 = 30.0;
+/// Longest frame the clock will catch up on, in seconds
+///
+/// A stall (window drag, breakpoint) skips ahead instead of fast-forwarding
+/// through a burst of ticks.
+pub const MAX_FRAME_SECS: f32 = 0.25;
+
+/// Turns variable frame times into a whole number of fixed ticks
+#[derive(Debug, Clone)]
+pub struct FixedTimestep {
+    hz: f64,
+    /// Seconds fed in so far
+    elapsed: f64,
+    /// Ticks handed out so far
+    ticks: u64,
+}
+
+impl FixedTimestep {
+    pub fn new(hz: f64) -> Self {
+        Self { hz, elapsed: 0.0, ticks: 0 }
+    }
+
+    /// Length of one tick in seconds
+    pub fn step_secs(&self) -> f32 {
+        (1.0 / self.hz) as f32
+    }
+
+    /// Feed in a frame's time and return how many ticks to run now
+    ///
+    /// Counts ticks from the total time fed in rather than a running
+    /// remainder, so the count doesn't depend on how the time was split
+    /// into frames.
+    pub fn advance(&mut self, dt: f32) -> u32 {
+        self.elapsed += dt.clamp(0.0, MAX_FRAME_SECS) as f64;
+        // A thousandth of a tick of slack keeps the rounding in f32 frame
+        // times from dropping a tick
+        let due = (self.elapsed * self.hz + 1e-3).floor() as u64;
+        let run = due.saturating_sub(self.ticks);
+        self.ticks = due.max(self.ticks);
+        run as u32
+    }
+
+    /// How far the clock is past the last tick, from 0 to just under 1
+    pub fn alpha(&self) -> f32 {
+        (self.elapsed * self.hz - self.ticks as f64).clamp(0.0, 1.0) as f32
+    }
+
+    /// Ticks handed out since the clock started
+    pub fn total_ticks(&self) -> u64 {
+        self.ticks
+    }
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new(WORLD_TICK_HZ)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_count_does_not_depend_on_frame_chunking() {
+        let run = |frames: &[f32]| {
+            let mut clock = FixedTimestep::default();
+            frames.iter().map(|&dt| clock.advance(dt)).sum::<u32>()
+        };
+        let even = |fps: usize, seconds: usize| vec![1.0 / fps as f32; fps * seconds];
+        let uneven: Vec<f32> = (0..40).map(|i| if i % 2 == 0 { 0.07 } else { 0.03 }).collect();
+
+        assert_eq!(run(&even(60, 2)), 60);
+        assert_eq!(run(&even(144, 2)), 60);
+        assert_eq!(run(&even(25, 2)), 60);
+        assert_eq!(run(&uneven), 60);
+        assert_eq!(run(&[0.2; 10]), 60);
+    }
+
+    #[test]
+    fn test_alpha_tracks_time_between_ticks() {
+        let mut clock = FixedTimestep::new(10.0);
+        assert_eq!(clock.advance(0.05), 0);
+        assert!((clock.alpha() - 0.5).abs() < 1e-4);
+        assert_eq!(clock.advance(0.075), 1);
+        assert!((clock.alpha() - 0.25).abs() < 1e-4);
+
+        // A long stall only catches up on MAX_FRAME_SECS
+        assert_eq!(clock.advance(10.0), (MAX_FRAME_SECS * 10.0) as u32);
+        assert_eq!(clock.total_ticks(), 3);
+    }
+}
@@ -0,0 +1,181 @@
+//! Tilemap Loading
+//!
+//! Loads the map layout and building placements from config/map.toml,
+//! so level design doesn't require editing Rust.
+//!
+//! # Tile Legend
+//! - `.` Grass
+//! - `#` Path
+//! - `~` Water
+//! - `B` Building
+//! - `D` Door
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use super::map::{Building, BuildingType, GameMap, Tile, MAP_HEIGHT, MAP_WIDTH};
+
+/// Building placement from TOML
+#[derive(Debug, Clone, Deserialize)]
+struct BuildingConfig {
+    name: String,
+    #[serde(rename = "type")]
+    building_type: String,
+    #[serde(default)]
+    tier: u8,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    /// Entry tile; defaults to the bottom-center tile
+    #[serde(default)]
+    door: Option<(i32, i32)>,
+}
+
+/// Root tilemap structure
+#[derive(Debug, Clone, Deserialize)]
+struct TilemapConfig {
+    tiles: String,
+    #[serde(default)]
+    buildings: Vec<BuildingConfig>,
+}
+
+fn parse_tile(c: char) -> Result<Tile> {
+    match c {
+        '.' => Ok(Tile::Grass),
+        '#' => Ok(Tile::Path),
+        '~' => Ok(Tile::Water),
+        'B' => Ok(Tile::Building),
+        'D' => Ok(Tile::Door),
+        _ => bail!("Unknown tile character: {:?}", c),
+    }
+}
+
+fn parse_building_type(s: &str, tier: u8) -> Result<BuildingType> {
+    match s {
+        "Apartment" => Ok(BuildingType::Apartment),
+        "Library" => Ok(BuildingType::Library),
+        "University" => Ok(BuildingType::University),
+        "CoffeeShop" => Ok(BuildingType::CoffeeShop),
+        "Company" => Ok(BuildingType::Company { tier }),
+        "JobCenter" => Ok(BuildingType::JobCenter),
+        "Park" => Ok(BuildingType::Park),
+        _ => bail!("Unknown building type: {}", s),
+    }
+}
+
+impl GameMap {
+    /// Load the embedded map from config/map.toml
+    ///
+    /// The file matches `GameMap::new`, so callers can fall back to that
+    /// if it fails to parse.
+    pub fn load() -> Result<Self> {
+        const CONFIG: &str = include_str!("../config/map.toml");
+        Self::from_tilemap(CONFIG).context("Failed to load map.toml")
+    }
+
+    /// Parse a map from tilemap TOML
+    pub fn from_tilemap(src: &str) -> Result<Self> {
+        let config: TilemapConfig = toml::from_str(src).context("Failed to parse tilemap")?;
+
+        let rows: Vec<&str> = config.tiles.lines().collect();
+        if rows.len() > MAP_HEIGHT {
+            bail!("Tilemap has {} rows, max is {}", rows.len(), MAP_HEIGHT);
+        }
+
+        let mut tiles = [[Tile::Grass; MAP_HEIGHT]; MAP_WIDTH];
+        for (y, row) in rows.iter().enumerate() {
+            let chars: Vec<char> = row.chars().collect();
+            if chars.len() > MAP_WIDTH {
+                bail!("Tilemap row {} has {} tiles, max is {}", y, chars.len(), MAP_WIDTH);
+            }
+            for (x, c) in chars.into_iter().enumerate() {
+                tiles[x][y] = parse_tile(c).with_context(|| format!("at row {}, column {}", y, x))?;
+            }
+        }
+
+        let buildings = config
+            .buildings
+            .into_iter()
+            .map(|b| {
+                let mut building = Building::new(
+                    &b.name,
+                    b.x,
+                    b.y,
+                    b.width,
+                    b.height,
+                    parse_building_type(&b.building_type, b.tier)?,
+                );
+                if let Some(door) = b.door {
+                    building.door = door;
+                }
+                Ok(building)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { tiles, buildings })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_small_tilemap() {
+        let src = r#"
+tiles = """
+.#~
+##D
+"""
+
+[[buildings]]
+name = "Tiny Corp"
+type = "Company"
+tier = 2
+x = 1
+y = 0
+width = 2
+height = 1
+door = [2, 0]
+"#;
+        let map = GameMap::from_tilemap(src).unwrap();
+
+        assert_eq!(map.tiles[0][0], Tile::Grass);
+        assert_eq!(map.tiles[1][0], Tile::Path);
+        assert_eq!(map.tiles[2][0], Tile::Water);
+        assert_eq!(map.tiles[0][1], Tile::Path);
+        assert_eq!(map.tiles[2][1], Tile::Door);
+        // Unspecified tiles default to grass
+        assert_eq!(map.tiles[5][5], Tile::Grass);
+
+        assert_eq!(map.buildings.len(), 1);
+        let building = &map.buildings[0];
+        assert_eq!(building.name, "Tiny Corp");
+        assert_eq!((building.x, building.y), (1, 0));
+        assert_eq!(building.building_type, BuildingType::Company { tier: 2 });
+        assert_eq!(building.door, (2, 0));
+    }
+
+    #[test]
+    fn test_unknown_tile_is_error() {
+        let src = "tiles = \"\"\"\n.?.\n\"\"\"\n";
+        assert!(GameMap::from_tilemap(src).is_err());
+    }
+
+    #[test]
+    fn test_embedded_map_matches_built_in() {
+        const CONFIG: &str = include_str!("../config/map.toml");
+        let loaded = GameMap::from_tilemap(CONFIG).unwrap();
+        let built_in = GameMap::new();
+
+        assert_eq!(loaded.tiles, built_in.tiles);
+        assert_eq!(loaded.buildings.len(), built_in.buildings.len());
+        for (a, b) in loaded.buildings.iter().zip(&built_in.buildings) {
+            assert_eq!(a.name, b.name);
+            assert_eq!((a.x, a.y, a.width, a.height), (b.x, b.y, b.width, b.height));
+            assert_eq!(a.building_type, b.building_type);
+            assert_eq!(a.door, b.door);
+        }
+    }
+}
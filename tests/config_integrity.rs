@@ -0,0 +1,119 @@
+//! Config Integrity Tests
+//!
+//! Parses every embedded config file and checks content invariants, so a
+//! bad edit fails `cargo test` instead of panicking on launch.
+
+use ai_career_rpg::companies::get_all_companies;
+use ai_career_rpg::engine::{EngineType, GameConfig};
+use ai_career_rpg::interview::questions::InterviewQuestionDb;
+use ai_career_rpg::llm::SUPPORTED_PROVIDERS;
+use ai_career_rpg::player::CoffeeMenu;
+use ai_career_rpg::skills::get_all_skills;
+#[cfg(feature = "gui")]
+use ai_career_rpg::world::GameMap;
+
+#[test]
+fn coffee_menu_items_are_sane() {
+    let menu = CoffeeMenu::load();
+    assert!(!menu.items.is_empty());
+    for item in &menu.items {
+        assert!(item.price > 0, "{} is free", item.name);
+        if let Some(buff) = &item.buff {
+            assert!(buff.hours > 0.0, "{} buff never lasts", item.name);
+            assert!((0.0..1.0).contains(&buff.magnitude), "{} buff magnitude out of range", item.name);
+        }
+    }
+}
+
+#[test]
+fn game_config_parses() {
+    let config = GameConfig::load().expect("game_config.toml should parse");
+    assert!(!config.llm.model.is_empty());
+}
+
+#[test]
+fn llm_provider_is_supported() {
+    let config = GameConfig::load().unwrap();
+    assert!(
+        SUPPORTED_PROVIDERS.contains(&config.llm.provider.as_str()),
+        "unsupported provider {:?}",
+        config.llm.provider
+    );
+}
+
+#[test]
+fn engine_names_are_valid() {
+    let config = GameConfig::load().unwrap();
+    assert!(config.npc.default_engine.parse::<EngineType>().is_ok());
+    assert!(config.interview.engine.parse::<EngineType>().is_ok());
+    for (name, class) in &config.npc.classes {
+        if let Some(engine) = &class.engine {
+            assert!(engine.parse::<EngineType>().is_ok(), "bad engine for {}", name);
+        }
+    }
+}
+
+#[test]
+fn llm_npc_classes_have_persona() {
+    let config = GameConfig::load().unwrap();
+    for (name, class) in &config.npc.classes {
+        let engine = class.engine.as_deref().unwrap_or(&config.npc.default_engine);
+        if engine != "rule" {
+            let persona = class.persona.as_deref().unwrap_or("");
+            assert!(!persona.trim().is_empty(), "{} uses the LLM but has no persona", name);
+        }
+    }
+}
+
+#[test]
+fn rule_npc_classes_have_fallback_dialog() {
+    let config = GameConfig::load().unwrap();
+    for (name, class) in &config.npc.classes {
+        assert!(!class.fallback_dialog.is_empty(), "{} has no fallback dialog", name);
+    }
+}
+
+#[test]
+fn interview_skills_have_valid_questions() {
+    let db = InterviewQuestionDb::load();
+    for (skill, questions) in db.skills() {
+        assert!(!questions.is_empty(), "{} has no questions", skill);
+        for q in questions {
+            assert!(
+                q.correct_idx < q.options.len(),
+                "{}: correct_idx out of range in {:?}",
+                skill,
+                q.question
+            );
+            assert!(q.options.len() >= 2, "{}: too few options in {:?}", skill, q.question);
+            assert!((1..=4).contains(&q.difficulty), "{}: bad difficulty in {:?}", skill, q.question);
+        }
+    }
+}
+
+#[test]
+fn company_jobs_reference_known_skills() {
+    let skills: Vec<String> = get_all_skills().into_iter().map(|s| s.name).collect();
+    for company in get_all_companies() {
+        for job in &company.open_positions {
+            assert!(job.salary_min <= job.salary_max, "{} salary band inverted", job.title);
+            for req in &job.requirements {
+                assert!(
+                    skills.contains(&req.skill_name),
+                    "{} at {} requires unknown skill {}",
+                    job.title,
+                    company.name,
+                    req.skill_name
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "gui")]
+#[test]
+fn map_config_parses() {
+    let map = GameMap::from_tilemap(include_str!("../src/config/map.toml"))
+        .expect("map.toml should parse");
+    assert!(!map.buildings.is_empty());
+}
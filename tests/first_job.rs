@@ -0,0 +1,55 @@
+//! End-to-End: Landing the First Job
+//!
+//! Scripts the early game with the headless `GameSim`: study up to the
+//! DataStartup Junior ML Engineer requirements, sleeping whenever energy
+//! runs out, then interview, accept the offer and check the player is hired.
+
+use ai_career_rpg::companies::get_all_companies;
+use ai_career_rpg::game::GameSim;
+use ai_career_rpg::jobs::CompanyTier;
+use ai_career_rpg::skills::Proficiency;
+
+const SEED: u64 = 2024;
+/// Upper bound on days spent studying, so a regression fails instead of hanging
+const MAX_DAYS: u32 = 60;
+
+/// Study `skill` until it reaches `target`, sleeping when out of energy
+fn study_to(sim: &mut GameSim, skill: &str, target: Proficiency) {
+    while sim.state.player.skills[skill].proficiency < target {
+        assert!(sim.state.day <= MAX_DAYS, "{} never reached {:?}", skill, target);
+        if sim.study(skill).is_err() {
+            sim.sleep();
+        }
+    }
+}
+
+#[test]
+fn study_interview_and_land_first_job() {
+    let mut sim = GameSim::new(SEED);
+
+    study_to(&mut sim, "Python", Proficiency::Intermediate);
+    study_to(&mut sim, "PyTorch", Proficiency::Basic);
+    assert!(sim.state.day > 1, "studying should take more than one day");
+
+    let company = get_all_companies()
+        .into_iter()
+        .find(|c| c.name == "DataStartup AI")
+        .expect("DataStartup AI is in the company config");
+    let job = company
+        .open_positions
+        .iter()
+        .find(|j| j.title == "Junior ML Engineer")
+        .expect("DataStartup AI hires a Junior ML Engineer")
+        .clone();
+    assert!(job.check_eligibility(&sim.state.player).is_ok());
+
+    assert!(sim.interview(&job), "a fully qualified player should pass");
+    assert!(!sim.state.player.employed, "a pass makes an offer, not a hire");
+    assert!(sim.accept_offer(job.id).succeeded());
+
+    let player = &sim.state.player;
+    assert!(player.employed);
+    assert_eq!(company.tier, CompanyTier::Startup);
+    assert_eq!(player.current_salary, job.offer_for(player, CompanyTier::Startup));
+    assert_eq!(player.job_history.last().map(|r| r.title.as_str()), Some("Junior ML Engineer"));
+}
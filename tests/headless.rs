@@ -0,0 +1,54 @@
+//! Headless Simulation Tests
+//!
+//! Exercises the core game loop using only modules that build without the
+//! `gui` feature. CI runs this with `--no-default-features` to keep the
+//! simulation free of window dependencies.
+
+use ai_career_rpg::companies::{self, get_all_companies};
+use ai_career_rpg::game::{GameRng, GameState, TimeCategory};
+use ai_career_rpg::interview::adaptive::AdaptiveQuiz;
+use ai_career_rpg::interview::questions::InterviewQuestionDb;
+use ai_career_rpg::jobs::batch_apply;
+use ai_career_rpg::player::{Player, STUDY_SESSION_HOURS};
+use ai_career_rpg::skills::{get_all_skills, Proficiency};
+
+#[test]
+fn study_and_advance_days_without_window() {
+    let mut state = GameState::with_seed("Headless", 7);
+    state.time_of_day = 9.0;
+    state
+        .player
+        .study("Python", STUDY_SESSION_HOURS, state.time_of_day)
+        .expect("study should succeed with full energy");
+    let finished = state.advance_time(24.0, TimeCategory::Resting);
+
+    assert_eq!(state.day, 2);
+    assert!(finished.is_some());
+    assert!(state.player.skills["Python"].experience_points > 0);
+}
+
+#[test]
+fn apply_and_interview_without_window() {
+    let mut builder = Player::builder("Headless");
+    for skill in get_all_skills() {
+        builder = builder.skill(&skill.name, Proficiency::Expert);
+    }
+    let player = builder.build();
+    let batch = batch_apply(&get_all_companies(), &player, 0.5, None);
+    let application = batch
+        .queued
+        .first()
+        .expect("an expert should qualify for some job");
+
+    let db = InterviewQuestionDb::load();
+    let job = &application.job;
+    let company = companies::find(&job.company);
+    let mut quiz = AdaptiveQuiz::for_job(job, company.as_ref(), &db);
+    let mut rng = GameRng::new(1);
+    let mut asked = 0;
+    while let Some(question) = quiz.next_question(&mut rng) {
+        quiz.record_answer(question.correct_idx == 0);
+        asked += 1;
+    }
+    assert_eq!(asked, quiz.len());
+}